@@ -29,7 +29,21 @@ fn main() {
     // `memory.x` is changed.
     println!("cargo:rerun-if-changed=memory.x");
 
-    println!("cargo:rustc-link-arg-bins=--nmagic");
-    println!("cargo:rustc-link-arg-bins=-Tlink.x");
-    println!("cargo:rustc-link-arg-bins=-Tdefmt.x");
+    // The linker scripts only apply to the micro:bit image, not to the
+    // host build used by `cargo test`
+    if env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("none") {
+        println!("cargo:rustc-link-arg-bins=--nmagic");
+        println!("cargo:rustc-link-arg-bins=-Tlink.x");
+        println!("cargo:rustc-link-arg-bins=-Tdefmt.x");
+    }
+
+    // Host tests on Linux lay out defmt's interned strings the same way,
+    // so they can decode what they log. Without PIE the string indices are
+    // link-time addresses, as they are on the device.
+    if env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("linux") && env::var_os("CARGO_FEATURE_DEFMT").is_some() {
+        let manifest_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+        println!("cargo:rustc-link-arg=-T{}", manifest_dir.join("defmt_host.x").display());
+        println!("cargo:rustc-link-arg=-no-pie");
+        println!("cargo:rerun-if-changed=defmt_host.x");
+    }
 }
@@ -0,0 +1,25 @@
+/* Host counterpart of defmt's `defmt.x`, linked into the `cargo test`
+   binary on Linux so tests can decode defmt output against its own table.
+   It only adds the `.defmt` section; the rest of the default layout is
+   kept by the `INSERT` at the end. */
+EXTERN(__defmt_default_timestamp);
+EXTERN(__defmt_default_panic);
+PROVIDE(_defmt_timestamp = __defmt_default_timestamp);
+PROVIDE(_defmt_panic = __defmt_default_panic);
+
+SECTIONS
+{
+  /* Holds a pointer, which the loader would try to relocate inside the
+     unloaded `.defmt` section; the marker only matters on the device */
+  /DISCARD/ : { *(.defmt.end.timestamp) }
+
+  /* Tag number 0 is reserved, so interned strings start at 1 */
+  .defmt 1 (INFO) :
+  {
+    . = 1;
+    *(.defmt.prim.*);
+    *(.defmt.*);
+    KEEP(*(.defmt.end .defmt.end.*));
+  }
+}
+INSERT AFTER .comment;
@@ -1,5 +1,6 @@
 MEMORY
 {
-  FLASH : ORIGIN = 0x00000000, LENGTH = 512K
+  /* The last 4K page (0x7F000) is reserved for persistent settings */
+  FLASH : ORIGIN = 0x00000000, LENGTH = 508K
   RAM : ORIGIN = 0x20000000, LENGTH = 128K
 }
@@ -0,0 +1,323 @@
+//! # Analog Input Module
+//!
+//! This module reads analog voltages from the micro:bit edge connector using
+//! the nRF52833's SAADC (successive approximation ADC). It is intended for
+//! simple sensors such as a potentiometer wired to one of the large pins.
+//!
+//! ## Features
+//! - **Raw Readings**: 12-bit conversions (0-4095)
+//! - **Millivolts**: Conversion to millivolts using the configured range
+//! - **Microphone**: Sound level from the built-in microphone and clap detection
+//! - **Battery**: Supply voltage from the SAADC's internal VDD channel
+//!
+//! ## Measurement Range
+//! Each reading uses the internal 0.6 V reference with 1/6 gain, giving a
+//! 0-3.6 V input range. The micro:bit runs at 3.3 V, so a potentiometer
+//! between 3V and GND covers most of the scale.
+//!
+//! ## Peripheral Ownership
+//! `AnalogInput` consumes the single SAADC peripheral. It cannot be used at
+//! the same time as any other SAADC user, such as `Microphone`. `Battery`
+//! can borrow the SAADC instead (`&mut board.saadc`), so an occasional
+//! supply check can be made and dropped before the SAADC is handed on.
+//!
+//! ## Usage Example
+//! ```ignore
+//! let board = Microbit::default();
+//! let mut pot = AnalogInput::new(board.saadc, board.p0);
+//! let mv = pot.read_millivolts().await;
+//!
+//! let mut mic = Microphone::new(board.saadc, board.microphone, board.micen).await;
+//! wait_for_clap(&mut mic, CLAP_THRESHOLD).await;
+//!
+//! let mv = Battery::new(&mut board.saadc).battery_millivolts().await;
+//! ```
+
+use embassy_nrf::gpio::{Level, Output, OutputDrive};
+use embassy_nrf::peripherals::{P0_05, P0_20, SAADC};
+use embassy_nrf::saadc::{self, ChannelConfig, Gain, Reference, Resolution, Saadc, VddInput};
+use embassy_nrf::Peripheral;
+use embassy_time::{Duration, Instant, Timer};
+
+use crate::board::Irqs;
+
+/// **Full-Scale Input Voltage**
+///
+/// Input voltage in millivolts that produces the maximum reading: the
+/// 0.6 V internal reference divided by the 1/6 gain.
+const FULL_SCALE_MV: u32 = 3600;
+
+/// **Full-Scale Reading**
+///
+/// Number of distinct values produced by a 12-bit conversion.
+const FULL_SCALE_COUNTS: u32 = 4096;
+
+/// **Analog Input Pin**
+///
+/// Single-channel SAADC reader bound to one analog-capable pin, such as the
+/// edge connector pins P0 (P0_02), P1 (P0_03) or P2 (P0_04).
+pub struct AnalogInput {
+    saadc: Saadc<'static, 1>,
+}
+
+#[allow(dead_code)]
+impl AnalogInput {
+    /// **Create Analog Input**
+    ///
+    /// Configures the SAADC for 12-bit single-ended readings on `pin`.
+    ///
+    /// # Arguments
+    /// * `saadc` - SAADC peripheral (consumed)
+    /// * `pin` - Analog-capable pin, e.g. `board.p0`, `board.p1` or `board.p2`
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut pot = AnalogInput::new(board.saadc, board.p0);
+    /// ```
+    pub fn new(saadc: SAADC, pin: impl Peripheral<P = impl saadc::Input> + 'static) -> Self {
+        let mut config = saadc::Config::default();
+        config.resolution = Resolution::_12BIT;
+
+        let mut channel = ChannelConfig::single_ended(pin);
+        channel.gain = Gain::GAIN1_6;
+        channel.reference = Reference::INTERNAL;
+
+        Self {
+            saadc: Saadc::new(saadc, Irqs, config, [channel]),
+        }
+    }
+
+    /// **Calibrate**
+    ///
+    /// Runs the SAADC offset calibration. Worth calling once after startup
+    /// and again if the chip temperature changes significantly.
+    pub async fn calibrate(&mut self) {
+        self.saadc.calibrate().await;
+    }
+
+    /// **Read Raw Value**
+    ///
+    /// Takes a single conversion.
+    ///
+    /// # Returns
+    /// The raw 12-bit reading (0-4095); slightly negative readings caused
+    /// by offset error near 0 V are reported as 0
+    pub async fn read(&mut self) -> u16 {
+        let mut buf = [0i16; 1];
+        self.saadc.sample(&mut buf).await;
+        buf[0].max(0) as u16
+    }
+
+    /// **Read Millivolts**
+    ///
+    /// Takes a single conversion and scales it to the 0-3.6 V input range.
+    ///
+    /// # Returns
+    /// The input voltage in millivolts
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mv = pot.read_millivolts().await;
+    /// display.show_number(mv as i32, Duration::from_secs(2)).await;
+    /// ```
+    pub async fn read_millivolts(&mut self) -> u16 {
+        let raw = self.read().await as u32;
+        (raw * FULL_SCALE_MV / FULL_SCALE_COUNTS) as u16
+    }
+}
+
+/// **Low Battery Threshold**
+///
+/// Supply voltage in millivolts below which the battery should be replaced.
+/// Two AAA cells read about 3000 mV when fresh; the LEDs dim visibly and
+/// the radio becomes unreliable not far below this.
+pub const LOW_BATTERY_MV: u16 = 2400;
+
+/// **Battery Monitor**
+///
+/// Measures the nRF52833's own supply (VDD) through the SAADC's internal
+/// channel, so no pin is needed. On battery power VDD follows the battery,
+/// less the drop across the micro:bit's protection diode; on USB power it
+/// reads the regulated 3.3 V.
+///
+/// `Battery` only borrows the SAADC for its lifetime, but while it exists
+/// no other SAADC user such as `Microphone` or `AnalogInput` can be created.
+/// Drop it (or let it go out of scope) before creating the next one.
+pub struct Battery<'d> {
+    saadc: Saadc<'d, 1>,
+}
+
+#[allow(dead_code)]
+impl<'d> Battery<'d> {
+    /// **Create Battery Monitor**
+    ///
+    /// Configures the SAADC for 12-bit readings of VDD against the 0.6 V
+    /// internal reference with 1/6 gain, a 0-3.6 V range.
+    ///
+    /// # Arguments
+    /// * `saadc` - SAADC peripheral, borrowed (`&mut board.saadc`) or consumed
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut battery = Battery::new(&mut board.saadc);
+    /// ```
+    pub fn new(saadc: impl Peripheral<P = SAADC> + 'd) -> Self {
+        let mut config = saadc::Config::default();
+        config.resolution = Resolution::_12BIT;
+
+        let mut channel = ChannelConfig::single_ended(VddInput);
+        channel.gain = Gain::GAIN1_6;
+        channel.reference = Reference::INTERNAL;
+
+        Self {
+            saadc: Saadc::new(saadc, Irqs, config, [channel]),
+        }
+    }
+
+    /// **Read Battery Voltage**
+    ///
+    /// Takes a single conversion of VDD and scales it to millivolts.
+    ///
+    /// # Returns
+    /// The supply voltage in millivolts
+    ///
+    /// # Example
+    /// ```ignore
+    /// if battery.battery_millivolts().await < LOW_BATTERY_MV {
+    ///     display.scroll("LOW BATTERY").await;
+    /// }
+    /// ```
+    pub async fn battery_millivolts(&mut self) -> u16 {
+        let mut buf = [0i16; 1];
+        self.saadc.sample(&mut buf).await;
+        (buf[0].max(0) as u32 * FULL_SCALE_MV / FULL_SCALE_COUNTS) as u16
+    }
+}
+
+/// **Sound Level Window**
+///
+/// Number of conversions combined into one `Microphone::level` reading. At
+/// the SAADC's default acquisition time this spans a couple of
+/// milliseconds, long enough to catch several cycles of a clap's sound.
+const LEVEL_SAMPLES: usize = 32;
+
+/// **Microphone Power-Up Delay**
+///
+/// Time for the microphone's output to settle after it is switched on.
+const MIC_STARTUP: Duration = Duration::from_millis(10);
+
+/// **Default Clap Threshold**
+///
+/// Sound level (see `Microphone::level`) a clap is expected to exceed. A
+/// firm clap at arm's length reads several times higher; normal speech
+/// nearby stays below it.
+pub const CLAP_THRESHOLD: u16 = 600;
+
+/// **Longest Clap**
+///
+/// A sound that stays above the threshold for longer than this is steady
+/// noise, such as music or a whistle, rather than a clap.
+pub const CLAP_MAX_DURATION: Duration = Duration::from_millis(120);
+
+/// **Built-in Microphone**
+///
+/// SAADC reader for the micro:bit v2 MEMS microphone on P0_05. The
+/// microphone is powered from P0_20, which is held high for as long as this
+/// value exists.
+pub struct Microphone {
+    saadc: Saadc<'static, 1>,
+    _enable: Output<'static>,
+}
+
+#[allow(dead_code)]
+impl Microphone {
+    /// **Create Microphone**
+    ///
+    /// Switches the microphone on and configures the SAADC to read it. The
+    /// microphone output idles at half the supply voltage, so the channel
+    /// uses the VDD/4 reference with 1/4 gain to cover 0 V to VDD.
+    ///
+    /// # Arguments
+    /// * `saadc` - SAADC peripheral (consumed)
+    /// * `pin` - Microphone output, `board.microphone`
+    /// * `enable` - Microphone power pin, `board.micen`
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut mic = Microphone::new(board.saadc, board.microphone, board.micen).await;
+    /// ```
+    pub async fn new(saadc: SAADC, pin: P0_05, enable: P0_20) -> Self {
+        let enable = Output::new(enable, Level::High, OutputDrive::HighDrive);
+
+        let mut config = saadc::Config::default();
+        config.resolution = Resolution::_12BIT;
+
+        let mut channel = ChannelConfig::single_ended(pin);
+        channel.gain = Gain::GAIN1_4;
+        channel.reference = Reference::VDD1_4;
+
+        let saadc = Saadc::new(saadc, Irqs, config, [channel]);
+        Timer::after(MIC_STARTUP).await;
+
+        Self { saadc, _enable: enable }
+    }
+
+    /// **Read Sound Level**
+    ///
+    /// Takes `LEVEL_SAMPLES` conversions and measures how far the signal
+    /// swings. Silence reads close to 0 and the loudest sounds approach
+    /// 4095; the value is a relative loudness, not a calibrated dB figure.
+    ///
+    /// # Returns
+    /// The peak-to-peak amplitude in raw 12-bit counts
+    ///
+    /// # Example
+    /// ```ignore
+    /// let level = mic.level().await;
+    /// // Drop the low 4 bits to fit the 12-bit level into `level_frame`'s u8
+    /// display.apply(level_frame((level >> 4) as u8, 255));
+    /// ```
+    pub async fn level(&mut self) -> u16 {
+        let mut min = i16::MAX;
+        let mut max = i16::MIN;
+        let mut buf = [0i16; 1];
+        for _ in 0..LEVEL_SAMPLES {
+            self.saadc.sample(&mut buf).await;
+            min = min.min(buf[0]);
+            max = max.max(buf[0]);
+        }
+        max.saturating_sub(min).max(0) as u16
+    }
+}
+
+/// **Wait For Clap**
+///
+/// Resolves on the next short, sharp sound: the level must rise above
+/// `threshold` and fall back below it within `CLAP_MAX_DURATION`. Sounds
+/// below the threshold are ignored, and a sound that stays loud for longer
+/// is treated as steady noise; nothing fires until it goes quiet again, so
+/// loud music does not trigger repeatedly.
+///
+/// # Arguments
+/// * `mic` - Microphone to listen on
+/// * `threshold` - Minimum sound level of a clap, e.g. `CLAP_THRESHOLD`
+///
+/// # Example
+/// ```ignore
+/// wait_for_clap(&mut mic, CLAP_THRESHOLD).await;
+/// display.display(CHECK_MARK, Duration::from_secs(1)).await;
+/// ```
+#[allow(dead_code)]
+pub async fn wait_for_clap(mic: &mut Microphone, threshold: u16) {
+    loop {
+        // Only a rise out of quiet counts, not a sound already in progress
+        while mic.level().await >= threshold {}
+        while mic.level().await < threshold {}
+
+        let rise = Instant::now();
+        while mic.level().await >= threshold {}
+        if rise.elapsed() <= CLAP_MAX_DURATION {
+            return;
+        }
+    }
+}
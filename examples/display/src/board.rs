@@ -9,11 +9,12 @@
 /// - **Type Aliases**: Creates convenient type definitions for hardware components
 ///
 /// ## Pin Layout (micro:bit v2)
-/// - **LED Matrix**: Uses pins P0_21-P0_19 (rows) and P0_28-P0_30 (columns)
-/// - **Buttons**: Button A (P0_14), Button B (P0_23)
+/// - **LED Matrix**: Uses pins P0_21-P0_19 (rows) and P0_28-P0_30 (columns);
+///   the columns double as edge pins P3, P4, P6, P7 and P10
+/// - **Buttons**: Button A (P0_14), Button B (P0_23), touch logo (P1_04)
 /// - **Edge Connector**: P0_02, P0_03, P0_04, etc. for external connections
 /// - **Internal I2C**: P0_08 (SCL), P0_16 (SDA) for accelerometer/magnetometer
-/// - **UART Debug**: P1_08 (TX), P0_06 (RX) for debug communication
+/// - **UART Debug**: P0_06 (TX), P1_08 (RX) for debug communication
 ///
 /// ## Usage Example
 /// ```no_run
@@ -21,40 +22,233 @@
 /// let mut display = board.display;
 /// let mut button_a = board.btn_a;
 /// ```
-use embassy_nrf::gpio::{AnyPin, Input, Level, Output, OutputDrive, Pin, Pull};
+use embassy_nrf::buffered_uarte::BufferedUarte;
+use embassy_nrf::gpio::{AnyPin, Flex, Input, OutputDrive, Pin, Pull};
+use embassy_nrf::pac;
 use embassy_nrf::peripherals::{
-    P0_00, P0_01, P0_02, P0_03, P0_04, P0_05, P0_06, P0_08, P0_09, P0_10, P0_12, P0_13, P0_16, P0_17, P0_20, P0_26,
-    P1_00, P1_02, P1_08, PPI_CH0, PPI_CH1, PWM0, PWM1, PWM2, PWM3, RNG, SAADC, TIMER0, TWISPI0, TWISPI1, UARTE0,
-    UARTE1,
+    NVMC, P0_00, P0_01, P0_02, P0_03, P0_04, P0_05, P0_06, P0_08, P0_09, P0_10, P0_11, P0_12, P0_13, P0_15, P0_16,
+    P0_17, P0_19, P0_20, P0_21, P0_22, P0_24, P0_26, P0_28, P0_30, P0_31, P1_00, P1_02, P1_04, P1_05, P1_08, PPI_CH0,
+    PPI_CH1, PPI_GROUP0, PWM0, PWM1, PWM2, PWM3, RADIO, RNG, SAADC, TEMP, TIMER0, TWISPI0, TWISPI1, UARTE0, UARTE1,
+    WDT,
 };
+use embassy_nrf::twim::{self, Twim};
+use embassy_nrf::uarte::{self, Baudrate, Parity};
 
 use crate::display::LedMatrix as LedMatrixDriver;
+use crate::system::ResetReason;
+
+/// Interrupt bindings for the async peripheral drivers built on this board
+/// (internal and external I2C, debug UART, radio, random number generator, ADC, temperature sensor). Pass `Irqs` wherever an
+/// embassy-nrf driver constructor asks for an interrupt binding.
+pub use irqs::Irqs;
+
+#[allow(missing_docs)]
+mod irqs {
+    use embassy_nrf::peripherals::{RADIO, RNG, TWISPI0, TWISPI1, UARTE0};
+    use embassy_nrf::{bind_interrupts, buffered_uarte, radio, rng, saadc, temp, twim};
+
+    bind_interrupts!(pub struct Irqs {
+        RADIO => radio::InterruptHandler<RADIO>;
+        RNG => rng::InterruptHandler<RNG>;
+        SAADC => saadc::InterruptHandler;
+        TEMP => temp::InterruptHandler;
+        TWISPI0 => twim::InterruptHandler<TWISPI0>;
+        TWISPI1 => twim::InterruptHandler<TWISPI1>;
+        UARTE0 => buffered_uarte::InterruptHandler<UARTE0>;
+    });
+}
 
 /// Type alias for the micro:bit's 5x5 LED matrix display.
 ///
 /// This creates a convenient shorthand for the LED matrix driver configured
 /// specifically for the micro:bit's hardware layout with 5 rows and 5 columns.
-pub type LedMatrix = LedMatrixDriver<Output<'static>, 5, 5>;
+pub type LedMatrix = LedMatrixDriver<Flex<'static>, 5, 5>;
 
 /// Type alias for micro:bit button inputs.
 ///
 /// Represents the physical buttons A and B on the micro:bit board.
 /// Configured as GPIO inputs without internal pull-up/pull-down resistors
-/// since the micro:bit has external pull-up resistors on the button lines.
+/// since the micro:bit has external pull-up resistors on the button lines;
+/// use `Microbit::with_button_pull` on boards that lack them.
 pub type Button = Input<'static>;
 
+/// Concrete LED matrix pins, for boards built with `Microbit::new_typed`.
+///
+/// `Microbit::new` degrades the ten matrix pins to `AnyPin` to build the
+/// display driver, after which their `P0_xx` types are gone. Five of them
+/// are also edge connector pins, so `new_typed` hands them over untouched
+/// instead. Use them with peripheral drivers that need a specific pin type,
+/// or turn them back into a display with `into_matrix`.
+///
+/// # Pins Consumed by the Display
+/// | Field  | Pin   | Edge pin |
+/// |--------|-------|----------|
+/// | `row1` | P0_21 | -        |
+/// | `row2` | P0_22 | -        |
+/// | `row3` | P0_15 | -        |
+/// | `row4` | P0_24 | -        |
+/// | `row5` | P0_19 | -        |
+/// | `col1` | P0_28 | P4       |
+/// | `col2` | P0_11 | P7       |
+/// | `col3` | P0_31 | P3       |
+/// | `col4` | P1_05 | P6       |
+/// | `col5` | P0_30 | P10      |
+///
+/// Driving a column pin for something else lights matrix LEDs in any row
+/// that is driven high. `new_typed` leaves every matrix pin unconfigured,
+/// so the rows float and light nothing; keep them that way, or drive them
+/// low with `Output`, while the columns are in other use.
+pub struct DisplayPins {
+    /// Row 1 (P0_21)
+    pub row1: P0_21,
+    /// Row 2 (P0_22)
+    pub row2: P0_22,
+    /// Row 3 (P0_15)
+    pub row3: P0_15,
+    /// Row 4 (P0_24)
+    pub row4: P0_24,
+    /// Row 5 (P0_19)
+    pub row5: P0_19,
+    /// Column 1 (P0_28), edge pin P4
+    pub col1: P0_28,
+    /// Column 2 (P0_11), edge pin P7
+    pub col2: P0_11,
+    /// Column 3 (P0_31), edge pin P3
+    pub col3: P0_31,
+    /// Column 4 (P1_05), edge pin P6
+    pub col4: P1_05,
+    /// Column 5 (P0_30), edge pin P10
+    pub col5: P0_30,
+}
+
+impl DisplayPins {
+    /// Builds the LED matrix driver from the pins, as `Microbit::new` does.
+    ///
+    /// # Returns
+    /// The same display driver `Microbit::new` puts in `board.display`
+    ///
+    /// # Example
+    /// ```ignore
+    /// let board = Microbit::new_typed(Default::default());
+    /// let mut display = board.display.into_matrix();
+    /// ```
+    #[allow(dead_code)]
+    pub fn into_matrix(self) -> LedMatrix {
+        let rows = [
+            output_pin(self.row1.degrade()),
+            output_pin(self.row2.degrade()),
+            output_pin(self.row3.degrade()),
+            output_pin(self.row4.degrade()),
+            output_pin(self.row5.degrade()),
+        ];
+
+        let cols = [
+            output_pin(self.col1.degrade()),
+            output_pin(self.col2.degrade()),
+            output_pin(self.col3.degrade()),
+            output_pin(self.col4.degrade()),
+            output_pin(self.col5.degrade()),
+        ];
+
+        LedMatrixDriver::new(rows, cols)
+    }
+}
+
+/// Peripherals for a buffered driver on the debug UART.
+///
+/// The interface chip bridges the debug UART to the USB serial port. A
+/// plain `Uarte` only receives while a read is pending, so bytes sent
+/// between two reads are lost. `BufferedUarte` keeps receiving into a ring
+/// buffer in the background instead, using a timer and two PPI channels to
+/// count the received bytes and a PPI group to stop them together.
+///
+/// # Example
+/// ```ignore
+/// let uart = DebugUart {
+///     uarte: board.uarte0,
+///     timer: board.timer0,
+///     ppi_ch1: board.ppi_ch0,
+///     ppi_ch2: board.ppi_ch1,
+///     ppi_group: board.ppi_group0,
+///     rx: board.uart_int_rx,
+///     tx: board.uart_int_tx,
+/// };
+/// ```
+pub struct DebugUart {
+    /// UARTE0 peripheral (`board.uarte0`)
+    pub uarte: UARTE0,
+    /// Timer counting received bytes (`board.timer0`)
+    pub timer: TIMER0,
+    /// First PPI channel (`board.ppi_ch0`)
+    pub ppi_ch1: PPI_CH0,
+    /// Second PPI channel (`board.ppi_ch1`)
+    pub ppi_ch2: PPI_CH1,
+    /// PPI channel group (`board.ppi_group0`)
+    pub ppi_group: PPI_GROUP0,
+    /// Receive pin from the interface chip (`board.uart_int_rx`)
+    pub rx: P1_08,
+    /// Transmit pin to the interface chip (`board.uart_int_tx`)
+    pub tx: P0_06,
+}
+
+impl DebugUart {
+    /// Starts a buffered UART at 115200 baud, 8N1.
+    ///
+    /// Reception starts immediately and continues in the background until
+    /// the driver is dropped.
+    ///
+    /// # Arguments
+    /// * `rx_buffer` - Ring buffer for received bytes; its length must be even
+    /// * `tx_buffer` - Ring buffer for bytes waiting to be sent
+    ///
+    /// # Returns
+    /// The running driver, borrowing both buffers
+    ///
+    /// # Example
+    /// ```ignore
+    /// let (mut rx_buffer, mut tx_buffer) = ([0u8; 64], [0u8; 64]);
+    /// let mut uart = debug_uart.into_buffered(&mut rx_buffer, &mut tx_buffer);
+    /// ```
+    pub fn into_buffered<'d>(
+        self,
+        rx_buffer: &'d mut [u8],
+        tx_buffer: &'d mut [u8],
+    ) -> BufferedUarte<'d, UARTE0, TIMER0> {
+        let mut config = uarte::Config::default();
+        config.baudrate = Baudrate::BAUD115200;
+        config.parity = Parity::EXCLUDED;
+        BufferedUarte::new(
+            self.uarte,
+            self.timer,
+            self.ppi_ch1,
+            self.ppi_ch2,
+            self.ppi_group,
+            Irqs,
+            self.rx,
+            self.tx,
+            config,
+            rx_buffer,
+            tx_buffer,
+        )
+    }
+}
+
 /// Main board structure containing all available peripherals and pins.
 ///
 /// This structure provides organized access to all the hardware components
 /// available on the BBC micro:bit v2 board. It groups related functionality
 /// and provides a clean interface for application code.
 ///
+/// The display type `D` is the ready-made `LedMatrix` for boards created by
+/// `new`, `default` and `with_button_pull`, and the raw `DisplayPins` for
+/// boards created by `new_typed` and `without_display`.
+///
 /// # Pin Organization
 /// - **Display**: Pre-configured 5x5 LED matrix
 /// - **Buttons**: Button A and B inputs
 /// - **Edge Connector**: Pins P0-P20 accessible via the edge connector
 /// - **Internal Interfaces**: I2C, UART, and other internal connections
-/// - **Peripherals**: Timers, PWM, ADC, RNG, and communication interfaces
+/// - **Peripherals**: Timers, PWM, ADC, RNG, radio, watchdog, and communication interfaces
 ///
 /// # Example
 /// ```no_run
@@ -62,19 +256,21 @@ pub type Button = Input<'static>;
 /// let display = board.display;
 /// let button_a = board.btn_a;
 /// ```
-pub struct Microbit {
+pub struct Microbit<D = LedMatrix> {
     /// **5x5 LED Matrix Display**
     ///
     /// Pre-configured LED matrix driver ready for displaying text, graphics,
     /// and animations. The matrix uses charlieplexing to control 25 LEDs
-    /// with only 10 GPIO pins (5 rows + 5 columns).
+    /// with only 10 GPIO pins (5 rows + 5 columns). Holds the unconfigured
+    /// `DisplayPins` instead on boards created with `new_typed` or
+    /// `without_display`.
     ///
     /// # Usage
     /// ```ignore
     /// board.display.scroll("Hello!").await;
     /// board.display.display(pattern, Duration::from_secs(2)).await;
     /// ```
-    pub display: LedMatrix,
+    pub display: D,
 
     /// **Button A Input (Left Button)**
     ///
@@ -147,6 +343,13 @@ pub struct Microbit {
     #[allow(dead_code)]
     pub micen: P0_20,
 
+    /// **Touch Logo Pin (P1_04)**
+    ///
+    /// Capacitive touch sensor behind the gold micro:bit logo on the front
+    /// face. Read it with the `touch` module's `TouchPad`.
+    #[allow(dead_code)]
+    pub logo: P1_04,
+
     // Edge Connector Pins (Large Pins)
     /// **Edge Connector Pin 0 (P0_02)**
     ///
@@ -256,19 +459,19 @@ pub struct Microbit {
     #[allow(dead_code)]
     pub i2c_int_sda: P0_16,
 
-    /// **Debug UART TX (P1_08)**
+    /// **Debug UART TX (P0_06)**
     ///
     /// UART transmit line to the debug MCU for USB serial communication.
     /// Used for debug output and programming interface.
     #[allow(dead_code)]
-    pub uart_int_tx: P1_08,
+    pub uart_int_tx: P0_06,
 
-    /// **Debug UART RX (P0_06)**
+    /// **Debug UART RX (P1_08)**
     ///
     /// UART receive line from the debug MCU for USB serial communication.
     /// Used for debug input and programming interface.
     #[allow(dead_code)]
-    pub uart_int_rx: P0_06,
+    pub uart_int_rx: P1_08,
 
     // Communication Peripherals
     /// **SPI0/I2C0 Peripheral (TWISPI0)**
@@ -330,6 +533,27 @@ pub struct Microbit {
     #[allow(dead_code)]
     pub ppi_ch1: PPI_CH1,
 
+    /// **PPI Group 0**
+    ///
+    /// PPI channel group, used together with `ppi_ch0` and `ppi_ch1` by
+    /// buffered UART drivers (see `DebugUart`).
+    #[allow(dead_code)]
+    pub ppi_group0: PPI_GROUP0,
+
+    /// **Non-Volatile Memory Controller (NVMC)**
+    ///
+    /// Flash controller for erasing and writing the internal flash,
+    /// used to persist settings across resets.
+    #[allow(dead_code)]
+    pub nvmc: NVMC,
+
+    /// **2.4 GHz Radio (RADIO)**
+    ///
+    /// Radio transceiver for wireless messaging between micro:bits.
+    /// Requires the external high-frequency crystal to be enabled.
+    #[allow(dead_code)]
+    pub radio: RADIO,
+
     /// **Random Number Generator (RNG)**
     ///
     /// Hardware random number generator for cryptographic applications,
@@ -343,6 +567,20 @@ pub struct Microbit {
     /// analog sensors, battery voltage, or other analog signals.
     #[allow(dead_code)]
     pub saadc: SAADC,
+
+    /// **Temperature Sensor (TEMP)**
+    ///
+    /// On-die temperature sensor with 0.25 °C resolution, read through
+    /// `system::TempSensor`.
+    #[allow(dead_code)]
+    pub temp: TEMP,
+
+    /// **Watchdog Timer (WDT)**
+    ///
+    /// Resets the chip if it is not fed in time, read through
+    /// `system::Watchdog`.
+    #[allow(dead_code)]
+    pub wdt: WDT,
 }
 
 impl Default for Microbit {
@@ -388,34 +626,213 @@ impl Microbit {
     /// let board = Microbit::new(config);
     /// ```
     pub fn new(config: embassy_nrf::config::Config) -> Self {
-        let p = embassy_nrf::init(config);
-        // LED Matrix
-        let rows = [
-            output_pin(p.P0_21.degrade()),
-            output_pin(p.P0_22.degrade()),
-            output_pin(p.P0_15.degrade()),
-            output_pin(p.P0_24.degrade()),
-            output_pin(p.P0_19.degrade()),
-        ];
+        Self::with_button_pull(config, Pull::None)
+    }
 
-        let cols = [
-            output_pin(p.P0_28.degrade()),
-            output_pin(p.P0_11.degrade()),
-            output_pin(p.P0_31.degrade()),
-            output_pin(p.P1_05.degrade()),
-            output_pin(p.P0_30.degrade()),
-        ];
+    /// Creates a new Microbit instance with a chosen pull mode for buttons A and B.
+    ///
+    /// The genuine micro:bit has external pull-up resistors on both button
+    /// lines, so `new` leaves the internal pulls off (`Pull::None`). Breakout
+    /// and clone boards often omit those resistors, leaving the inputs
+    /// floating so they read random presses; pass `Pull::Up` there.
+    ///
+    /// # Active-Low Buttons
+    /// The rest of the crate treats a button as pressed when its input reads
+    /// low (`wait_for_low`, `is_low`), which assumes the line idles high and
+    /// a press shorts it to ground. `Pull::Up` keeps that assumption true on
+    /// boards without external resistors. `Pull::Down` would hold an unpressed
+    /// button low, so it reads as permanently pressed unless the buttons are
+    /// wired to 3V instead of ground.
+    ///
+    /// # Arguments
+    /// * `config` - Embassy nRF configuration struct for customizing hardware settings
+    /// * `pull` - Internal pull resistor for the button A (P0_14) and B (P0_23) inputs
+    ///
+    /// # Returns
+    /// A fully initialized `Microbit` struct with all peripherals configured
+    ///
+    /// # Example
+    /// ```no_run
+    /// use embassy_nrf::gpio::Pull;
+    ///
+    /// // Clone board without external button pull-ups
+    /// let board = Microbit::with_button_pull(Default::default(), Pull::Up);
+    /// ```
+    #[allow(dead_code)]
+    pub fn with_button_pull(config: embassy_nrf::config::Config, pull: Pull) -> Self {
+        Microbit::build(config, pull, DisplayPins::into_matrix)
+    }
+
+    /// Returns the chip's factory-programmed 64-bit unique identifier.
+    ///
+    /// Reads the FICR DEVICEID registers, which are fixed at manufacture and
+    /// differ between boards. Useful for telling devices apart in radio
+    /// demos. This is a read-only register access and consumes no
+    /// peripherals, so it can be called at any time.
+    ///
+    /// # Returns
+    /// `DEVICEID[1]` in the upper 32 bits and `DEVICEID[0]` in the lower 32 bits
+    ///
+    /// # Example
+    /// ```ignore
+    /// let id = Microbit::device_id();
+    /// display.show_number((id & 0xFFFF) as i32, Duration::from_secs(3)).await;
+    /// ```
+    pub fn device_id() -> u64 {
+        let low = pac::FICR.deviceid(0).read() as u64;
+        let high = pac::FICR.deviceid(1).read() as u64;
+        (high << 32) | low
+    }
+
+    /// Returns why the chip last reset.
+    ///
+    /// Decodes the POWER RESETREAS register without clearing it. This is a
+    /// read-only register access and consumes no peripherals. The bits are
+    /// sticky, so call `clear_reset_reason` once the cause has been handled
+    /// or a later pin or soft reset will report the same cause again.
+    ///
+    /// # Returns
+    /// The most specific reset cause recorded by the hardware
+    ///
+    /// # Example
+    /// ```ignore
+    /// if Microbit::reset_reason() == ResetReason::Watchdog {
+    ///     defmt::warn!("recovered from a hang");
+    /// }
+    /// ```
+    pub fn reset_reason() -> ResetReason {
+        ResetReason::from_bits(pac::POWER.resetreas().read().0)
+    }
+
+    /// Clears the recorded reset reason.
+    ///
+    /// RESETREAS bits are cleared by writing 1 to them, so the bits that are
+    /// currently set are written back. Afterwards `reset_reason` reports
+    /// `PowerOn` until the next reset records a new cause.
+    ///
+    /// # Example
+    /// ```ignore
+    /// defmt::info!("Reset reason: {}", Microbit::reset_reason());
+    /// Microbit::clear_reset_reason();
+    /// ```
+    pub fn clear_reset_reason() {
+        let bits = pac::POWER.resetreas().read();
+        pac::POWER.resetreas().write_value(bits);
+    }
+
+    /// Creates an I2C bus on the edge connector for external devices.
+    ///
+    /// Configures the second TWI/SPI instance (TWISPI1) as an I2C master on
+    /// edge pins P19 (SCL) and P20 (SDA). This bus is separate from the
+    /// internal sensor bus on TWISPI0, so external OLEDs or sensors can be
+    /// attached without disturbing the onboard LSM303AGR.
+    ///
+    /// # Pin Consumption
+    /// Takes ownership of `twispi1`, `p19` and `p20`; none of them can be
+    /// used for anything else afterwards.
+    ///
+    /// # Arguments
+    /// * `twispi1` - Second TWI/SPI peripheral (`board.twispi1`)
+    /// * `p19` - Edge connector pin 19, used as SCL (`board.p19`)
+    /// * `p20` - Edge connector pin 20, used as SDA (`board.p20`)
+    /// * `freq` - Bus clock frequency
+    ///
+    /// # Returns
+    /// An async I2C master ready for transfers
+    ///
+    /// # Example
+    /// ```ignore
+    /// let board = Microbit::default();
+    /// let mut i2c = Microbit::external_i2c(board.twispi1, board.p19, board.p20, twim::Frequency::K100);
+    /// i2c.write(0x3C, &[0x00, 0xAF]).await?; // e.g. turn on an SSD1306 OLED
+    /// ```
+    #[allow(dead_code)]
+    pub fn external_i2c(twispi1: TWISPI1, p19: P0_26, p20: P1_00, freq: twim::Frequency) -> Twim<'static, TWISPI1> {
+        let mut config = twim::Config::default();
+        config.frequency = freq;
+        Twim::new(twispi1, Irqs, p20, p19, config)
+    }
+}
+
+impl Microbit<DisplayPins> {
+    /// Creates a new Microbit instance that keeps the LED matrix pins typed.
+    ///
+    /// Identical to `new` except that `board.display` holds the concrete
+    /// `DisplayPins` instead of a display driver, so edge pins P3, P4, P6,
+    /// P7 and P10 (the matrix columns) keep their `P0_xx` types for
+    /// peripheral drivers that require them. All matrix pins are left
+    /// untouched, so no LEDs light until the pins are driven. Call
+    /// `board.display.into_matrix()` to get the display back afterwards.
+    ///
+    /// `new` and `default` are unchanged and still build the display.
+    ///
+    /// # Arguments
+    /// * `config` - Embassy nRF configuration struct for customizing hardware settings
+    ///
+    /// # Returns
+    /// A `Microbit` whose `display` field is the raw `DisplayPins`
+    ///
+    /// # Example
+    /// ```ignore
+    /// let board = Microbit::new_typed(Default::default());
+    /// let pins = board.display;
+    /// let mut led = SimplePwm::new_1ch(board.pwm1, pins.col3); // edge pin P3
+    /// ```
+    #[allow(dead_code)]
+    pub fn new_typed(config: embassy_nrf::config::Config) -> Self {
+        Microbit::build(config, Pull::None, |pins| pins)
+    }
+
+    /// Creates a new Microbit instance without the LED matrix display.
+    ///
+    /// The `new_typed` counterpart of `default`: uses Embassy's default
+    /// configuration and skips display construction, leaving the ten matrix
+    /// pins in `board.display` for use as plain GPIO or with other drivers.
+    /// Buttons, sensors and every other peripheral are set up as usual.
+    ///
+    /// # Returns
+    /// A `Microbit` whose `display` field is the raw `DisplayPins`
+    ///
+    /// # Example
+    /// ```ignore
+    /// let board = Microbit::without_display();
+    /// let mut p4 = Output::new(board.display.col1, Level::Low, OutputDrive::Standard);
+    /// ```
+    #[allow(dead_code)]
+    pub fn without_display() -> Self {
+        Self::new_typed(Default::default())
+    }
+}
+
+impl<D> Microbit<D> {
+    /// Initializes the chip and hands the matrix pins to `display` to
+    /// produce the board's display field.
+    fn build(config: embassy_nrf::config::Config, pull: Pull, display: impl FnOnce(DisplayPins) -> D) -> Self {
+        let p = embassy_nrf::init(config);
+        let pins = DisplayPins {
+            row1: p.P0_21,
+            row2: p.P0_22,
+            row3: p.P0_15,
+            row4: p.P0_24,
+            row5: p.P0_19,
+            col1: p.P0_28,
+            col2: p.P0_11,
+            col3: p.P0_31,
+            col4: p.P1_05,
+            col5: p.P0_30,
+        };
 
         Self {
-            display: LedMatrixDriver::new(rows, cols),
-            btn_a: Input::new(p.P0_14.degrade(), Pull::None),
-            btn_b: Input::new(p.P0_23.degrade(), Pull::None),
+            display: display(pins),
+            btn_a: Input::new(p.P0_14.degrade(), pull),
+            btn_b: Input::new(p.P0_23.degrade(), pull),
             uarte0: p.UARTE0,
             uarte1: p.UARTE1,
             timer0: p.TIMER0,
             speaker: p.P0_00,
             microphone: p.P0_05,
             micen: p.P0_20,
+            logo: p.P1_04,
             p0: p.P0_02,
             p1: p.P0_03,
             p2: p.P0_04,
@@ -430,18 +847,23 @@ impl Microbit {
             p20: p.P1_00,
             i2c_int_scl: p.P0_08,
             i2c_int_sda: p.P0_16,
-            uart_int_tx: p.P1_08,
-            uart_int_rx: p.P0_06,
+            uart_int_tx: p.P0_06,
+            uart_int_rx: p.P1_08,
             ppi_ch0: p.PPI_CH0,
             ppi_ch1: p.PPI_CH1,
+            ppi_group0: p.PPI_GROUP0,
             twispi0: p.TWISPI0,
             twispi1: p.TWISPI1,
             pwm0: p.PWM0,
             pwm1: p.PWM1,
             pwm2: p.PWM2,
             pwm3: p.PWM3,
+            nvmc: p.NVMC,
+            radio: p.RADIO,
             rng: p.RNG,
             saadc: p.SAADC,
+            temp: p.TEMP,
+            wdt: p.WDT,
         }
     }
 }
@@ -453,14 +875,20 @@ impl Microbit {
 /// - **Drive Strength**: Standard (sufficient for LED matrix)
 /// - **Lifetime**: Static (lives for the entire program duration)
 ///
+/// The pin is returned as a `Flex` so the display driver can briefly turn
+/// it into an input, which `LedMatrix::read_light` needs for light sensing.
+///
 /// # Arguments
 /// * `pin` - Any GPIO pin that implements the `Pin` trait
 ///
 /// # Returns
-/// A configured `Output` pin ready for use
+/// A `Flex` pin configured as an output, ready for use
 ///
 /// # Usage
 /// This function is used internally to configure the LED matrix row and column pins.
-fn output_pin(pin: AnyPin) -> Output<'static> {
-    Output::new(pin, Level::Low, OutputDrive::Standard)
+fn output_pin(pin: AnyPin) -> Flex<'static> {
+    let mut pin = Flex::new(pin);
+    pin.set_low();
+    pin.set_as_output(OutputDrive::Standard);
+    pin
 }
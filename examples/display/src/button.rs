@@ -7,29 +7,139 @@
 //! ## Features
 //! - **Button A Handler**: Left arrow display on button A press
 //! - **Button B Handler**: Right arrow display on button B press
+//! - **Either Button**: Waits for A or B and reports which was pressed
+//! - **A+B Chord**: Waits for both buttons pressed together
+//! - **State Snapshot**: Non-blocking read of both buttons for polling loops
+//! - **Press Timeout**: Waits for a press with an idle timeout
+//! - **Hold to Repeat**: Keyboard-style auto-repeat while a button is held
+//! - **Event Channel**: Background task publishing debounced press/release events
 //! - **Visual Feedback**: Immediate arrow display for user interaction
 //! - **Debug Logging**: Button press events logged for debugging
 //! - **Async Operations**: Non-blocking button response handling
+//! - **Portable Logic**: Helpers work on any `embedded-hal` input pin, so the
+//!   debounce and hold timing can be driven by a mock pin in host tests
 //!
 //! ## Usage
 //! ```ignore
-//! use button::{handle_button_a_press, handle_button_b_press};
+//! use button::{handle_button_a_press, handle_button_b_press, wait_any, Which};
 //!
 //! // In main loop
-//! match select(btn_a.wait_for_low(), btn_b.wait_for_low()).await {
-//!     Either::First(_) => {
+//! match wait_any(&mut btn_a, &mut btn_b).await {
+//!     Which::A => {
 //!         handle_button_a_press(&mut display).await;
 //!     }
-//!     Either::Second(_) => {
+//!     Which::B => {
 //!         handle_button_b_press(&mut display).await;
 //!     }
 //! }
 //! ```
+//!
+//! ## Event Channel Usage
+//! ```ignore
+//! spawner.spawn(button_task(board.btn_a, board.btn_b)).unwrap();
+//! let events = ButtonEvents::new();
+//! loop {
+//!     let event = events.recv().await;
+//!     defmt::info!("{}", event);
+//! }
+//! ```
 
-use crate::board::LedMatrix;
+use crate::board::{Button, LedMatrix};
 use crate::fonts::{ARROW_LEFT, ARROW_RIGHT};
 use crate::types::Frame;
-use embassy_time::Duration;
+use embassy_futures::join::join;
+use embassy_futures::select::{select, Either};
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+use embassy_sync::channel::{Channel, Receiver};
+use embassy_time::{with_timeout, Duration, Instant, Timer};
+use embedded_hal::digital::InputPin;
+use embedded_hal_async::digital::Wait;
+
+/// **A+B Chord Window**
+///
+/// Maximum time between the two presses for them to count as pressing
+/// A and B together. People rarely press both buttons in the same instant.
+pub const AB_WINDOW: Duration = Duration::from_millis(50);
+
+/// **Debounce Time**
+///
+/// How long a button must stay in its new state before `button_task`
+/// reports the change. Contact bounce on the micro:bit buttons settles well
+/// within this.
+pub const DEBOUNCE: Duration = Duration::from_millis(20);
+
+/// **Event Queue Depth**
+///
+/// Number of events buffered between `button_task` and its consumers. A full
+/// press and release is two events, so this holds several rapid presses
+/// while a consumer is busy, e.g. scrolling text.
+const EVENT_QUEUE_DEPTH: usize = 16;
+
+/// Events published by `button_task`, waiting to be received
+static BUTTON_EVENTS: Channel<CriticalSectionRawMutex, ButtonEvent, EVENT_QUEUE_DEPTH> = Channel::new();
+
+/// **Button Pin**
+///
+/// Any active-low input the button helpers can read and wait on, the same
+/// way `LedMatrix` drives any `OutputPin`. The micro:bit's `Button` is one;
+/// a mock pin implementing both traits runs the same logic on the host.
+///
+/// Pin errors are treated as "released" when reading and as an immediate
+/// edge when waiting. The nRF pins cannot fail.
+pub trait ButtonPin: InputPin + Wait {}
+
+impl<P: InputPin + Wait> ButtonPin for P {}
+
+/// Whether `pin` is held down
+fn is_pressed<P: ButtonPin>(pin: &mut P) -> bool {
+    pin.is_low().unwrap_or(false)
+}
+
+/// Wait until `pin` is held down
+async fn wait_pressed<P: ButtonPin>(pin: &mut P) {
+    let _ = pin.wait_for_low().await;
+}
+
+/// Wait until `pin` is released
+async fn wait_released<P: ButtonPin>(pin: &mut P) {
+    let _ = pin.wait_for_high().await;
+}
+
+/// **Button Identifier**
+///
+/// Which front-face button an event refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Which {
+    /// Button A (left)
+    A,
+    /// Button B (right)
+    B,
+}
+
+/// **Button Event Kind**
+///
+/// Whether the button went down or came back up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum EventKind {
+    /// The button was pressed
+    Pressed,
+    /// The button was released
+    Released,
+}
+
+/// **Button Event**
+///
+/// A debounced change of state of one button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ButtonEvent {
+    /// Button that changed
+    pub button: Which,
+    /// Direction of the change
+    pub kind: EventKind,
+}
 
 /// **Display Button Press Feedback**
 ///
@@ -123,3 +233,416 @@ pub async fn handle_button_a_press(display: &mut LedMatrix) {
 pub async fn handle_button_b_press(display: &mut LedMatrix) {
     show_button_press(display, "B", ARROW_RIGHT).await;
 }
+
+/// **Wait For Press With Timeout**
+///
+/// Waits for `button` to be pressed, giving up after `timeout`. A button
+/// that is already held down when this is called counts as pressed
+/// straight away.
+///
+/// # Arguments
+/// * `button` - Button to wait on
+/// * `timeout` - Longest time to wait
+///
+/// # Returns
+/// `true` if the button was pressed before the timeout, `false` otherwise
+///
+/// # Example
+/// ```ignore
+/// if !wait_for_press_timeout(&mut btn_a, Duration::from_secs(10)).await {
+///     defmt::info!("idle");
+/// }
+/// ```
+#[allow(dead_code)]
+pub async fn wait_for_press_timeout<P: ButtonPin>(button: &mut P, timeout: Duration) -> bool {
+    if is_pressed(button) {
+        return true;
+    }
+    matches!(
+        select(wait_pressed(button), Timer::after(timeout)).await,
+        Either::First(_)
+    )
+}
+
+/// **Hold-to-Repeat Events**
+///
+/// Auto-repeat state for one button, created by `hold_repeat`. Each call to
+/// `next` waits for the next repeat event.
+pub struct HoldRepeat<'a, P: ButtonPin = Button> {
+    button: &'a mut P,
+    initial_delay: Duration,
+    repeat_interval: Duration,
+    /// When the next repeat fires, or `None` while waiting for a press
+    next_repeat: Option<Instant>,
+}
+
+#[allow(dead_code)]
+impl<P: ButtonPin> HoldRepeat<'_, P> {
+    /// **Next Repeat Event**
+    ///
+    /// Fires once as soon as the button is pressed, again after the initial
+    /// delay if it is still held, then every repeat interval until it is
+    /// released. A tap shorter than the initial delay fires exactly once.
+    ///
+    /// Cancel-safe: the repeat schedule is kept between calls, so racing
+    /// this against a display refresh in a `select` does not delay repeats.
+    ///
+    /// # Returns
+    /// `true` for a press or repeat, `false` once the button is released;
+    /// the following call waits for a new press
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut repeat = hold_repeat(&mut btn_a, Duration::from_millis(500), Duration::from_millis(100));
+    /// while repeat.next().await {
+    ///     count += 1;
+    /// }
+    /// ```
+    pub async fn next(&mut self) -> bool {
+        match self.next_repeat {
+            None => {
+                wait_pressed(self.button).await;
+                self.next_repeat = Some(Instant::now() + self.initial_delay);
+                true
+            }
+            Some(at) => match select(Timer::at(at), wait_released(self.button)).await {
+                Either::First(_) => {
+                    self.next_repeat = Some(at + self.repeat_interval);
+                    true
+                }
+                Either::Second(_) => {
+                    self.next_repeat = None;
+                    false
+                }
+            },
+        }
+    }
+}
+
+/// **Hold to Repeat**
+///
+/// Classic keyboard auto-repeat for a button: one event on press, then
+/// after `initial_delay` one every `repeat_interval` for as long as the
+/// button stays down. Read the events with `HoldRepeat::next`.
+///
+/// # Arguments
+/// * `button` - Button to watch
+/// * `initial_delay` - Time the button must be held before repeating starts
+/// * `repeat_interval` - Time between repeats after that
+///
+/// # Returns
+/// The repeat state, waiting for the first press
+///
+/// # Example
+/// ```ignore
+/// let mut repeat = hold_repeat(&mut btn_a, Duration::from_millis(500), Duration::from_millis(100));
+/// loop {
+///     if repeat.next().await {
+///         display.increase_brightness();
+///     }
+/// }
+/// ```
+#[allow(dead_code)]
+pub fn hold_repeat<P: ButtonPin>(
+    button: &mut P,
+    initial_delay: Duration,
+    repeat_interval: Duration,
+) -> HoldRepeat<'_, P> {
+    HoldRepeat {
+        button,
+        initial_delay,
+        repeat_interval,
+        next_repeat: None,
+    }
+}
+
+/// **Wait For Either Button**
+///
+/// Waits until button A or button B is pressed and reports which one.
+/// Button A is checked first, so if both are pressed at the same instant,
+/// or both are already held when this is called, the result is `Which::A`.
+///
+/// # Arguments
+/// * `btn_a` - Button A input
+/// * `btn_b` - Button B input
+///
+/// # Returns
+/// The button that was pressed
+///
+/// # Example
+/// ```ignore
+/// match wait_any(&mut btn_a, &mut btn_b).await {
+///     Which::A => handle_button_a_press(&mut display).await,
+///     Which::B => handle_button_b_press(&mut display).await,
+/// }
+/// ```
+pub async fn wait_any<P: ButtonPin>(btn_a: &mut P, btn_b: &mut P) -> Which {
+    match select(wait_pressed(btn_a), wait_pressed(btn_b)).await {
+        Either::First(_) => Which::A,
+        Either::Second(_) => Which::B,
+    }
+}
+
+/// **Wait For A+B Press**
+///
+/// Resolves only once buttons A and B are both held down. The second press
+/// must follow the first within `AB_WINDOW`; a lone press is ignored, and
+/// after one the function waits for that button to be released before
+/// listening again, so holding A and pressing B much later does not count.
+///
+/// # Arguments
+/// * `btn_a` - Button A input
+/// * `btn_b` - Button B input
+///
+/// # Example
+/// ```ignore
+/// wait_for_ab(&mut btn_a, &mut btn_b).await;
+/// display.scroll("Settings").await;
+/// ```
+#[allow(dead_code)]
+pub async fn wait_for_ab<P: ButtonPin>(btn_a: &mut P, btn_b: &mut P) {
+    loop {
+        let (first, second) = match select(wait_pressed(btn_a), wait_pressed(btn_b)).await {
+            Either::First(_) => (&mut *btn_a, &mut *btn_b),
+            Either::Second(_) => (&mut *btn_b, &mut *btn_a),
+        };
+
+        if is_pressed(second) || with_timeout(AB_WINDOW, wait_pressed(second)).await.is_ok() {
+            return;
+        }
+        wait_released(first).await;
+    }
+}
+
+/// **Button State Snapshot**
+///
+/// Which buttons were held down at the moment `buttons` read them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ButtonState {
+    /// Button A was held down
+    pub a_pressed: bool,
+    /// Button B was held down
+    pub b_pressed: bool,
+}
+
+/// **Read Both Buttons**
+///
+/// Takes a non-blocking snapshot of both buttons for code that polls in a
+/// loop instead of awaiting presses, such as a game tick. The buttons are
+/// active low, so a pin reading low counts as pressed. No debouncing is
+/// done; a bouncing contact may read either way for a few milliseconds.
+///
+/// # Arguments
+/// * `btn_a` - Button A input
+/// * `btn_b` - Button B input
+///
+/// # Returns
+/// The current state of both buttons
+///
+/// # Example
+/// ```ignore
+/// let state = buttons(&mut btn_a, &mut btn_b);
+/// if state.a_pressed && state.b_pressed {
+///     snake = Snake::new();
+/// }
+/// ```
+#[allow(dead_code)]
+pub fn buttons<P: ButtonPin>(btn_a: &mut P, btn_b: &mut P) -> ButtonState {
+    ButtonState {
+        a_pressed: is_pressed(btn_a),
+        b_pressed: is_pressed(btn_b),
+    }
+}
+
+/// **Button Monitoring Task**
+///
+/// Watches both buttons and publishes a debounced `ButtonEvent` for every
+/// press and release. Spawn it once, handing over both buttons; events are
+/// read back through `ButtonEvents`.
+///
+/// Events are never dropped: if the queue is full the task waits for a
+/// consumer to catch up, and the released/pressed state is re-read once it
+/// resumes, so every press is still paired with a release.
+///
+/// # Arguments
+/// * `btn_a` - Button A input
+/// * `btn_b` - Button B input
+///
+/// # Example
+/// ```ignore
+/// spawner.spawn(button_task(board.btn_a, board.btn_b)).unwrap();
+/// ```
+#[embassy_executor::task]
+#[allow(dead_code)]
+pub async fn button_task(btn_a: Button, btn_b: Button) {
+    join(monitor_button(btn_a, Which::A), monitor_button(btn_b, Which::B)).await;
+}
+
+/// Publish debounced press and release events for one button forever
+async fn monitor_button<P: ButtonPin>(mut button: P, which: Which) {
+    loop {
+        wait_for_stable_level(&mut button, true).await;
+        BUTTON_EVENTS
+            .send(ButtonEvent {
+                button: which,
+                kind: EventKind::Pressed,
+            })
+            .await;
+
+        wait_for_stable_level(&mut button, false).await;
+        BUTTON_EVENTS
+            .send(ButtonEvent {
+                button: which,
+                kind: EventKind::Released,
+            })
+            .await;
+    }
+}
+
+/// **Wait For Stable Level**
+///
+/// Waits until the button has been held (`low == true`) or released for
+/// `DEBOUNCE`, ignoring shorter bounces. This is the debounce step behind
+/// `button_task`.
+///
+/// # Arguments
+/// * `button` - Button to watch
+/// * `low` - `true` to wait for a press, `false` for a release
+///
+/// # Example
+/// ```ignore
+/// wait_for_stable_level(&mut btn_a, true).await;
+/// defmt::info!("A down");
+/// ```
+pub async fn wait_for_stable_level<P: ButtonPin>(button: &mut P, low: bool) {
+    loop {
+        if low {
+            wait_pressed(button).await;
+        } else {
+            wait_released(button).await;
+        }
+        Timer::after(DEBOUNCE).await;
+        if is_pressed(button) == low {
+            return;
+        }
+    }
+}
+
+/// **Button Event Receiver**
+///
+/// Handle for reading events published by `button_task`. Handles are cheap
+/// and several can exist at once; each event is delivered to exactly one of
+/// them, whichever asks first.
+#[derive(Clone, Copy)]
+pub struct ButtonEvents {
+    receiver: Receiver<'static, CriticalSectionRawMutex, ButtonEvent, EVENT_QUEUE_DEPTH>,
+}
+
+#[allow(dead_code)]
+impl ButtonEvents {
+    /// **Create Receiver**
+    ///
+    /// # Example
+    /// ```ignore
+    /// let events = ButtonEvents::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            receiver: BUTTON_EVENTS.receiver(),
+        }
+    }
+
+    /// **Receive Event**
+    ///
+    /// Waits for the next button event. Cancel-safe: if the returned future
+    /// is dropped (e.g. losing a `select`), no event is consumed.
+    ///
+    /// # Returns
+    /// The oldest unread event
+    ///
+    /// # Example
+    /// ```ignore
+    /// match events.recv().await {
+    ///     ButtonEvent { button: Which::A, kind: EventKind::Pressed } => defmt::info!("A down"),
+    ///     _ => {}
+    /// }
+    /// ```
+    pub async fn recv(&self) -> ButtonEvent {
+        self.receiver.receive().await
+    }
+
+    /// **Try Receive Event**
+    ///
+    /// # Returns
+    /// The oldest unread event, or `None` if none is queued
+    pub fn try_recv(&self) -> Option<ButtonEvent> {
+        self.receiver.try_receive().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{advance, mock_clock, poll_once, MockButton};
+    use core::cell::Cell;
+    use core::pin::pin;
+    use core::task::Poll;
+
+    #[test]
+    fn long_press_repeats_until_release() {
+        let _clock = mock_clock();
+        let pressed = Cell::new(true);
+        let mut button = MockButton(&pressed);
+        let mut repeat = hold_repeat(&mut button, Duration::from_millis(500), Duration::from_millis(100));
+        let mut events = Vec::new();
+        // Hold for 750 ms, checking every 10 ms
+        for ms in (0..=750).step_by(10) {
+            pressed.set(ms < 750);
+            let mut next = pin!(repeat.next());
+            // Timers always yield once, even when already due
+            let event = match poll_once(next.as_mut()) {
+                Poll::Pending => poll_once(next.as_mut()),
+                ready => ready,
+            };
+            if let Poll::Ready(held) = event {
+                events.push((ms, held));
+            }
+            advance(Duration::from_millis(10));
+        }
+        assert_eq!(events, [(0, true), (500, true), (600, true), (700, true), (750, false)]);
+    }
+
+    #[test]
+    fn bounces_shorter_than_debounce_are_ignored() {
+        let _clock = mock_clock();
+        let pressed = Cell::new(true);
+        let mut button = MockButton(&pressed);
+        let mut press = pin!(wait_for_stable_level(&mut button, true));
+        assert_eq!(poll_once(press.as_mut()), Poll::Pending);
+        // Bounces open halfway through the debounce time
+        advance(DEBOUNCE / 2);
+        pressed.set(false);
+        assert_eq!(poll_once(press.as_mut()), Poll::Pending);
+        advance(DEBOUNCE / 2);
+        assert_eq!(poll_once(press.as_mut()), Poll::Pending);
+        // Closes again: a full debounce time from here is needed
+        pressed.set(true);
+        assert_eq!(poll_once(press.as_mut()), Poll::Pending);
+        advance(DEBOUNCE - Duration::from_millis(1));
+        assert_eq!(poll_once(press.as_mut()), Poll::Pending);
+        advance(Duration::from_millis(1));
+        assert_eq!(poll_once(press.as_mut()), Poll::Ready(()));
+    }
+
+    #[test]
+    fn snapshot_reports_each_combination() {
+        let (a, b) = (Cell::new(false), Cell::new(false));
+        let (mut btn_a, mut btn_b) = (MockButton(&a), MockButton(&b));
+        for (a_pressed, b_pressed) in [(false, false), (true, false), (false, true), (true, true)] {
+            a.set(a_pressed);
+            b.set(b_pressed);
+            assert_eq!(buttons(&mut btn_a, &mut btn_b), ButtonState { a_pressed, b_pressed });
+        }
+    }
+}
@@ -0,0 +1,283 @@
+//! # Console Module
+//!
+//! This module provides a line-based command console over the micro:bit's
+//! internal debug UART. The interface chip bridges that UART to the USB
+//! serial port, so any terminal program (115200 baud, 8N1) on the host can
+//! drive the display.
+//!
+//! ## Features
+//! - **Line Editing**: Typed characters are echoed and backspace erases
+//! - **Buffered Input**: Input keeps arriving while echoes are written, so
+//!   pasted text is not lost
+//! - **Bounded Buffers**: Lines are collected in a fixed `heapless::String`
+//! - **Long Input Handling**: Overly long lines are discarded up to the next
+//!   newline and reported, instead of being truncated into a wrong command
+//! - **Command Parsing**: `scroll <text>`, `bright <0-10>` and `clear`
+//!
+//! ## Usage Example
+//! ```ignore
+//! // `uart` is a `board::DebugUart` holding the debug UART peripherals
+//! let (mut rx_buffer, mut tx_buffer) = ([0u8; 64], [0u8; 64]);
+//! let mut console = Console::new(uart, &mut rx_buffer, &mut tx_buffer);
+//! loop {
+//!     match console.next_command().await {
+//!         Command::Scroll(text) => display.scroll(&text).await,
+//!         Command::Bright(level) => display.set_brightness(Brightness::new(level)),
+//!         Command::Clear => display.clear(),
+//!     }
+//! }
+//! ```
+
+use embassy_nrf::buffered_uarte::BufferedUarte;
+use embassy_nrf::peripherals::{TIMER0, UARTE0};
+use heapless::String;
+
+use crate::board::DebugUart;
+use crate::types::Brightness;
+
+/// **Line Capacity**
+///
+/// Longest line, in bytes, the console accepts. Longer input is rejected
+/// with `ParseError::TooLong`.
+pub const LINE_CAPACITY: usize = 64;
+
+/// **Prompt**
+///
+/// Written before every line the console reads.
+const PROMPT: &str = "> ";
+
+/// **Console Command**
+///
+/// A successfully parsed line of console input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Command {
+    /// `scroll <text>`: scroll the text across the display
+    Scroll(String<LINE_CAPACITY>),
+    /// `bright <0-10>`: set the display brightness level
+    Bright(u8),
+    /// `clear`: turn every LED off
+    Clear,
+}
+
+/// **Parse Errors**
+///
+/// Reasons a console line could not be turned into a `Command`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ParseError {
+    /// The line held nothing but whitespace
+    Empty,
+    /// The first word is not a known command
+    UnknownCommand,
+    /// The command needs an argument that was not given
+    MissingArgument,
+    /// The brightness argument is not a number from 0 to 10
+    InvalidBrightness,
+    /// The line was longer than `LINE_CAPACITY` bytes
+    TooLong,
+}
+
+impl ParseError {
+    /// **Error Message**
+    ///
+    /// # Returns
+    /// A short human-readable description written back to the terminal
+    pub fn message(self) -> &'static str {
+        match self {
+            ParseError::Empty => "empty line",
+            ParseError::UnknownCommand => "unknown command (try scroll, bright, clear)",
+            ParseError::MissingArgument => "missing argument",
+            ParseError::InvalidBrightness => "brightness must be 0-10",
+            ParseError::TooLong => "line too long",
+        }
+    }
+}
+
+/// **Parse Command Line**
+///
+/// Splits off the first word as the command name and parses the rest of the
+/// line as its argument. Command names are case-insensitive; the `scroll`
+/// text is kept exactly as typed apart from surrounding whitespace.
+///
+/// # Arguments
+/// * `line` - One line of input without its line terminator
+///
+/// # Returns
+/// The parsed `Command`, or the reason the line was rejected
+///
+/// # Example
+/// ```ignore
+/// assert_eq!(parse_command("bright 7"), Ok(Command::Bright(7)));
+/// assert_eq!(parse_command("bright 11"), Err(ParseError::InvalidBrightness));
+/// ```
+pub fn parse_command(line: &str) -> Result<Command, ParseError> {
+    let line = line.trim();
+    if line.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    if line.len() > LINE_CAPACITY {
+        return Err(ParseError::TooLong);
+    }
+
+    let (name, arg) = match line.split_once(char::is_whitespace) {
+        Some((name, arg)) => (name, arg.trim()),
+        None => (line, ""),
+    };
+
+    if name.eq_ignore_ascii_case("scroll") {
+        if arg.is_empty() {
+            return Err(ParseError::MissingArgument);
+        }
+        let mut text = String::new();
+        text.push_str(arg).map_err(|_| ParseError::TooLong)?;
+        Ok(Command::Scroll(text))
+    } else if name.eq_ignore_ascii_case("bright") {
+        if arg.is_empty() {
+            return Err(ParseError::MissingArgument);
+        }
+        match arg.parse::<u8>() {
+            Ok(level) if level <= Brightness::MAX.level() => Ok(Command::Bright(level)),
+            _ => Err(ParseError::InvalidBrightness),
+        }
+    } else if name.eq_ignore_ascii_case("clear") {
+        Ok(Command::Clear)
+    } else {
+        Err(ParseError::UnknownCommand)
+    }
+}
+
+/// **Serial Console**
+///
+/// Reads newline-terminated commands from the debug UART, echoing input and
+/// answering bad lines with an error message.
+pub struct Console<'d> {
+    uarte: BufferedUarte<'d, UARTE0, TIMER0>,
+}
+
+#[allow(dead_code)]
+impl<'d> Console<'d> {
+    /// **Create Console**
+    ///
+    /// Starts the debug UART at 115200 baud, 8N1. Input is received into
+    /// `rx_buffer` in the background, so nothing typed or pasted is lost
+    /// while the console is busy echoing or the caller is updating the
+    /// display.
+    ///
+    /// # Arguments
+    /// * `uart` - Debug UART peripherals
+    /// * `rx_buffer` - Ring buffer for received bytes; its length must be even
+    /// * `tx_buffer` - Ring buffer for echoes and messages waiting to be sent
+    ///
+    /// # Example
+    /// ```ignore
+    /// let (mut rx_buffer, mut tx_buffer) = ([0u8; 64], [0u8; 64]);
+    /// let mut console = Console::new(uart, &mut rx_buffer, &mut tx_buffer);
+    /// ```
+    pub fn new(uart: DebugUart, rx_buffer: &'d mut [u8], tx_buffer: &'d mut [u8]) -> Self {
+        Self {
+            uarte: uart.into_buffered(rx_buffer, tx_buffer),
+        }
+    }
+
+    /// **Write Text**
+    ///
+    /// Sends `text` to the terminal. Transmit errors are ignored, since
+    /// there is nowhere better to report them.
+    ///
+    /// # Arguments
+    /// * `text` - Text to send; use `\r\n` for line breaks
+    pub async fn write(&mut self, text: &str) {
+        self.write_bytes(text.as_bytes()).await;
+    }
+
+    /// Queue every byte for sending, stopping early on a transmit error
+    async fn write_bytes(&mut self, mut bytes: &[u8]) {
+        while !bytes.is_empty() {
+            match self.uarte.write(bytes).await {
+                Ok(written) => bytes = &bytes[written..],
+                Err(_) => return,
+            }
+        }
+    }
+
+    /// Wait for the next received byte
+    async fn read_byte(&mut self) -> u8 {
+        let mut byte = [0u8; 1];
+        loop {
+            match self.uarte.read(&mut byte).await {
+                Ok(1) => return byte[0],
+                Ok(_) => {}
+                Err(e) => defmt::warn!("console read error: {}", e),
+            }
+        }
+    }
+
+    /// **Next Command**
+    ///
+    /// Prompts for and reads lines until one parses, writing the error
+    /// message for every rejected line. Input past `LINE_CAPACITY` bytes is
+    /// dropped up to the end of the line and the whole line is rejected.
+    ///
+    /// # Returns
+    /// The first valid `Command` typed at the terminal
+    ///
+    /// # Example
+    /// ```ignore
+    /// if let Command::Clear = console.next_command().await {
+    ///     display.clear();
+    /// }
+    /// ```
+    pub async fn next_command(&mut self) -> Command {
+        loop {
+            self.write(PROMPT).await;
+            let result = match self.read_line().await {
+                Some(line) => parse_command(&line),
+                None => Err(ParseError::TooLong),
+            };
+            match result {
+                Ok(command) => return command,
+                Err(ParseError::Empty) => {}
+                Err(e) => {
+                    self.write("error: ").await;
+                    self.write(e.message()).await;
+                    self.write("\r\n").await;
+                }
+            }
+        }
+    }
+
+    /// **Read Line**
+    ///
+    /// Collects bytes up to a carriage return or line feed, echoing them and
+    /// handling backspace. Non-ASCII bytes are ignored.
+    ///
+    /// # Returns
+    /// The line, or `None` if it overflowed `LINE_CAPACITY`
+    async fn read_line(&mut self) -> Option<String<LINE_CAPACITY>> {
+        let mut line: String<LINE_CAPACITY> = String::new();
+        let mut overflowed = false;
+
+        loop {
+            let byte = self.read_byte().await;
+            match byte {
+                b'\r' | b'\n' => {
+                    self.write("\r\n").await;
+                    return if overflowed { None } else { Some(line) };
+                }
+                0x08 | 0x7F if !line.is_empty() => {
+                    line.pop();
+                    self.write("\x08 \x08").await;
+                }
+                b @ 0x20..=0x7E => {
+                    if overflowed || line.push(b as char).is_err() {
+                        overflowed = true;
+                    } else {
+                        self.write_bytes(&[byte]).await;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,1590 @@
+//! # Demo Module
+//!
+//! This module collects small, self-contained demonstrations of the board
+//! support features. Each demo receives exactly the peripherals it needs, so
+//! it can be awaited from the main application in place of the default
+//! button loop without any further setup.
+//!
+//! ## Usage
+//! ```ignore
+//! let board = Microbit::default();
+//! let mut display = board.display;
+//! let mut btn_a = board.btn_a;
+//! demos::counter_demo(&mut display, &mut btn_a).await;
+//! ```
+
+use core::fmt::Write;
+use embassy_futures::join::join;
+use embassy_futures::select::{select, select3, Either, Either3};
+
+use embassy_nrf::gpio::{Level, Output, OutputDrive};
+use embassy_nrf::peripherals::{P0_31, PWM1, RNG, SAADC, TWISPI1};
+use embassy_nrf::pwm::{self, SimplePwm};
+use embassy_nrf::rng::Rng;
+use embassy_nrf::twim::Twim;
+use embassy_sync::blocking_mutex::raw::NoopRawMutex;
+use embassy_sync::channel::Channel;
+use embassy_time::{with_timeout, Duration, Instant, Timer};
+use heapless::String;
+
+use crate::analog::{wait_for_clap, AnalogInput, Battery, Microphone, CLAP_THRESHOLD, LOW_BATTERY_MV};
+use crate::board::{Button, DisplayPins, LedMatrix, Microbit};
+use crate::button::{hold_repeat, wait_for_press_timeout, ButtonEvent, ButtonEvents, EventKind, Which, AB_WINDOW};
+use crate::console::{Command, Console};
+use crate::display::{refresh_rate, Animation, AnimationData, AnimationEffect, AnimationState, Frame};
+use crate::fonts::{dice_face, frame_5x5, spinner_frame, ARROW_LEFT, ARROW_RIGHT, BATTERY_LOW, CHECK_MARK, CROSS_MARK};
+use crate::format::format_fixed;
+use crate::games::{BouncingBall, GameState, Snake};
+use crate::motion::{
+    tilt_direction, AccelStream, Accelerometer, Gesture, Magnetometer, TiltCursor, FREEFALL_DURATION, SHAKE_THRESHOLD,
+    TILT_DEADZONE,
+};
+use crate::packet_radio::{Radio, MAX_PAYLOAD};
+use crate::serial::FrameReceiver;
+use crate::servo::{Servo, MAX_ANGLE};
+use crate::speaker::Speaker;
+use crate::stopwatch::Stopwatch;
+use crate::storage::{Settings, Storage};
+use crate::system::{ResetReason, TempSensor, Watchdog};
+use crate::touch::TouchPad;
+use crate::types::Brightness;
+use crate::widgets::{cursor_frame, level_columns_frame, level_frame, needle_frame, Scope};
+
+/// **Button Counter Demo**
+///
+/// Counts presses of a button and shows the running total on the display
+/// after every press. The counter waits for the button to be released
+/// before accepting the next press, so holding it counts only once.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `button` - Button whose presses are counted (typically button A)
+#[allow(dead_code)]
+pub async fn counter_demo(display: &mut LedMatrix, button: &mut Button) {
+    let mut count: i32 = 0;
+    loop {
+        button.wait_for_low().await;
+        count = count.wrapping_add(1);
+        display.show_number(count, Duration::from_secs(1)).await;
+        button.wait_for_high().await;
+    }
+}
+
+/// **Game of Life Demo**
+///
+/// Seeds a glider in the top-left corner and animates successive Game of
+/// Life generations. Off-grid cells count as dead, so the glider settles
+/// into a still life at the bottom-right edge; the world is reseeded as
+/// soon as it stops changing.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+#[allow(dead_code)]
+pub async fn life_demo(display: &mut LedMatrix) {
+    #[rustfmt::skip]
+    const GLIDER: Frame<5, 5> = frame_5x5(&[
+        0b01000,
+        0b00100,
+        0b11100,
+        0b00000,
+        0b00000,
+    ]);
+
+    let mut world = GLIDER;
+    loop {
+        display.display(world, Duration::from_millis(400)).await;
+        let next = world.life_step();
+        world = if next == world { GLIDER } else { next };
+    }
+}
+
+/// **Shake to Roll Dice Demo**
+///
+/// Waits for the board to be shaken, then rolls a six-sided die with the
+/// hardware random number generator and shows the result as dice pips.
+/// Bus errors from the accelerometer are logged and the demo keeps waiting for shakes.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `accel` - Accelerometer used to detect the shake
+/// * `rng` - Hardware random number generator
+///
+/// # Example
+/// ```ignore
+/// let mut accel = Accelerometer::new(board.twispi0, board.i2c_int_sda, board.i2c_int_scl).await?;
+/// let mut rng = Rng::new(board.rng, Irqs);
+/// demos::shake_dice_demo(&mut display, &mut accel, &mut rng).await;
+/// ```
+#[allow(dead_code)]
+pub async fn shake_dice_demo(display: &mut LedMatrix, accel: &mut Accelerometer, rng: &mut Rng<'static, RNG>) {
+    loop {
+        if let Err(e) = accel.wait_for_shake(SHAKE_THRESHOLD).await {
+            defmt::warn!("accelerometer error: {}", e);
+            continue;
+        }
+        let roll = roll_die(rng).await;
+        display.display(dice_face(roll), Duration::from_secs(2)).await;
+    }
+}
+
+/// **Button Dice Demo**
+///
+/// Rolls a die each time button A is pressed and shows the result as pips.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `button` - Button that rolls the die
+/// * `rng` - Hardware random number generator
+///
+/// # Example
+/// ```ignore
+/// let mut rng = Rng::new(board.rng, Irqs);
+/// demos::dice_demo(&mut display, &mut btn_a, &mut rng).await;
+/// ```
+#[allow(dead_code)]
+pub async fn dice_demo(display: &mut LedMatrix, button: &mut Button, rng: &mut Rng<'static, RNG>) {
+    loop {
+        button.wait_for_low().await;
+        let roll = roll_die(rng).await;
+        defmt::info!("rolled {}", roll);
+        display.display(dice_face(roll), Duration::from_secs(2)).await;
+        button.wait_for_high().await;
+    }
+}
+
+/// Roll a fair six-sided die, rejecting bytes that would bias the result
+async fn roll_die(rng: &mut Rng<'static, RNG>) -> u8 {
+    let mut byte = [0u8; 1];
+    loop {
+        rng.fill_bytes(&mut byte).await;
+        // 252 is the largest multiple of 6 that fits in a byte
+        if byte[0] < 252 {
+            return byte[0] % 6 + 1;
+        }
+    }
+}
+
+/// **Tilt Marble Demo**
+///
+/// Moves a single lit pixel around the matrix in the direction the board
+/// is tilted, stopping at the edges. The marble starts in the center and
+/// moves one step every 150 ms while the board stays tilted; the tilt is
+/// sampled more often, so a fresh tilt responds straight away.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `accel` - Accelerometer used to read the tilt
+#[allow(dead_code)]
+pub async fn tilt_demo(display: &mut LedMatrix, accel: &mut Accelerometer) {
+    let mut cursor = TiltCursor::new((2, 2), Duration::from_millis(150));
+    loop {
+        match accel.read().await {
+            Ok(a) => {
+                cursor.update(a.x, a.y, TILT_DEADZONE, Instant::now());
+            }
+            Err(e) => defmt::warn!("accelerometer error: {}", e),
+        }
+        display
+            .display(cursor_frame(cursor.position()), Duration::from_millis(30))
+            .await;
+    }
+}
+
+/// **Alarm Siren Demo**
+///
+/// Shows the cross mark while a rising siren sweeps from 400 Hz to
+/// 1.6 kHz, then pauses before sounding again. The display keeps
+/// multiplexing while the speaker sweeps, since both run concurrently.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `speaker` - Onboard speaker driver
+#[allow(dead_code)]
+pub async fn siren_demo(display: &mut LedMatrix, speaker: &mut Speaker) {
+    let length = Duration::from_millis(800);
+    loop {
+        join(display.display(CROSS_MARK, length), speaker.sweep(400, 1600, length)).await;
+        Timer::after(Duration::from_millis(400)).await;
+    }
+}
+
+/// **Analog Readout Demo**
+///
+/// Repeatedly reads an analog input (for example a potentiometer on edge
+/// pin P0) and scrolls the voltage in millivolts.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `input` - Analog input to read
+///
+/// # Example
+/// ```ignore
+/// let mut pot = AnalogInput::new(board.saadc, board.p0);
+/// demos::analog_demo(&mut display, &mut pot).await;
+/// ```
+#[allow(dead_code)]
+pub async fn analog_demo(display: &mut LedMatrix, input: &mut AnalogInput) {
+    input.calibrate().await;
+    loop {
+        let millivolts = input.read_millivolts().await;
+        display.show_number(millivolts as i32, Duration::from_secs(2)).await;
+    }
+}
+
+/// **Servo Control Demo**
+///
+/// Holding button A turns the servo toward 0°, holding button B turns it
+/// toward 180°. The current angle is shown as a horizontal level meter,
+/// from an empty matrix at 0° to a full one at 180°.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `btn_a` - Button that decreases the angle
+/// * `btn_b` - Button that increases the angle
+/// * `servo` - Servo to control
+///
+/// # Example
+/// ```ignore
+/// let mut servo = Servo::new(board.pwm1, board.p0);
+/// demos::servo_demo(&mut display, &mut btn_a, &mut btn_b, &mut servo).await;
+/// ```
+#[allow(dead_code)]
+pub async fn servo_demo<T: pwm::Instance>(
+    display: &mut LedMatrix,
+    btn_a: &mut Button,
+    btn_b: &mut Button,
+    servo: &mut Servo<T>,
+) {
+    const STEP: u8 = 10;
+    loop {
+        let mut angle = servo.angle();
+        if btn_a.is_low() {
+            angle = angle.saturating_sub(STEP);
+        }
+        if btn_b.is_low() {
+            angle = angle.saturating_add(STEP).min(MAX_ANGLE);
+        }
+        servo.set_angle(angle);
+        display
+            .display(level_columns_frame(angle, MAX_ANGLE), Duration::from_millis(100))
+            .await;
+    }
+}
+
+/// **I2C Bus Scan Demo**
+///
+/// Probes every 7-bit address on an I2C bus with a one-byte read and
+/// scrolls the hexadecimal address of each device that acknowledges,
+/// followed by the number of devices found.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `i2c` - External I2C bus to scan
+///
+/// # Example
+/// ```ignore
+/// let mut i2c = Microbit::external_i2c(board.twispi1, board.p19, board.p20, twim::Frequency::K100);
+/// demos::i2c_scan_demo(&mut display, &mut i2c).await;
+/// ```
+#[allow(dead_code)]
+pub async fn i2c_scan_demo(display: &mut LedMatrix, i2c: &mut Twim<'static, TWISPI1>) {
+    let mut found = 0;
+    // 0x00-0x07 and 0x78-0x7F are reserved addresses
+    for address in 0x08..0x78u8 {
+        let mut byte = [0u8; 1];
+        if i2c.read(address, &mut byte).await.is_ok() {
+            let mut text: heapless::String<4> = heapless::String::new();
+            write!(text, "{:02X}", address).ok();
+            display.scroll(&text).await;
+            found += 1;
+        }
+    }
+    display.show_number(found, Duration::from_secs(1)).await;
+}
+
+/// **Radio Ping Demo**
+///
+/// Run on two boards flashed with this firmware and set to the same group:
+/// pressing button A on one board lights the center LED on the other. Both
+/// boards run the same code, so either can ping the other.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `button` - Button that sends a ping (typically button A)
+/// * `radio` - Radio configured with the shared group and channel
+///
+/// # Example
+/// ```ignore
+/// let mut radio = Radio::new(board.radio, 1, DEFAULT_CHANNEL);
+/// demos::radio_ping_demo(&mut display, &mut btn_a, &mut radio).await;
+/// ```
+#[allow(dead_code)]
+pub async fn radio_ping_demo(display: &mut LedMatrix, button: &mut Button, radio: &mut Radio) {
+    const PING: u8 = 1;
+    #[rustfmt::skip]
+    const DOT: Frame<5, 5> = frame_5x5(&[
+        0b00000,
+        0b00000,
+        0b00100,
+        0b00000,
+        0b00000,
+    ]);
+
+    let mut buf = [0u8; MAX_PAYLOAD];
+    loop {
+        match select(button.wait_for_low(), radio.receive(&mut buf)).await {
+            Either::First(_) => {
+                let group = radio.group();
+                if let Err(e) = radio.send(group, &[PING]).await {
+                    defmt::warn!("radio send failed: {}", e);
+                }
+                button.wait_for_high().await;
+            }
+            Either::Second(len) => {
+                if len > 0 && buf[0] == PING {
+                    display.display(DOT, Duration::from_millis(500)).await;
+                }
+            }
+        }
+    }
+}
+
+/// **Saved Brightness Demo**
+///
+/// Restores the brightness saved in flash on boot, then lets button A dim
+/// and button B brighten a fully lit matrix. The new level is written back
+/// to flash when the button is released, so it survives a reset.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `btn_a` - Button that lowers the brightness
+/// * `btn_b` - Button that raises the brightness
+/// * `storage` - Flash settings storage
+///
+/// # Example
+/// ```ignore
+/// let mut storage = Storage::new(board.nvmc);
+/// demos::brightness_settings_demo(&mut display, &mut btn_a, &mut btn_b, &mut storage).await;
+/// ```
+#[allow(dead_code)]
+pub async fn brightness_settings_demo(
+    display: &mut LedMatrix,
+    btn_a: &mut Button,
+    btn_b: &mut Button,
+    storage: &mut Storage,
+) {
+    #[rustfmt::skip]
+    const FULL: Frame<5, 5> = frame_5x5(&[
+        0b11111,
+        0b11111,
+        0b11111,
+        0b11111,
+        0b11111,
+    ]);
+
+    let mut settings = storage.load().unwrap_or_default();
+    defmt::info!("restored settings: {}", settings);
+    let mut brightness = Brightness::new(settings.brightness);
+    loop {
+        display.set_brightness(brightness);
+        display.display(FULL, Duration::from_millis(100)).await;
+
+        if btn_a.is_low() {
+            brightness -= 1;
+        } else if btn_b.is_low() {
+            brightness += 1;
+        } else if brightness.level() != settings.brightness {
+            // Save once the button is released, so holding it down doesn't
+            // erase the flash page on every step
+            settings = Settings {
+                brightness: brightness.level(),
+                ..settings
+            };
+            if let Err(e) = storage.store(&settings) {
+                defmt::warn!("failed to save settings: {}", e);
+            }
+        }
+    }
+}
+
+/// **Touch Thermometer Demo**
+///
+/// Touching the gold logo shows the chip temperature in degrees Celsius
+/// with one decimal place, e.g. "23.5". The reading updates on every touch.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `logo` - Capacitive logo touch pad
+/// * `sensor` - On-die temperature sensor
+///
+/// # Example
+/// ```ignore
+/// let mut logo = TouchPad::new(board.logo);
+/// let mut sensor = TempSensor::new(board.temp);
+/// demos::temperature_demo(&mut display, &mut logo, &mut sensor).await;
+/// ```
+#[allow(dead_code)]
+pub async fn temperature_demo(display: &mut LedMatrix, logo: &mut TouchPad, sensor: &mut TempSensor) {
+    loop {
+        logo.wait_for_touch().await;
+        let tenths = sensor.read().await;
+        defmt::info!("temperature: {} tenths of a degree", tenths);
+        display.show_fixed(tenths as i32, 1, Duration::from_secs(2)).await;
+        logo.wait_for_release().await;
+    }
+}
+
+/// **A, B and A+B Demo**
+///
+/// Tells the three button inputs apart: a left arrow for A alone, a right
+/// arrow for B alone and a check mark for both pressed together. A single
+/// press is only reported once the `AB_WINDOW` for the other button has
+/// passed, so an A+B chord never flashes a single-button icon first.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `btn_a` - Button A input
+/// * `btn_b` - Button B input
+///
+/// # Example
+/// ```ignore
+/// demos::ab_demo(&mut display, &mut btn_a, &mut btn_b).await;
+/// ```
+#[allow(dead_code)]
+pub async fn ab_demo(display: &mut LedMatrix, btn_a: &mut Button, btn_b: &mut Button) {
+    loop {
+        let (icon, first, second) = match select(btn_a.wait_for_low(), btn_b.wait_for_low()).await {
+            Either::First(_) => (ARROW_LEFT, &mut *btn_a, &mut *btn_b),
+            Either::Second(_) => (ARROW_RIGHT, &mut *btn_b, &mut *btn_a),
+        };
+
+        let chord = second.is_low() || with_timeout(AB_WINDOW, second.wait_for_low()).await.is_ok();
+        display
+            .display(if chord { CHECK_MARK } else { icon }, Duration::from_secs(1))
+            .await;
+
+        // Wait for a full release so a held button doesn't retrigger
+        join(first.wait_for_high(), second.wait_for_high()).await;
+    }
+}
+
+/// **Button Events Demo**
+///
+/// Consumes events from the background `button_task` and shows the matching
+/// arrow for one second on every press. Because events are queued, quick
+/// taps made while an arrow is still showing are replayed afterwards rather
+/// than lost.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `events` - Receiver for events published by `button_task`
+///
+/// # Example
+/// ```ignore
+/// spawner.spawn(button::button_task(board.btn_a, board.btn_b)).unwrap();
+/// demos::button_events_demo(&mut display, ButtonEvents::new()).await;
+/// ```
+#[allow(dead_code)]
+pub async fn button_events_demo(display: &mut LedMatrix, events: ButtonEvents) {
+    loop {
+        let event = events.recv().await;
+        defmt::info!("{}", event);
+        let arrow = match event {
+            ButtonEvent {
+                kind: EventKind::Released,
+                ..
+            } => continue,
+            ButtonEvent { button: Which::A, .. } => ARROW_LEFT,
+            ButtonEvent { button: Which::B, .. } => ARROW_RIGHT,
+        };
+        display.display(arrow, Duration::from_secs(1)).await;
+    }
+}
+
+/// **Screensaver Demo**
+///
+/// Shows a check mark for every press of the button. After 10 seconds
+/// without a press, a dot starts circling the edge of the matrix until the
+/// button is pressed again, which wakes the display without counting as a
+/// press.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `button` - Button that counts as user input
+///
+/// # Example
+/// ```ignore
+/// demos::screensaver_demo(&mut display, &mut btn_a).await;
+/// ```
+#[allow(dead_code)]
+pub async fn screensaver_demo(display: &mut LedMatrix, button: &mut Button) {
+    const IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+    // Border positions in clockwise order, starting at the top-left corner
+    #[rustfmt::skip]
+    const BORDER: [(usize, usize); 16] = [
+        (0, 0), (1, 0), (2, 0), (3, 0), (4, 0),
+        (4, 1), (4, 2), (4, 3), (4, 4),
+        (3, 4), (2, 4), (1, 4), (0, 4),
+        (0, 3), (0, 2), (0, 1),
+    ];
+
+    loop {
+        if wait_for_press_timeout(button, IDLE_TIMEOUT).await {
+            display.display(CHECK_MARK, Duration::from_secs(1)).await;
+        } else {
+            let screensaver = async {
+                for &(x, y) in BORDER.iter().cycle() {
+                    let mut frame = Frame::empty();
+                    frame.set(x, y);
+                    display.display(frame, Duration::from_millis(100)).await;
+                }
+            };
+            select(button.wait_for_low(), screensaver).await;
+        }
+        button.wait_for_high().await;
+    }
+}
+
+/// **Idle Blank Demo**
+///
+/// Shows a check mark while the board is in use. After 10 seconds without
+/// a press the display is powered down so the CPU can sleep, and the next
+/// press wakes it again without counting as input.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `button` - Button that counts as user input
+///
+/// # Example
+/// ```ignore
+/// demos::idle_blank_demo(&mut display, &mut btn_a).await;
+/// ```
+#[allow(dead_code)]
+pub async fn idle_blank_demo(display: &mut LedMatrix, button: &mut Button) {
+    const IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+    loop {
+        match select(button.wait_for_low(), display.display(CHECK_MARK, IDLE_TIMEOUT)).await {
+            Either::First(_) => defmt::info!("input, idle timer restarted"),
+            Either::Second(_) => {
+                defmt::info!("idle, display off");
+                display.power_down();
+                button.wait_for_low().await;
+                display.power_up();
+            }
+        }
+        button.wait_for_high().await;
+    }
+}
+
+/// **Bouncing Ball Demo**
+///
+/// A stop-motion animation with hand-tuned timing: the ball hangs at the
+/// top, speeds up as it falls, squashes briefly on the ground and slows
+/// down again as it rises.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+///
+/// # Example
+/// ```ignore
+/// demos::bouncing_ball_demo(&mut display).await;
+/// ```
+#[allow(dead_code)]
+pub async fn bouncing_ball_demo(display: &mut LedMatrix) {
+    #[rustfmt::skip]
+    const HEIGHTS: [Frame<5, 5>; 4] = [
+        frame_5x5(&[0b00100, 0b00000, 0b00000, 0b00000, 0b00000]),
+        frame_5x5(&[0b00000, 0b00100, 0b00000, 0b00000, 0b00000]),
+        frame_5x5(&[0b00000, 0b00000, 0b00100, 0b00000, 0b00000]),
+        frame_5x5(&[0b00000, 0b00000, 0b00000, 0b00100, 0b00000]),
+    ];
+    #[rustfmt::skip]
+    const GROUND: Frame<5, 5> = frame_5x5(&[
+        0b00000,
+        0b00000,
+        0b00000,
+        0b00000,
+        0b00100,
+    ]);
+    #[rustfmt::skip]
+    const SQUASH: Frame<5, 5> = frame_5x5(&[
+        0b00000,
+        0b00000,
+        0b00000,
+        0b00000,
+        0b01110,
+    ]);
+
+    let ms = Duration::from_millis;
+    let bounce = [
+        (HEIGHTS[0], ms(400)),
+        (HEIGHTS[1], ms(160)),
+        (HEIGHTS[2], ms(110)),
+        (HEIGHTS[3], ms(80)),
+        (GROUND, ms(60)),
+        (SQUASH, ms(120)),
+        (GROUND, ms(60)),
+        (HEIGHTS[3], ms(80)),
+        (HEIGHTS[2], ms(110)),
+        (HEIGHTS[1], ms(160)),
+    ];
+    loop {
+        display.animate_timed(&bounce).await;
+    }
+}
+
+/// **Heartbeat Demo**
+///
+/// Beats a heart forever by alternating a full heart with a smaller one,
+/// about 75 beats per minute.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+///
+/// # Example
+/// ```ignore
+/// demos::heartbeat_demo(&mut display).await;
+/// ```
+#[allow(dead_code)]
+pub async fn heartbeat_demo(display: &mut LedMatrix) {
+    #[rustfmt::skip]
+    const HEART: Frame<5, 5> = frame_5x5(&[
+        0b01010,
+        0b11111,
+        0b11111,
+        0b01110,
+        0b00100,
+    ]);
+    #[rustfmt::skip]
+    const SMALL_HEART: Frame<5, 5> = frame_5x5(&[
+        0b00000,
+        0b01010,
+        0b01110,
+        0b00100,
+        0b00000,
+    ]);
+
+    display
+        .alternate(HEART, SMALL_HEART, Duration::from_millis(800), 0)
+        .await;
+}
+
+/// **Kitchen Timer Demo**
+///
+/// Button A starts a 10 second countdown with a turning spinner, ending in
+/// a blinking check mark. Button B cancels a running countdown and blanks
+/// the display.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `btn_a` - Button that starts the countdown
+/// * `btn_b` - Button that cancels it
+///
+/// # Example
+/// ```ignore
+/// demos::countdown_demo(&mut display, &mut btn_a, &mut btn_b).await;
+/// ```
+#[allow(dead_code)]
+pub async fn countdown_demo(display: &mut LedMatrix, btn_a: &mut Button, btn_b: &mut Button) {
+    const SECONDS: u32 = 10;
+
+    loop {
+        btn_a.wait_for_low().await;
+        match select(display.countdown(SECONDS), btn_b.wait_for_low()).await {
+            Either::First(_) => defmt::info!("countdown finished"),
+            Either::Second(_) => {
+                defmt::info!("countdown cancelled");
+                btn_b.wait_for_high().await;
+            }
+        }
+    }
+}
+
+/// **Wall Bounce Demo**
+///
+/// Drives a `BouncingBall` around the matrix, one step every 150 ms. Pressing
+/// the button sends the ball back to where it started.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `button` - Button that restarts the ball
+///
+/// # Example
+/// ```ignore
+/// demos::wall_bounce_demo(&mut display, &mut btn_a).await;
+/// ```
+#[allow(dead_code)]
+pub async fn wall_bounce_demo(display: &mut LedMatrix, button: &mut Button) {
+    let start = BouncingBall::new(1, 0, 1, 1);
+
+    let mut ball = start;
+    loop {
+        let frame = ball.step();
+        if let Either::Second(_) = select(
+            display.display(frame, Duration::from_millis(150)),
+            button.wait_for_low(),
+        )
+        .await
+        {
+            ball = start;
+            button.wait_for_high().await;
+        }
+    }
+}
+
+/// **Clap Slideshow Demo**
+///
+/// Shows a series of icons, moving on to the next one each time a clap is
+/// heard.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `mic` - Microphone listening for claps
+///
+/// # Example
+/// ```ignore
+/// let mut mic = Microphone::new(board.saadc, board.microphone, board.micen).await;
+/// demos::clap_demo(&mut display, &mut mic).await;
+/// ```
+#[allow(dead_code)]
+pub async fn clap_demo(display: &mut LedMatrix, mic: &mut Microphone) {
+    let icons = [ARROW_LEFT, ARROW_RIGHT, CHECK_MARK, CROSS_MARK];
+
+    for &icon in icons.iter().cycle() {
+        let show = async {
+            loop {
+                display.display(icon, Duration::from_secs(1)).await;
+            }
+        };
+        select(show, wait_for_clap(mic, CLAP_THRESHOLD)).await;
+        defmt::info!("clap");
+    }
+}
+
+/// **Compass Demo**
+///
+/// Calibrates the magnetometer, then keeps a needle on the display pointing
+/// toward magnetic north while the board lies flat.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `mag` - Magnetometer providing the heading
+///
+/// # Example
+/// ```ignore
+/// let mut mag = Magnetometer::new(board.twispi0, board.i2c_int_sda, board.i2c_int_scl).await?;
+/// demos::compass_demo(&mut display, &mut mag).await;
+/// ```
+#[allow(dead_code)]
+pub async fn compass_demo(display: &mut LedMatrix, mag: &mut Magnetometer) {
+    let offsets = match mag.calibrate(display).await {
+        Ok(offsets) => offsets,
+        Err(e) => {
+            defmt::warn!("magnetometer error: {}", e);
+            return;
+        }
+    };
+
+    let mut needle = Frame::empty();
+    loop {
+        match mag.heading(&offsets).await {
+            // North lies `heading` degrees anticlockwise of the top edge
+            Ok(heading) => needle = needle_frame(360 - heading),
+            Err(e) => defmt::warn!("magnetometer error: {}", e),
+        }
+        display.display(needle, Duration::from_millis(100)).await;
+    }
+}
+
+/// **Auto-Dim Demo**
+///
+/// Shows a heart and measures the room light twice a second, dimming the
+/// display in the dark and brightening it in daylight.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+///
+/// # Example
+/// ```ignore
+/// demos::auto_dim_demo(&mut display).await;
+/// ```
+#[allow(dead_code)]
+pub async fn auto_dim_demo(display: &mut LedMatrix) {
+    #[rustfmt::skip]
+    const HEART: Frame<5, 5> = frame_5x5(&[
+        0b01010,
+        0b11111,
+        0b11111,
+        0b01110,
+        0b00100,
+    ]);
+
+    loop {
+        display.auto_brightness().await;
+        display.display(HEART, Duration::from_millis(500)).await;
+    }
+}
+
+/// **Brightness Ramp Demo**
+///
+/// Lights the whole matrix; holding button A ramps the brightness up and
+/// holding button B ramps it down, one level per repeat. A single tap
+/// changes the level by exactly one step.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `btn_a` - Button that brightens the display
+/// * `btn_b` - Button that dims the display
+///
+/// # Example
+/// ```ignore
+/// demos::brightness_ramp_demo(&mut display, &mut btn_a, &mut btn_b).await;
+/// ```
+#[allow(dead_code)]
+pub async fn brightness_ramp_demo(display: &mut LedMatrix, btn_a: &mut Button, btn_b: &mut Button) {
+    const INITIAL_DELAY: Duration = Duration::from_millis(500);
+    const REPEAT_INTERVAL: Duration = Duration::from_millis(150);
+    const FULL: Frame<5, 5> = frame_5x5(&[0b11111; 5]);
+
+    let mut up = hold_repeat(btn_a, INITIAL_DELAY, REPEAT_INTERVAL);
+    let mut down = hold_repeat(btn_b, INITIAL_DELAY, REPEAT_INTERVAL);
+    loop {
+        // Keep refreshing until a repeat fires, rather than redrawing the
+        // frame in short slices, so the matrix does not flicker between them
+        match display.display_while(FULL, select(up.next(), down.next())).await {
+            Either::First(true) => display.increase_brightness(),
+            Either::Second(true) => display.decrease_brightness(),
+            _ => {}
+        }
+    }
+}
+
+/// **Serial Console Demo**
+///
+/// Drives the display from a terminal on the USB serial port: `scroll
+/// <text>` scrolls the text, `bright <0-10>` sets the brightness and `clear`
+/// turns the matrix off. Each command is acknowledged with `ok`.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `console` - Console on the debug UART
+///
+/// # Example
+/// ```ignore
+/// let uart = DebugUart {
+///     uarte: board.uarte0,
+///     timer: board.timer0,
+///     ppi_ch1: board.ppi_ch0,
+///     ppi_ch2: board.ppi_ch1,
+///     ppi_group: board.ppi_group0,
+///     rx: board.uart_int_rx,
+///     tx: board.uart_int_tx,
+/// };
+/// let (mut rx_buffer, mut tx_buffer) = ([0u8; 64], [0u8; 64]);
+/// let mut console = Console::new(uart, &mut rx_buffer, &mut tx_buffer);
+/// demos::console_demo(&mut display, &mut console).await;
+/// ```
+#[allow(dead_code)]
+pub async fn console_demo(display: &mut LedMatrix, console: &mut Console<'_>) {
+    console.write("micro:bit console\r\n").await;
+    loop {
+        match console.next_command().await {
+            Command::Scroll(text) => display.scroll(&text).await,
+            Command::Bright(level) => display.set_brightness(Brightness::new(level)),
+            Command::Clear => display.clear(),
+        }
+        console.write("ok\r\n").await;
+    }
+}
+
+/// **Stopwatch Demo**
+///
+/// Button A starts timing from zero and button B stops it. While running,
+/// the center LED blinks once a second; after stopping, the elapsed time
+/// scrolls as minutes and seconds.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `btn_a` - Button that starts the stopwatch
+/// * `btn_b` - Button that stops the stopwatch
+///
+/// # Example
+/// ```ignore
+/// demos::stopwatch_demo(&mut display, &mut btn_a, &mut btn_b).await;
+/// ```
+#[allow(dead_code)]
+pub async fn stopwatch_demo(display: &mut LedMatrix, btn_a: &mut Button, btn_b: &mut Button) {
+    const DOT: Frame<5, 5> = frame_5x5(&[0b00000, 0b00000, 0b00100, 0b00000, 0b00000]);
+
+    let mut stopwatch = Stopwatch::new();
+    loop {
+        btn_a.wait_for_low().await;
+        stopwatch.reset();
+        stopwatch.start();
+
+        let blink = async {
+            loop {
+                display.display(DOT, Duration::from_millis(500)).await;
+                Timer::after(Duration::from_millis(500)).await;
+            }
+        };
+        select(btn_b.wait_for_low(), blink).await;
+
+        stopwatch.stop();
+        stopwatch.show_on(display).await;
+        btn_b.wait_for_high().await;
+    }
+}
+
+/// **Temperature Ticker Demo**
+///
+/// Reads the chip temperature every ten seconds and feeds it, as text such
+/// as `"23.5C"`, into a channel drained by the display's `ticker`. The
+/// latest reading keeps scrolling until the next one arrives.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `sensor` - On-die temperature sensor
+///
+/// # Example
+/// ```ignore
+/// let mut sensor = TempSensor::new(board.temp);
+/// demos::ticker_demo(&mut display, &mut sensor).await;
+/// ```
+#[allow(dead_code)]
+pub async fn ticker_demo(display: &mut LedMatrix, sensor: &mut TempSensor) {
+    let channel: Channel<NoopRawMutex, String<16>, 2> = Channel::new();
+
+    let producer = async {
+        loop {
+            let tenths = sensor.read().await;
+            let mut text: String<16> = String::new();
+            // Cannot fail: a formatted i16 and the unit fit in 16 characters
+            text.push_str(&format_fixed(tenths as i32, 1)).ok();
+            text.push('C').ok();
+            channel.send(text).await;
+            Timer::after(Duration::from_secs(10)).await;
+        }
+    };
+    join(producer, display.ticker(&mut channel.receiver(), false)).await;
+}
+
+/// **Edge Pin PWM Demo**
+///
+/// Fades an LED wired from edge pin P3 to GND smoothly up and down with
+/// PWM. P3 is LED matrix column 3, so the board must come from
+/// `Microbit::new_typed`, which keeps the pin's concrete `P0_31` type
+/// instead of folding it into the display driver. `new_typed` leaves the
+/// matrix rows unconfigured and floating, and a floating row never lights
+/// its LEDs, so the onboard matrix remains dark.
+///
+/// # Arguments
+/// * `pwm1` - PWM peripheral driving the pin (`board.pwm1`)
+/// * `p3` - Edge connector pin 3 (`board.display.col3`)
+///
+/// # Example
+/// ```ignore
+/// let board = Microbit::new_typed(Default::default());
+/// demos::edge_pwm_demo(board.pwm1, board.display.col3).await;
+/// ```
+#[allow(dead_code)]
+pub async fn edge_pwm_demo(pwm1: PWM1, p3: P0_31) {
+    const MAX_DUTY: u16 = 1000;
+    const STEP: u16 = 20;
+
+    let mut pwm = SimplePwm::new_1ch(pwm1, p3);
+    pwm.set_max_duty(MAX_DUTY);
+    loop {
+        for duty in (0..=MAX_DUTY)
+            .step_by(STEP as usize)
+            .chain((0..=MAX_DUTY).rev().step_by(STEP as usize))
+        {
+            pwm.set_duty(0, duty);
+            Timer::after(Duration::from_millis(10)).await;
+        }
+    }
+}
+
+/// **Manual Animation Demo**
+///
+/// Steps a sliding text animation by hand instead of through
+/// `LedMatrix::scroll`, and lays a `BouncingBall` over every frame the
+/// animation produces. The ball moves once per animation step, so game
+/// logic and scrolling stay in lockstep without either owning the display.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+///
+/// # Example
+/// ```ignore
+/// demos::manual_animation_demo(&mut display).await;
+/// ```
+#[allow(dead_code)]
+pub async fn manual_animation_demo(display: &mut LedMatrix) {
+    let mut display = display.clear_on_drop();
+    let mut ball = BouncingBall::new(0, 0, 1, 1);
+    let mut background = Frame::empty();
+    let mut ball_frame = Frame::empty();
+
+    loop {
+        let Ok(mut animation) = Animation::new(
+            AnimationData::Bytes(b"PLAY"),
+            AnimationEffect::Slide,
+            Duration::from_secs(3),
+        ) else {
+            return;
+        };
+        loop {
+            match animation.step(Instant::now()) {
+                AnimationState::Apply(frame) => {
+                    background = frame;
+                    ball_frame = ball.step();
+                }
+                AnimationState::Wait => {}
+                AnimationState::Done => break,
+            }
+            let mut frame = background;
+            frame.or(&ball_frame);
+            // Refresh one row at a time like the built-in animations; a
+            // `display` call per step would blank the matrix in between
+            display.apply(frame);
+            display.render();
+            Timer::after_micros(500).await;
+        }
+    }
+}
+
+/// **Watchdog Demo**
+///
+/// Shows a main loop guarded by the watchdog: each pass blinks the check
+/// mark and feeds the watchdog. Holding button A makes the loop wait
+/// forever, like a deadlocked driver call; two seconds later the watchdog
+/// resets the chip, and the restarted demo scrolls `"WDT"` because
+/// `reset_reason` reports the watchdog. The reason is cleared afterwards,
+/// so a later reset by the pin or a debugger does not scroll it again.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `button` - Button that simulates a hang
+/// * `watchdog` - Watchdog guarding the loop (not yet started)
+///
+/// # Example
+/// ```ignore
+/// let mut watchdog = Watchdog::new(board.wdt);
+/// demos::watchdog_demo(&mut display, &mut btn_a, &mut watchdog).await;
+/// ```
+#[allow(dead_code)]
+pub async fn watchdog_demo(display: &mut LedMatrix, button: &mut Button, watchdog: &mut Watchdog) {
+    if Microbit::reset_reason() == ResetReason::Watchdog {
+        display.scroll("WDT").await;
+    }
+    Microbit::clear_reset_reason();
+    if let Err(e) = watchdog.start(Duration::from_secs(2)) {
+        defmt::warn!("watchdog not started: {}", e);
+    }
+
+    loop {
+        display.display(CHECK_MARK, Duration::from_millis(500)).await;
+        Timer::after(Duration::from_millis(500)).await;
+        if button.is_low() {
+            defmt::warn!("simulating a hang");
+            core::future::pending::<()>().await;
+        }
+        watchdog.feed();
+    }
+}
+
+/// **Battery Meter Demo**
+///
+/// Shows the supply voltage as a bar graph, from empty at `LOW_BATTERY_MV`
+/// to full at 3000 mV (two fresh AAA cells), refreshed every few seconds.
+/// Below `LOW_BATTERY_MV` the low-battery icon flashes instead.
+///
+/// The SAADC is only borrowed for each reading, so it is free for other
+/// users between them.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `saadc` - SAADC peripheral, borrowed for each reading
+///
+/// # Example
+/// ```ignore
+/// demos::battery_meter_demo(&mut display, &mut board.saadc).await;
+/// ```
+#[allow(dead_code)]
+pub async fn battery_meter_demo(display: &mut LedMatrix, saadc: &mut SAADC) {
+    const FULL_MV: u16 = 3000;
+    loop {
+        let mv = Battery::new(&mut *saadc).battery_millivolts().await;
+        defmt::info!("battery: {} mV", mv);
+
+        if mv < LOW_BATTERY_MV {
+            for _ in 0..3 {
+                display.display(BATTERY_LOW, Duration::from_millis(500)).await;
+                Timer::after(Duration::from_millis(500)).await;
+            }
+        } else {
+            let charge = (mv.min(FULL_MV) - LOW_BATTERY_MV) as u32 * 255 / (FULL_MV - LOW_BATTERY_MV) as u32;
+            display
+                .display(level_frame(charge as u8, 255), Duration::from_secs(3))
+                .await;
+        }
+    }
+}
+
+/// **Snake Demo**
+///
+/// Plays snake: tilt the board to steer, or press A to turn left and B to
+/// turn right relative to the snake's heading. A button press wins over the
+/// tilt for that step. The snake moves every 400 ms; when it crashes, its
+/// length scrolls past and a new game starts on the next A press.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `btn_a` - Button that turns the snake left
+/// * `btn_b` - Button that turns the snake right
+/// * `accel` - Accelerometer used to read the tilt
+/// * `rng` - Hardware random number generator placing the food
+///
+/// # Example
+/// ```ignore
+/// let mut rng = Rng::new(board.rng, Irqs);
+/// demos::snake_demo(&mut display, &mut btn_a, &mut btn_b, &mut accel, &mut rng).await;
+/// ```
+#[allow(dead_code)]
+pub async fn snake_demo(
+    display: &mut LedMatrix,
+    btn_a: &mut Button,
+    btn_b: &mut Button,
+    accel: &mut Accelerometer,
+    rng: &mut Rng<'static, RNG>,
+) {
+    const STEP: Duration = Duration::from_millis(400);
+
+    loop {
+        let mut snake = Snake::new();
+        snake.spawn_food(random_u32(rng).await);
+
+        loop {
+            let mut direction = match accel.read().await {
+                Ok(a) => tilt_direction(a.x, a.y, TILT_DEADZONE).unwrap_or(snake.heading()),
+                Err(e) => {
+                    defmt::warn!("accelerometer error: {}", e);
+                    snake.heading()
+                }
+            };
+
+            // Show the board until the next step, noting the latest button turn
+            let deadline = Instant::now() + STEP;
+            while Instant::now() < deadline {
+                match select3(
+                    display.display(snake.render(), deadline.saturating_duration_since(Instant::now())),
+                    btn_a.wait_for_falling_edge(),
+                    btn_b.wait_for_falling_edge(),
+                )
+                .await
+                {
+                    Either3::First(_) => {}
+                    Either3::Second(_) => direction = snake.heading().turn_left(),
+                    Either3::Third(_) => direction = snake.heading().turn_right(),
+                }
+            }
+
+            match snake.step(direction) {
+                GameState::Ate => {
+                    if !snake.spawn_food(random_u32(rng).await) {
+                        display.scroll("WIN").await;
+                        break;
+                    }
+                }
+                GameState::GameOver => {
+                    display.display(CROSS_MARK, Duration::from_secs(1)).await;
+                    display.show_number(snake.length() as i32, Duration::from_secs(1)).await;
+                    break;
+                }
+                GameState::Running => {}
+            }
+        }
+
+        btn_a.wait_for_falling_edge().await;
+    }
+}
+
+/// Draw a random `u32` from the hardware RNG
+async fn random_u32(rng: &mut Rng<'static, RNG>) -> u32 {
+    let mut bytes = [0u8; 4];
+    rng.fill_bytes(&mut bytes).await;
+    u32::from_le_bytes(bytes)
+}
+
+/// **Drop Detector Demo**
+///
+/// Waits for the board to be dropped and flashes the cross mark three
+/// times when it is, then waits for the next drop. Bus errors from the
+/// accelerometer are logged and the demo keeps waiting.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `accel` - Accelerometer used to detect the fall
+///
+/// # Example
+/// ```ignore
+/// let mut accel = Accelerometer::new(board.twispi0, board.i2c_int_sda, board.i2c_int_scl).await?;
+/// demos::drop_demo(&mut display, &mut accel).await;
+/// ```
+#[allow(dead_code)]
+pub async fn drop_demo(display: &mut LedMatrix, accel: &mut Accelerometer) {
+    loop {
+        if let Err(e) = accel.wait_for_freefall(FREEFALL_DURATION).await {
+            defmt::warn!("accelerometer error: {}", e);
+            continue;
+        }
+        defmt::info!("drop detected");
+        display
+            .alternate(CROSS_MARK, Frame::empty(), Duration::from_millis(200), 3)
+            .await;
+    }
+}
+
+/// **Sound Scope Demo**
+///
+/// Plots the microphone's sound level as a bar chart scrolling from right
+/// to left, one new bar every 100 ms.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `mic` - Microphone to listen on
+///
+/// # Example
+/// ```ignore
+/// let mut mic = Microphone::new(board.saadc, board.microphone, board.micen).await;
+/// demos::sound_scope_demo(&mut display, &mut mic).await;
+/// ```
+#[allow(dead_code)]
+pub async fn sound_scope_demo(display: &mut LedMatrix, mic: &mut Microphone) {
+    let mut scope = Scope::new();
+    loop {
+        // Levels run up to 4095; scale to the scope's 0-255 range
+        scope.push((mic.level().await / 16).min(255) as u8);
+        display.display(scope.frame(), Duration::from_millis(100)).await;
+    }
+}
+
+/// **Marquee Demo**
+///
+/// Scrolls a looping banner until the button is pressed, then checks that
+/// the cancelled scroll left the display blank without an explicit
+/// `clear`, and waits for the next press to start over.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `button` - Button that stops the banner
+///
+/// # Example
+/// ```ignore
+/// demos::marquee_demo(&mut display, &mut btn_a).await;
+/// ```
+#[allow(dead_code)]
+pub async fn marquee_demo(display: &mut LedMatrix, button: &mut Button) {
+    loop {
+        let marquee = display.scroll_loop("MICRO:BIT", Duration::from_secs(4), 0);
+        select(marquee, button.wait_for_low()).await;
+        defmt::info!("marquee stopped, display blank: {}", display.is_blank());
+        button.wait_for_high().await;
+        button.wait_for_low().await;
+        button.wait_for_high().await;
+    }
+}
+
+/// **Type-On Demo**
+///
+/// Types "OK" one letter at a time, teletype style, each time the button is
+/// pressed.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `button` - Button that starts the typing
+///
+/// # Example
+/// ```ignore
+/// demos::type_on_demo(&mut display, &mut btn_a).await;
+/// ```
+#[allow(dead_code)]
+pub async fn type_on_demo(display: &mut LedMatrix, button: &mut Button) {
+    loop {
+        button.wait_for_low().await;
+        display.type_text("OK", Duration::from_millis(500)).await;
+        button.wait_for_high().await;
+    }
+}
+
+/// **Clap Wait Demo**
+///
+/// Shows a spinner frame while listening for a clap, then confirms each
+/// clap with a check mark.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `mic` - Microphone listening for claps
+///
+/// # Example
+/// ```ignore
+/// let mut mic = Microphone::new(board.saadc, board.microphone, board.micen).await;
+/// demos::clap_wait_demo(&mut display, &mut mic).await;
+/// ```
+#[allow(dead_code)]
+pub async fn clap_wait_demo(display: &mut LedMatrix, mic: &mut Microphone) {
+    loop {
+        display
+            .display_while(spinner_frame(0), wait_for_clap(mic, CLAP_THRESHOLD))
+            .await;
+        display.display(CHECK_MARK, Duration::from_secs(1)).await;
+    }
+}
+
+/// **Radio Alert Demo**
+///
+/// Shows a check mark while listening on the radio and flashes the whole
+/// display three times whenever a message arrives. The alert is an
+/// overlay, so the dimmed check mark comes back at its own brightness.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `radio` - Radio configured with the shared group and channel
+///
+/// # Example
+/// ```ignore
+/// let mut radio = Radio::new(board.radio, 1, DEFAULT_CHANNEL);
+/// demos::radio_alert_demo(&mut display, &mut radio).await;
+/// ```
+#[allow(dead_code)]
+pub async fn radio_alert_demo(display: &mut LedMatrix, radio: &mut Radio) {
+    let mut buf = [0u8; MAX_PAYLOAD];
+    display.set_brightness(Brightness::new(3));
+    loop {
+        let len = display.display_while(CHECK_MARK, radio.receive(&mut buf)).await;
+        if len > 0 {
+            display.alert(3, Duration::from_millis(300)).await;
+        }
+    }
+}
+
+/// **Freed Pins GPIO Demo**
+///
+/// Uses matrix columns 1 and 3 as ordinary GPIO outputs on a board built
+/// without the display, alternately blinking LEDs wired from edge pins P4
+/// and P3 to GND. The matrix rows are never driven, so the onboard LEDs
+/// stay dark while the columns toggle.
+///
+/// # Arguments
+/// * `pins` - LED matrix pins freed by `Microbit::without_display` (`board.display`)
+///
+/// # Example
+/// ```ignore
+/// let board = Microbit::without_display();
+/// demos::freed_pins_demo(board.display).await;
+/// ```
+#[allow(dead_code)]
+pub async fn freed_pins_demo(pins: DisplayPins) {
+    let mut p4 = Output::new(pins.col1, Level::High, OutputDrive::Standard);
+    let mut p3 = Output::new(pins.col3, Level::Low, OutputDrive::Standard);
+    defmt::info!("Blinking edge pins P3 and P4");
+    loop {
+        Timer::after(Duration::from_millis(500)).await;
+        p4.toggle();
+        p3.toggle();
+    }
+}
+
+/// **Live Acceleration Demo**
+///
+/// Plots the acceleration magnitude streamed by the background `accel_task`
+/// as a bar chart scrolling from right to left, one bar per sample. The
+/// chart is redrawn as each sample arrives, so the scroll speed follows
+/// the task's sample rate. At rest the bars sit about three rows high;
+/// shaking the board pushes them to the top.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `stream` - Receiver for samples published by `accel_task`
+///
+/// # Example
+/// ```ignore
+/// let accel = Accelerometer::new(board.twispi0, board.i2c_int_sda, board.i2c_int_scl).await?;
+/// spawner.spawn(motion::accel_task(accel, 20, Overflow::DropOldest)).unwrap();
+/// demos::accel_scope_demo(&mut display, AccelStream::new()).await;
+/// ```
+#[allow(dead_code)]
+pub async fn accel_scope_demo(display: &mut LedMatrix, stream: AccelStream) {
+    let mut scope = Scope::new();
+    let mut sample = stream.recv().await;
+    loop {
+        // Scale 0-2040 mg (just over 2 g) to the scope's 0-255 range
+        scope.push((sample.magnitude() / 8).min(255) as u8);
+        sample = display.display_while(scope.frame(), stream.recv()).await;
+    }
+}
+
+/// **Face Down Demo**
+///
+/// Shows a check mark until the board is placed screen down, then turns
+/// the display off, as a phone does when laid on its face. Turning the
+/// board back over lights it again.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `accel` - Accelerometer used to detect the gestures
+///
+/// # Example
+/// ```ignore
+/// let mut accel = Accelerometer::new(board.twispi0, board.i2c_int_sda, board.i2c_int_scl).await?;
+/// demos::face_down_demo(&mut display, &mut accel).await;
+/// ```
+#[allow(dead_code)]
+pub async fn face_down_demo(display: &mut LedMatrix, accel: &mut Accelerometer) {
+    loop {
+        if let Err(e) = display
+            .display_while(CHECK_MARK, accel.wait_for_gesture(Gesture::ScreenDown))
+            .await
+        {
+            defmt::warn!("accelerometer error: {}", e);
+            continue;
+        }
+        defmt::info!("screen down, display off");
+        if let Err(e) = accel.wait_for_gesture(Gesture::ScreenUp).await {
+            defmt::warn!("accelerometer error: {}", e);
+        }
+    }
+}
+
+/// **Twinkle Screensaver Demo**
+///
+/// Shows a check mark for every press of the button. After 10 seconds
+/// without a press, stars twinkle across the matrix until the button is
+/// pressed again, which wakes the display without counting as a press.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `button` - Button that counts as user input
+/// * `rng` - Hardware random number generator for the stars
+///
+/// # Example
+/// ```ignore
+/// let mut rng = Rng::new(board.rng, Irqs);
+/// demos::twinkle_idle_demo(&mut display, &mut btn_a, &mut rng).await;
+/// ```
+#[allow(dead_code)]
+pub async fn twinkle_idle_demo(display: &mut LedMatrix, button: &mut Button, rng: &mut Rng<'static, RNG>) {
+    const IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+    loop {
+        if wait_for_press_timeout(button, IDLE_TIMEOUT).await {
+            display.display(CHECK_MARK, Duration::from_secs(1)).await;
+        } else {
+            defmt::info!("idle, twinkling");
+            select(button.wait_for_low(), display.twinkle(rng)).await;
+        }
+        button.wait_for_high().await;
+    }
+}
+
+/// **Refresh Rate Demo**
+///
+/// Scrolls a long message over and over and logs the refresh rate the
+/// display achieved during each pass, along with the running count of
+/// dropped frames. Run other tasks alongside it to see how much load the
+/// display tolerates before refreshes fall behind.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+///
+/// # Example
+/// ```ignore
+/// demos::refresh_rate_demo(&mut display).await;
+/// ```
+#[allow(dead_code)]
+pub async fn refresh_rate_demo(display: &mut LedMatrix) {
+    loop {
+        let (start, frames) = (Instant::now(), display.frames_rendered());
+        display.scroll("THE QUICK BROWN FOX").await;
+        let rendered = display.frames_rendered().wrapping_sub(frames);
+        defmt::info!(
+            "{} refreshes/s, {} frames dropped",
+            refresh_rate(rendered, start.elapsed()),
+            display.dropped_frames()
+        );
+    }
+}
+
+/// **Status Playlist Demo**
+///
+/// Cycles three status messages across the display, one after another
+/// with a one-second blank pause between them, until the button is
+/// pressed. The press cancels the playlist wherever it is, leaving the
+/// display blank, and the next press starts it again from the top.
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `button` - Button that stops and restarts the playlist
+///
+/// # Example
+/// ```ignore
+/// demos::playlist_demo(&mut display, &mut btn_a).await;
+/// ```
+#[allow(dead_code)]
+pub async fn playlist_demo(display: &mut LedMatrix, button: &mut Button) {
+    const MESSAGES: [&str; 3] = ["TEMP OK", "BATT 80%", "RADIO ON"];
+
+    loop {
+        select(
+            display.scroll_playlist(&MESSAGES, Duration::from_millis(400), Duration::from_secs(1)),
+            button.wait_for_low(),
+        )
+        .await;
+        defmt::info!("playlist stopped");
+        button.wait_for_high().await;
+        button.wait_for_low().await;
+        button.wait_for_high().await;
+    }
+}
+
+/// **Live Serial Pattern Demo**
+///
+/// Shows patterns pushed from a PC over the USB serial port. Each valid
+/// packet replaces the pattern on screen as soon as it arrives, and the
+/// last pattern stays lit until the next one. See the `serial` module for
+/// the byte protocol; from a Linux host, for example:
+///
+/// ```text
+/// stty -F /dev/ttyACM0 115200 raw
+/// printf '\xa5\x00\x00\x20\x00\x00\x20' > /dev/ttyACM0   # center dot
+/// ```
+///
+/// # Arguments
+/// * `display` - Mutable reference to the LED matrix display driver
+/// * `receiver` - Receiver reading packets from the debug UART
+///
+/// # Example
+/// ```ignore
+/// let uart = DebugUart {
+///     uarte: board.uarte0,
+///     timer: board.timer0,
+///     ppi_ch1: board.ppi_ch0,
+///     ppi_ch2: board.ppi_ch1,
+///     ppi_group: board.ppi_group0,
+///     rx: board.uart_int_rx,
+///     tx: board.uart_int_tx,
+/// };
+/// let (mut rx_buffer, mut tx_buffer) = ([0u8; 64], [0u8; 16]);
+/// let mut receiver = FrameReceiver::new(uart, &mut rx_buffer, &mut tx_buffer);
+/// demos::serial_pattern_demo(&mut display, &mut receiver).await;
+/// ```
+#[allow(dead_code)]
+pub async fn serial_pattern_demo(display: &mut LedMatrix, receiver: &mut FrameReceiver<'_>) {
+    let mut frame = receiver.next_frame().await;
+    loop {
+        defmt::info!("new pattern {}", frame.to_bytes());
+        frame = display.display_while(frame, receiver.next_frame()).await;
+    }
+}
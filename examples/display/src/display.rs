@@ -8,6 +8,7 @@
 /// - **Text Rendering**: Character and string display using built-in fonts
 /// - **Animations**: Smooth scrolling text and custom animation effects
 /// - **Brightness Control**: Adjustable brightness levels from 0-10
+/// - **Light Sensing**: Ambient light estimated with the LEDs as photodiodes
 /// - **Frame Buffering**: Efficient frame-based graphics rendering
 /// - **Async Operations**: Non-blocking display operations using Embassy
 ///
@@ -46,9 +47,21 @@
 /// - `fonts`: Character bitmaps and predefined graphics
 /// - `types`: Core data types (Frame, Bitmap, Brightness)
 /// - Animation support for smooth visual effects
+use core::future::Future;
+use core::ops::{Deref, DerefMut};
+
+use embassy_futures::select::{select, Either};
+use embassy_futures::yield_now;
+use embassy_nrf::gpio::{Flex, OutputDrive, Pull};
+use embassy_nrf::rng::{self, Rng};
+use embassy_sync::blocking_mutex::raw::RawMutex;
+use embassy_sync::channel::Receiver;
 use embassy_time::{block_for, Duration, Instant, Timer};
-use embedded_hal::digital::OutputPin;
+use embedded_hal::digital::{OutputPin, PinState};
+use heapless::String;
 
+use crate::fonts::{spinner_frame, CHECK_MARK, SPINNER_PHASES};
+use crate::format::{format_fixed, format_hex, format_hh_mm, format_number};
 pub use crate::types::*;
 
 /// **Display Refresh Interval**
@@ -59,6 +72,144 @@ pub use crate::types::*;
 /// visible flickering during animations and scrolling text.
 const REFRESH_INTERVAL: Duration = Duration::from_micros(500);
 
+/// **Maximum Row Debt**
+///
+/// Upper bound on the extra on-time a row can owe after being left lit
+/// through a stall at full brightness. Longer stalls are only partly paid
+/// back, so one long hiccup cannot blank a row for a visible stretch.
+const MAX_ROW_DEBT: Duration = Duration::from_millis(5);
+
+/// **Gamma-Corrected On-Time**
+///
+/// Time in microseconds each row stays lit out of its `REFRESH_INTERVAL`,
+/// indexed by brightness level. Perceived brightness rises steeply at low
+/// duty cycles and flattens out at high ones, so the table follows the
+/// usual `(level / 10)^2.2` gamma curve rather than a straight line; each
+/// step up then looks like a similar increase. Level 0
+/// never lights the LEDs and level 10 keeps them on for the whole interval.
+pub const GAMMA_ON_TIME_US: [u16; 11] = [0, 3, 15, 35, 67, 109, 163, 228, 306, 396, 500];
+
+/// **Gamma On-Time Lookup**
+///
+/// # Arguments
+/// * `level` - Brightness level (values above 10 are clamped)
+///
+/// # Returns
+/// How long each row stays lit per refresh, in microseconds
+///
+/// # Example
+/// ```ignore
+/// assert_eq!(gamma_on_time(0), 0);
+/// assert_eq!(gamma_on_time(10), 500);
+/// ```
+pub fn gamma_on_time(level: u8) -> u64 {
+    GAMMA_ON_TIME_US[(level as usize).min(GAMMA_ON_TIME_US.len() - 1)] as u64
+}
+
+/// **Refresh Rate**
+///
+/// Converts a number of `render` calls over a measured window into a rate.
+/// A display keeping up refreshes one row per `REFRESH_INTERVAL`, 2000
+/// times a second; a lower figure means refreshes were late.
+///
+/// # Arguments
+/// * `frames` - `render` calls made during the window, from `frames_rendered`
+/// * `elapsed` - Length of the window
+///
+/// # Returns
+/// Refreshes per second, rounded down, or 0 for an empty window
+///
+/// # Example
+/// ```ignore
+/// assert_eq!(refresh_rate(3000, Duration::from_millis(1500)), 2000);
+/// ```
+#[allow(dead_code)]
+pub fn refresh_rate(frames: u32, elapsed: Duration) -> u32 {
+    match elapsed.as_micros() {
+        0 => 0,
+        micros => (frames as u64 * 1_000_000 / micros).min(u32::MAX as u64) as u32,
+    }
+}
+
+/// **Light Sensing Charge Time**
+///
+/// How long the reverse-biased LEDs are charged before timing their
+/// discharge in `read_light`.
+const LIGHT_CHARGE_TIME: Duration = Duration::from_micros(50);
+
+/// **Longest Light Measurement**
+///
+/// Discharge time treated as complete darkness. Caps how long `read_light`
+/// keeps the display dark.
+const LIGHT_MAX_DISCHARGE: Duration = Duration::from_millis(5);
+
+/// **Light Level Range**
+///
+/// Highest value returned by `read_light`, in full daylight.
+pub const LIGHT_LEVEL_MAX: u16 = 255;
+
+/// **Auto-Brightness Dead-Band**
+///
+/// How far, in `read_light` units, the light must move past the edge of the
+/// current brightness level's band before `auto_brightness` changes level.
+/// Keeps flickering lights from toggling between neighboring levels.
+pub const AUTO_BRIGHTNESS_DEADBAND: u16 = 8;
+
+/// **Proportional Space Width**
+///
+/// Columns taken by a blank glyph, such as space, in proportional text.
+/// Together with the gaps either side this leaves a clear break between
+/// words.
+pub const PROPORTIONAL_SPACE_WIDTH: usize = 2;
+
+/// **Pause Between Words**
+///
+/// Blank gap between words in `LedMatrix::scroll_words`.
+const WORD_PAUSE: Duration = Duration::from_millis(300);
+
+/// **Repeated Character Gap**
+///
+/// Blank gap `LedMatrix::type_text` leaves between two identical
+/// characters, so "OO" reads as two letters rather than one long one.
+const REPEAT_GAP: Duration = Duration::from_millis(60);
+
+/// **Dither Steps**
+///
+/// Number of sub-frames in one temporal dithering cycle, one per entry of
+/// the 4x4 Bayer matrix. A pixel at level `l` is lit in about
+/// `l * DITHER_STEPS / 256` of them.
+pub const DITHER_STEPS: usize = 16;
+
+/// **Bayer Matrix**
+///
+/// 4x4 ordered-dither thresholds, ranked 0-15. Neighboring cells are far
+/// apart in rank, so pixels sharing a gray level switch at different
+/// sub-frames instead of flashing together.
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// **Twinkle Tick**
+///
+/// Time between updates of the stars in `LedMatrix::twinkle`: each tick
+/// may light a new star and moves every star one step through its fade.
+const TWINKLE_TICK: Duration = Duration::from_millis(40);
+
+/// **Twinkle Star Chance**
+///
+/// Odds out of 256 that a tick of `LedMatrix::twinkle` lights a new star,
+/// about one every 120 ms on average at irregular intervals.
+const TWINKLE_CHANCE: u8 = 85;
+
+/// **Twinkle Fade-In Step**
+///
+/// Level a new star gains per tick, so it reaches full brightness in about
+/// a tenth of a second.
+const TWINKLE_RISE: u8 = 96;
+
+/// **Font Glyph Width**
+///
+/// Width in columns of one character cell of the built-in 5x5 font.
+const GLYPH_WIDTH: usize = 5;
+
 /// **LED Matrix Display Driver**
 ///
 /// A generic driver for NxM LED matrix displays using charlieplexing.
@@ -67,11 +218,14 @@ const REFRESH_INTERVAL: Duration = Duration::from_micros(500);
 /// ## Hardware Interface
 /// - **Rows**: Output pins controlling LED cathodes (active high)
 /// - **Columns**: Output pins controlling LED anodes (active low)
+/// - **Polarity**: Both active levels can be swapped with `with_polarity`
+///   for differently wired matrices
 /// - **Multiplexing**: Rapidly switches between rows for persistence of vision
 /// - **Brightness Control**: PWM-style timing control for 11 brightness levels
 ///
 /// ## Features
 /// - **Frame Buffering**: Efficient bitmap storage and manipulation
+/// - **Double Buffering**: Batched edits in a back buffer, shown all at once
 /// - **Async Display**: Non-blocking operations using Embassy timers
 /// - **Animations**: Built-in support for scrolling and sliding effects
 /// - **Text Rendering**: Automatic font conversion and display
@@ -118,6 +272,12 @@ where
     /// updated by display operations and rendered by multiplexing.
     frame_buffer: Frame<COLS, ROWS>,
 
+    /// **Back Buffer**
+    ///
+    /// Off-screen frame edited through `draw_to_back`. Never rendered;
+    /// `swap` copies it into the frame buffer in one step.
+    back_buffer: Frame<COLS, ROWS>,
+
     /// **Current Row Pointer**
     ///
     /// Index of the currently active row during multiplexing.
@@ -131,6 +291,58 @@ where
     /// Implemented through PWM-style timing during multiplexing,
     /// where higher values result in longer LED on-time per cycle.
     brightness: Brightness,
+
+    /// **Power State**
+    ///
+    /// `false` after `power_down`, until the next `power_up` or `render`.
+    powered: bool,
+
+    /// **Dirty Flag**
+    ///
+    /// Set whenever the frame buffer changes and cleared each time `render`
+    /// finishes a pass over the last row.
+    dirty: bool,
+
+    /// **Last Render Time**
+    ///
+    /// When `render` last ran, or `None` after `clear`/`power_down` so
+    /// idle time between drawing calls is not mistaken for lag.
+    last_render: Option<Instant>,
+
+    /// **Lit Row**
+    ///
+    /// Row `render` left switched on at full brightness, which stays lit
+    /// until the next call.
+    lit_row: Option<usize>,
+
+    /// **Row Debt**
+    ///
+    /// Extra on-time each row received while left lit through a late
+    /// refresh. A row skips its turns until its debt is paid back, so every
+    /// row ends up with the same average on-time.
+    row_debt: [Duration; ROWS],
+
+    /// **Dropped Frame Counter**
+    ///
+    /// Refresh slots missed because `render` was called late.
+    dropped_frames: u32,
+
+    /// **Render Counter**
+    ///
+    /// Number of `render` calls since the display was created, wrapping at
+    /// `u32::MAX`.
+    frames_rendered: u32,
+
+    /// **Row Active Level**
+    ///
+    /// Level that switches a row on; `High` on the micro:bit.
+    row_active: PinState,
+
+    /// **Column Active Level**
+    ///
+    /// Level that lights the LED of a column in the active row; `Low` on
+    /// the micro:bit.
+    col_active: PinState,
 }
 
 impl<P, const ROWS: usize, const COLS: usize> LedMatrix<P, ROWS, COLS>
@@ -166,11 +378,46 @@ where
             pin_rows,
             pin_cols,
             frame_buffer: Frame::empty(),
+            back_buffer: Frame::empty(),
             row_p: 0,
             brightness: Default::default(),
+            powered: true,
+            dirty: false,
+            last_render: None,
+            lit_row: None,
+            row_debt: [Duration::from_ticks(0); ROWS],
+            dropped_frames: 0,
+            frames_rendered: 0,
+            row_active: PinState::High,
+            col_active: PinState::Low,
         }
     }
 
+    /// **Set Pin Polarity**
+    ///
+    /// Chooses the levels that switch a row on and light a column. The
+    /// default, set by `new`, matches the micro:bit: rows active high and
+    /// columns active low. Clone boards that wire the matrix the other way
+    /// round need one or both levels swapped, or every LED appears inverted.
+    ///
+    /// # Arguments
+    /// * `row_active` - Level that switches a row on
+    /// * `col_active` - Level that lights a column's LED in the active row
+    ///
+    /// # Returns
+    /// The display, using the new levels from its next refresh
+    ///
+    /// # Example
+    /// ```ignore
+    /// let display = LedMatrix::new(rows, cols).with_polarity(PinState::Low, PinState::High);
+    /// ```
+    #[allow(dead_code)]
+    pub fn with_polarity(mut self, row_active: PinState, col_active: PinState) -> Self {
+        self.row_active = row_active;
+        self.col_active = col_active;
+        self
+    }
+
     /// **Clear All LEDs**
     ///
     /// Turns off all LEDs in the matrix and resets the frame buffer.
@@ -181,6 +428,7 @@ where
     /// 2. Sets all row pins HIGH (deactivate rows)
     /// 3. Sets all column pins HIGH (deactivate columns)
     /// 4. Results in all LEDs being off
+    /// 5. Marks the display dirty, even if it was already blank
     ///
     /// # Usage
     /// Call this method to:
@@ -195,11 +443,59 @@ where
     /// ```
     pub fn clear(&mut self) {
         self.frame_buffer.clear();
+        self.dirty = true;
+        self.blank_pins();
+        self.reset_timing();
+    }
+
+    /// **Reset to Frame**
+    ///
+    /// Replaces the frame buffer with `frame` and marks the display dirty,
+    /// even if it already showed that pattern. Use it like `clear` to put
+    /// the display into a known state, e.g. all LEDs on as a quick check
+    /// that every LED works.
+    ///
+    /// # Arguments
+    /// * `frame` - Pattern to reset the display to
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut all_on = Frame::<5, 5>::empty();
+    /// all_on.fill(true);
+    /// display.reset_to(all_on);
+    /// ```
+    #[allow(dead_code)]
+    pub fn reset_to(&mut self, frame: Frame<COLS, ROWS>) {
+        self.frame_buffer = frame;
+        self.dirty = true;
+    }
+
+    /// Forget the refresh timing so the next `render` does not count the
+    /// time since the last one as lag
+    fn reset_timing(&mut self) {
+        self.last_render = None;
+        self.lit_row = None;
+    }
+
+    /// Put every row and column pin in the idle (all LEDs off) state,
+    /// columns first so no LED lights while the rows change
+    fn blank_pins(&mut self) {
+        for col in self.pin_cols.iter_mut() {
+            col.set_state(!self.col_active).ok();
+        }
         for row in self.pin_rows.iter_mut() {
-            row.set_high().ok();
+            row.set_state(!self.row_active).ok();
         }
-        for col in self.pin_cols.iter_mut() {
-            col.set_high().ok();
+    }
+
+    /// Switch on the current row, then drive the columns of its lit pixels.
+    /// Expects every pin to be idle, as left by `blank_pins`.
+    fn light_row(&mut self) {
+        self.pin_rows[self.row_p].set_state(self.row_active).ok();
+        for (cid, col) in self.pin_cols.iter_mut().enumerate() {
+            if self.frame_buffer.is_set(cid, self.row_p) {
+                col.set_state(self.col_active).ok();
+            }
         }
     }
 
@@ -228,7 +524,10 @@ where
     /// ```
     #[allow(dead_code)]
     pub fn on(&mut self, x: usize, y: usize) {
-        self.frame_buffer.set(x, y);
+        if !self.frame_buffer.is_set(x, y) {
+            self.frame_buffer.set(x, y);
+            self.dirty = true;
+        }
     }
 
     /// **Turn Off Single LED**
@@ -247,13 +546,18 @@ where
     /// ```
     #[allow(dead_code)]
     pub fn off(&mut self, x: usize, y: usize) {
-        self.frame_buffer.unset(x, y);
+        if self.frame_buffer.is_set(x, y) {
+            self.frame_buffer.unset(x, y);
+            self.dirty = true;
+        }
     }
 
     /// **Apply Frame to Display**
     ///
     /// Replaces the current frame buffer with a new frame pattern.
     /// This is the primary method for updating the display content.
+    /// The display is only marked dirty if `frame` differs from the
+    /// current buffer.
     ///
     /// # Arguments
     /// * `frame` - New frame pattern to display
@@ -269,7 +573,75 @@ where
     /// display.apply(frame);
     /// ```
     pub fn apply(&mut self, frame: Frame<COLS, ROWS>) {
-        self.frame_buffer = frame;
+        if self.frame_buffer != frame {
+            self.frame_buffer = frame;
+            self.dirty = true;
+        }
+    }
+
+    /// **Draw to Back Buffer**
+    ///
+    /// Gives access to the back buffer, an off-screen frame for building up
+    /// several changes without the display showing a half-finished frame in
+    /// between. Nothing drawn there is visible until `swap`.
+    ///
+    /// The back buffer is independent of the frame on screen: `clear`,
+    /// `apply`, `on`/`off` and the drawing methods such as `display` only
+    /// change the frame buffer, and the back buffer keeps its contents
+    /// across `swap`, so each frame can be edited from the last one. Clear
+    /// it with `draw_to_back().clear()` to start from a blank frame.
+    ///
+    /// # Returns
+    /// The back buffer, to edit in place
+    ///
+    /// # Example
+    /// ```ignore
+    /// let back = display.draw_to_back();
+    /// back.clear();
+    /// for x in 0..5 {
+    ///     back.set(x, 2);
+    /// }
+    /// display.swap(); // the whole line appears at once
+    /// ```
+    #[allow(dead_code)]
+    pub fn draw_to_back(&mut self) -> &mut Frame<COLS, ROWS> {
+        &mut self.back_buffer
+    }
+
+    /// **Present Back Buffer**
+    ///
+    /// Copies the back buffer into the frame buffer, so every change made
+    /// through `draw_to_back` appears on the next refresh together. Frames
+    /// are `Copy`, so this is a plain copy of a few words, and like `apply`
+    /// it only marks the display dirty if the frame changed.
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.draw_to_back().set(2, 2);
+    /// display.swap();
+    /// ```
+    #[allow(dead_code)]
+    pub fn swap(&mut self) {
+        self.apply(self.back_buffer);
+    }
+
+    /// **Check Dirty Flag**
+    ///
+    /// Reports whether the frame buffer has changed since `render` last
+    /// completed a full pass over the rows. A renderer that keeps the pins
+    /// latched between passes can skip its pin writes while this is `false`.
+    ///
+    /// # Returns
+    /// `true` if the buffer changed and has not been fully refreshed since
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.apply(CHECK_MARK);
+    /// assert!(display.is_dirty());
+    /// ```
+    #[allow(dead_code)]
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
     }
 
     /// **Set Display Brightness**
@@ -293,6 +665,20 @@ where
         self.brightness = brightness;
     }
 
+    /// **Get Display Brightness**
+    ///
+    /// # Returns
+    /// The brightness level set by `set_brightness`
+    ///
+    /// # Example
+    /// ```ignore
+    /// defmt::info!("brightness {}", display.brightness().level());
+    /// ```
+    #[allow(dead_code)]
+    pub fn brightness(&self) -> Brightness {
+        self.brightness
+    }
+
     /// **Increase Brightness**
     ///
     /// Increments the current brightness level by 1, up to the maximum (10).
@@ -321,99 +707,1994 @@ where
         self.brightness -= 1;
     }
 
-    /// Perform a full refresh of the display based on the current frame buffer
-    pub fn render(&mut self) {
+    /// **Power Down Display**
+    ///
+    /// Blanks the matrix and drives every row and column pin low, so no LED
+    /// has any voltage across it and no current flows through the matrix.
+    /// The frame buffer is cleared.
+    ///
+    /// Nothing refreshes the display while it is powered down, so an idle
+    /// application just awaits its next input and the executor lets the CPU
+    /// sleep. `render` (and therefore `display`, `scroll` and every other
+    /// drawing method) powers the display back up automatically; call
+    /// `power_up` to do so explicitly without drawing.
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.power_down();
+    /// btn_a.wait_for_low().await; // CPU sleeps here
+    /// display.power_up();
+    /// ```
+    #[allow(dead_code)]
+    pub fn power_down(&mut self) {
+        self.frame_buffer.clear();
+        self.dirty = true;
         for row in self.pin_rows.iter_mut() {
-            row.set_low().ok();
+            row.set_state(!self.row_active).ok();
         }
+        for col in self.pin_cols.iter_mut() {
+            col.set_state(self.col_active).ok();
+        }
+        self.reset_timing();
+        self.powered = false;
+    }
 
-        for (cid, col) in self.pin_cols.iter_mut().enumerate() {
-            if self.frame_buffer.is_set(cid, self.row_p) {
-                col.set_low().ok();
-            } else {
-                col.set_high().ok();
-            }
+    /// **Power Up Display**
+    ///
+    /// Returns the pins to the blank idle state used by `clear`, ready for
+    /// drawing. The frame buffer is left untouched, so a frame applied while
+    /// powered down appears on the next refresh. Does nothing if the display
+    /// is already powered.
+    #[allow(dead_code)]
+    pub fn power_up(&mut self) {
+        if !self.powered {
+            self.blank_pins();
+            self.powered = true;
         }
+    }
 
-        // Adjust interval will impact brightness of the LEDs
-        block_for(Duration::from_micros(
-            ((Brightness::MAX.level() - self.brightness.level()) as u64) * 6000 / Brightness::MAX.level() as u64,
-        ));
+    /// **Check Power State**
+    ///
+    /// # Returns
+    /// `false` between `power_down` and the next `power_up` or `render`
+    #[allow(dead_code)]
+    pub fn is_powered(&self) -> bool {
+        self.powered
+    }
+
+    /// Perform a full refresh of the display based on the current frame buffer.
+    /// Powers the display back up first if `power_down` was called.
+    ///
+    /// Below full brightness the row is lit for its `gamma_on_time` and then
+    /// switched off again before returning; wait `refresh_wait` before the
+    /// next call to keep the refresh rate steady.
+    ///
+    /// # Falling Behind
+    /// A call that comes at least one whole `REFRESH_INTERVAL` late counts
+    /// the missed slots in `dropped_frames` and, below full brightness,
+    /// skips lighting its row so the `block_for` does not add to the lag.
+    /// At full brightness the previous row stayed lit through the delay;
+    /// that extra time is booked as the row's debt and the row sits out its
+    /// following turns until the debt is paid. Either way a busy executor
+    /// dims all rows evenly instead of leaving bright and dark bands.
+    pub fn render(&mut self) {
+        self.frames_rendered = self.frames_rendered.wrapping_add(1);
+        let now = Instant::now();
+        let late = self.track_lag(now);
+
+        self.power_up();
+        // Columns go dark before any row changes, so the previous row's
+        // pattern never shows on the new row (or vice versa) in between
+        self.blank_pins();
 
-        self.pin_rows[self.row_p].set_high().ok();
+        // The share of the refresh interval the row stays lit sets its brightness
+        let level = self.brightness.level();
+        let debt = &mut self.row_debt[self.row_p];
+        if level == Brightness::MAX.level() {
+            if *debt >= REFRESH_INTERVAL {
+                *debt -= REFRESH_INTERVAL;
+            } else {
+                self.light_row();
+                self.lit_row = Some(self.row_p);
+            }
+        } else if level > Brightness::MIN.level() && !late {
+            self.light_row();
+            block_for(Duration::from_micros(gamma_on_time(level)));
+            self.pin_rows[self.row_p].set_state(!self.row_active).ok();
+        }
 
         self.row_p = (self.row_p + 1) % self.pin_rows.len();
+        if self.row_p == 0 {
+            self.dirty = false;
+        }
     }
 
-    /// Display the provided frame for the duration. Handles screen refresh
-    /// in an async display loop.
-    pub async fn display(&mut self, frame: Frame<COLS, ROWS>, length: Duration) {
-        self.apply(frame);
-        let end = Instant::now() + length;
-        while Instant::now() < end {
-            self.render();
-            Timer::after(REFRESH_INTERVAL).await;
+    /// Record a refresh at `now`, counting missed slots and booking any extra
+    /// on-time of the row left lit since the previous call. Returns whether
+    /// the call is at least one whole slot late.
+    fn track_lag(&mut self, now: Instant) -> bool {
+        let elapsed = match self.last_render.replace(now) {
+            Some(last) => now.saturating_duration_since(last),
+            None => return false,
+        };
+        let lit_row = self.lit_row.take();
+
+        let slots = elapsed.as_ticks() / REFRESH_INTERVAL.as_ticks();
+        if slots < 2 {
+            return false;
         }
-        self.clear();
+        self.dropped_frames = self.dropped_frames.saturating_add((slots - 1) as u32);
+        if let Some(row) = lit_row {
+            let debt = self.row_debt[row] + (elapsed - REFRESH_INTERVAL);
+            self.row_debt[row] = debt.min(MAX_ROW_DEBT);
+        }
+        true
     }
 
-    /// Scroll the provided text across the LED display using default duration based on text length
-    pub async fn scroll(&mut self, text: &str) {
-        self.scroll_with_speed(text, Duration::from_secs((text.len() / 2) as u64))
-            .await;
+    /// **Dropped Frame Count**
+    ///
+    /// Number of refresh slots missed because `render` ran late, counted
+    /// since the display was created. Idle time after `clear` (which every
+    /// drawing method ends with) does not count. A steadily climbing count
+    /// means the executor is too busy to keep the display refreshed.
+    ///
+    /// # Returns
+    /// Total missed slots, saturating at `u32::MAX`
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.scroll("Hello").await;
+    /// defmt::info!("dropped {} frames", display.dropped_frames());
+    /// ```
+    #[allow(dead_code)]
+    pub fn dropped_frames(&self) -> u32 {
+        self.dropped_frames
     }
 
-    /// Scroll the provided text across the screen within the provided duration
-    pub async fn scroll_with_speed(&mut self, text: &str, speed: Duration) {
-        self.animate(text.as_bytes(), AnimationEffect::Slide, speed).await;
+    /// **Rendered Frame Count**
+    ///
+    /// Number of `render` calls since the display was created, each one a
+    /// single row refresh. Counting costs one add per call and takes no
+    /// timestamps, so it does not disturb the refresh timing. Read it
+    /// before and after a stretch of work and pass the difference to
+    /// `refresh_rate` to get the achieved rate; use `wrapping_sub`, as the
+    /// count wraps after about 24 days at full speed.
+    ///
+    /// # Returns
+    /// Total `render` calls, wrapping at `u32::MAX`
+    ///
+    /// # Example
+    /// ```ignore
+    /// let (start, frames) = (Instant::now(), display.frames_rendered());
+    /// display.scroll("Hello").await;
+    /// let rendered = display.frames_rendered().wrapping_sub(frames);
+    /// defmt::info!("{} refreshes/s", refresh_rate(rendered, start.elapsed()));
+    /// ```
+    #[allow(dead_code)]
+    pub fn frames_rendered(&self) -> u32 {
+        self.frames_rendered
     }
 
-    /// Apply animation based on data with the given effect during the provided duration
-    pub async fn animate(&mut self, data: &[u8], effect: AnimationEffect, duration: Duration) {
-        let mut animation: Animation<'_, COLS, ROWS> =
-            Animation::new(AnimationData::Bytes(data), effect, duration).unwrap();
-        loop {
-            match animation.next(Instant::now()) {
-                AnimationState::Apply(f) => {
-                    self.apply(f);
-                }
-                AnimationState::Wait => {}
-                AnimationState::Done => {
-                    break;
-                }
-            }
-            self.render();
-            Timer::after(REFRESH_INTERVAL).await;
+    /// Time to wait after `render` so every row takes one `REFRESH_INTERVAL`
+    fn refresh_wait(&self) -> Duration {
+        let level = self.brightness.level();
+        if level > Brightness::MIN.level() && level < Brightness::MAX.level() {
+            REFRESH_INTERVAL - Duration::from_micros(gamma_on_time(level))
+        } else {
+            REFRESH_INTERVAL
         }
-        self.clear();
     }
 
-    /// Animate a slice of frames using the provided effect during the provided duration
+    /// Display the provided frame for the duration. Handles screen refresh
+    /// in an async display loop.
+    pub async fn display(&mut self, frame: Frame<COLS, ROWS>, length: Duration) {
+        let mut display = self.clear_on_drop();
+        display.apply(frame);
+        display.refresh_until(Instant::now() + length).await;
+    }
+
+    /// **Display While Waiting**
+    ///
+    /// Keeps `frame` on the display for as long as `fut` takes to complete,
+    /// then clears the display and returns the future's output. Handy for a
+    /// busy indicator while waiting on a sensor or a message. Like the other
+    /// drawing methods it is cancel-safe: dropping the returned future also
+    /// drops `fut` and clears the display.
+    ///
+    /// # Arguments
+    /// * `frame` - Frame to show while waiting
+    /// * `fut` - Future to wait for
+    ///
+    /// # Returns
+    /// The output of `fut`
+    ///
+    /// # Example
+    /// ```ignore
+    /// let level = display.display_while(spinner_frame(0), mic.level()).await;
+    /// ```
     #[allow(dead_code)]
-    pub async fn animate_frames(&mut self, data: &[Frame<COLS, ROWS>], effect: AnimationEffect, duration: Duration) {
-        let mut animation: Animation<'_, COLS, ROWS> =
-            Animation::new(AnimationData::Frames(data), effect, duration).unwrap();
-        loop {
-            match animation.next(Instant::now()) {
-                AnimationState::Apply(f) => {
-                    self.apply(f);
-                }
-                AnimationState::Wait => {}
-                AnimationState::Done => {
-                    break;
-                }
+    pub async fn display_while<F: Future>(&mut self, frame: Frame<COLS, ROWS>, fut: F) -> F::Output {
+        let mut display = self.clear_on_drop();
+        display.apply(frame);
+        let refresh = async {
+            loop {
+                display.render();
+                Timer::after(display.refresh_wait()).await;
             }
-            self.render();
-            Timer::after(REFRESH_INTERVAL).await;
+        };
+        match select(fut, refresh).await {
+            Either::First(output) => output,
+            Either::Second(()) => unreachable!("the refresh loop never ends"),
         }
-        self.clear();
     }
 
-    /// Disassemble the `LedMatrix` and return the pins, as
-    /// an array of row pins and an array of column pins.
+    /// **Flash Alert**
+    ///
+    /// Flashes the whole matrix at full brightness `times` times, each flash
+    /// lit for the first half of `period` and dark for the second, as an
+    /// attention-grabbing overlay. The brightness and frame buffer in use
+    /// beforehand are restored afterwards, also if the alert is cancelled
+    /// by dropping the future, so the alert never changes the display's
+    /// state.
+    ///
+    /// # Arguments
+    /// * `times` - Number of flashes
+    /// * `period` - Length of one on-then-off flash
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.alert(3, Duration::from_millis(300)).await;
+    /// ```
     #[allow(dead_code)]
-    pub fn into_inner(self) -> ([P; ROWS], [P; COLS]) {
-        (self.pin_rows, self.pin_cols)
+    pub async fn alert(&mut self, times: usize, period: Duration) {
+        if times == 0 {
+            return;
+        }
+        let mut all_on = Frame::empty();
+        all_on.fill(true);
+
+        let mut display = Overlay {
+            brightness: self.brightness,
+            frame: self.frame_buffer,
+            display: self,
+        };
+        display.set_brightness(Brightness::MAX);
+        display.alternate(all_on, Frame::empty(), period, times).await;
+    }
+
+    /// **Dissolve In a Frame**
+    ///
+    /// Reveals the lit pixels of `frame` one at a time in a scrambled order,
+    /// starting from a blank display. The order comes from an internal
+    /// linear-feedback shift register, so no RNG peripheral is needed and
+    /// every lit pixel appears exactly once. The last pixel appears at
+    /// `duration * (n - 1) / n` and the complete frame is then held until
+    /// `duration` has elapsed, after which the display is cleared.
+    ///
+    /// # Arguments
+    /// * `frame` - Frame to reveal
+    /// * `duration` - Total time from the first pixel to clearing the display
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.dissolve(CHECK_MARK, Duration::from_secs(2)).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn dissolve(&mut self, frame: Frame<COLS, ROWS>, duration: Duration) {
+        let lit = (0..ROWS * COLS).filter(|&i| frame.is_set(i % COLS, i / COLS)).count() as u32;
+        if lit == 0 {
+            self.display(frame, duration).await;
+            return;
+        }
+
+        let mut display = self.clear_on_drop();
+        let start = Instant::now();
+        let mut shown = Frame::empty();
+        let mut revealed = 0;
+        for i in PixelShuffle::new(ROWS * COLS) {
+            let (x, y) = (i % COLS, i / COLS);
+            if !frame.is_set(x, y) {
+                continue;
+            }
+            shown.set(x, y);
+            display.apply(shown);
+            revealed += 1;
+            display.refresh_until(start + duration * revealed / lit).await;
+        }
+    }
+
+    /// **Twinkle**
+    ///
+    /// Attract-mode animation of random pixels fading in and out like
+    /// stars. Every tick a new star may appear at a random free pixel; it
+    /// fades in quickly, then dies away slowly. Brightness is global, so
+    /// each pixel's fade is shown by temporal dithering as in
+    /// `display_gray_dithered`: dimmer stars are lit on fewer refreshes.
+    /// Stars are born at random times and positions and each is at its own
+    /// point in its fade, so they never blink in step.
+    ///
+    /// Runs until cancelled, e.g. by losing a `select` against a button
+    /// press, which clears the display.
+    ///
+    /// # Arguments
+    /// * `rng` - Hardware random number generator choosing the stars
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut rng = Rng::new(board.rng, Irqs);
+    /// select(display.twinkle(&mut rng), board.btn_a.wait_for_low()).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn twinkle<T: rng::Instance>(&mut self, rng: &mut Rng<'_, T>) {
+        let mut display = self.clear_on_drop();
+        let mut levels = [[0u8; COLS]; ROWS];
+        let mut rising: Frame<COLS, ROWS> = Frame::empty();
+        let mut step = 0;
+        let mut tick_end = Instant::now();
+        loop {
+            for (y, row) in levels.iter_mut().enumerate() {
+                for (x, level) in row.iter_mut().enumerate() {
+                    if rising.is_set(x, y) {
+                        *level = level.saturating_add(TWINKLE_RISE);
+                        if *level == u8::MAX {
+                            rising.unset(x, y);
+                        }
+                    } else {
+                        // Lose about a sixth per tick, dying out in under a second
+                        *level = level.saturating_sub(*level / 6 + 2);
+                    }
+                }
+            }
+
+            let mut random = [0u8; 3];
+            rng.fill_bytes(&mut random).await;
+            if random[0] < TWINKLE_CHANCE {
+                let i = u16::from_le_bytes([random[1], random[2]]) as usize % (ROWS * COLS);
+                let (x, y) = (i % COLS, i / COLS);
+                if levels[y][x] == 0 {
+                    rising.set(x, y);
+                }
+            }
+
+            tick_end += TWINKLE_TICK;
+            while Instant::now() < tick_end {
+                display.apply(dither_frame(&levels, step));
+                let sub_frame_end = Instant::now() + REFRESH_INTERVAL * ROWS as u32;
+                display.refresh_until(sub_frame_end.min(tick_end)).await;
+                step = (step + 1) % DITHER_STEPS;
+            }
+        }
+    }
+
+    /// **Wipe to a Frame**
+    ///
+    /// Transitions from the current frame buffer to `to` by uncovering one
+    /// column (or row) at a time in the given direction, like a slide-show
+    /// wipe. Each step is shown for an equal share of `duration`.
+    ///
+    /// Unlike `display`, the frame buffer keeps `to` when the wipe ends
+    /// (the LEDs are switched off until the next refresh), so consecutive
+    /// wipes chain from one icon to the next. If `duration` is too short
+    /// for every step, intermediate steps are skipped but `to` is always
+    /// shown for at least one full refresh. Cancelling the wipe, for example
+    /// in a `select`, also switches the LEDs off and leaves `to` in the
+    /// frame buffer.
+    ///
+    /// # Arguments
+    /// * `to` - Frame to end on
+    /// * `direction` - Direction the wipe travels
+    /// * `duration` - Total transition time
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.apply(ARROW_LEFT);
+    /// display.wipe(ARROW_RIGHT, WipeDir::LeftToRight, Duration::from_millis(500)).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn wipe(&mut self, to: Frame<COLS, ROWS>, direction: WipeDir, duration: Duration) {
+        let from = self.frame_buffer;
+        let steps = match direction {
+            WipeDir::LeftToRight | WipeDir::RightToLeft => COLS,
+            WipeDir::TopToBottom | WipeDir::BottomToTop => ROWS,
+        } as u32;
+
+        let mut display = Transition { display: self, to };
+        let start = Instant::now();
+        for step in 1..steps {
+            display.apply(wipe_frame(&from, &to, direction, step as usize));
+            display.refresh_until(start + duration * step / steps).await;
+        }
+
+        display.apply(to);
+        let minimum = Instant::now() + REFRESH_INTERVAL * ROWS as u32;
+        display.refresh_until((start + duration).max(minimum)).await;
+    }
+
+    /// **Animate With Per-Frame Timing**
+    ///
+    /// Shows each frame for its own duration, without any sliding effect,
+    /// then clears the display. Frame times are scheduled from the start of
+    /// the animation, so refresh overhead does not accumulate as drift. A
+    /// frame with a zero duration is skipped.
+    ///
+    /// # Arguments
+    /// * `frames` - Frames paired with how long each stays on screen
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.animate_timed(&[
+    ///     (ARROW_LEFT, Duration::from_millis(200)),
+    ///     (ARROW_RIGHT, Duration::from_millis(800)),
+    /// ]).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn animate_timed(&mut self, frames: &[(Frame<COLS, ROWS>, Duration)]) {
+        let mut display = self.clear_on_drop();
+        let mut deadline = Instant::now();
+        for &(frame, duration) in frames {
+            deadline += duration;
+            display.apply(frame);
+            display.refresh_until(deadline).await;
+        }
+    }
+
+    /// **Alternate Between Two Frames**
+    ///
+    /// Shows `a` then `b`, each for half of `period`, for `cycles` cycles,
+    /// then clears the display. Handy for heartbeat-style icons that swap
+    /// between two patterns rather than flashing on and off.
+    ///
+    /// With `cycles == 0` the alternation runs until the future is dropped,
+    /// e.g. by losing a `select` against a button press. Dropping it at any
+    /// point is safe and clears the display.
+    ///
+    /// # Arguments
+    /// * `a` - Frame shown first in each cycle
+    /// * `b` - Frame shown second in each cycle
+    /// * `period` - Length of one a-then-b cycle
+    /// * `cycles` - Number of cycles, or 0 to repeat indefinitely
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.alternate(ARROW_LEFT, ARROW_RIGHT, Duration::from_secs(1), 3).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn alternate(&mut self, a: Frame<COLS, ROWS>, b: Frame<COLS, ROWS>, period: Duration, cycles: usize) {
+        let mut display = self.clear_on_drop();
+        let half = period / 2;
+        let mut deadline = Instant::now();
+        let mut done = 0;
+        while cycles == 0 || done < cycles {
+            for frame in [a, b] {
+                deadline += half;
+                display.apply(frame);
+                display.refresh_until(deadline).await;
+            }
+            done += 1;
+        }
+    }
+
+    /// Keep refreshing the current frame buffer until `deadline`
+    async fn refresh_until(&mut self, deadline: Instant) {
+        while Instant::now() < deadline {
+            self.render();
+            Timer::after(self.refresh_wait()).await;
+        }
+    }
+
+    /// **LED Self-Test**
+    ///
+    /// Walks through a fixed sequence of patterns so every LED and every
+    /// row/column connection can be checked by eye during hardware bring-up:
+    ///
+    /// 1. Each LED alone, row by row from the top-left corner
+    /// 2. Each full row, top to bottom
+    /// 3. Each full column, left to right
+    /// 4. All LEDs on together
+    ///
+    /// A dark spot in step 1 is a dead LED, a missing line in steps 2-3 is
+    /// a dead row or column pin, and a light appearing out of sequence
+    /// points to swapped pins. The display is cleared when the test ends.
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.self_test().await;
+    /// ```
+    pub async fn self_test(&mut self) {
+        const STEP: Duration = Duration::from_millis(50);
+        const HOLD: Duration = Duration::from_millis(300);
+
+        for y in 0..ROWS {
+            for x in 0..COLS {
+                let mut frame = Frame::empty();
+                frame.set(x, y);
+                self.display(frame, STEP).await;
+            }
+        }
+
+        for y in 0..ROWS {
+            let mut frame = Frame::empty();
+            for x in 0..COLS {
+                frame.set(x, y);
+            }
+            self.display(frame, HOLD).await;
+        }
+
+        for x in 0..COLS {
+            let mut frame = Frame::empty();
+            for y in 0..ROWS {
+                frame.set(x, y);
+            }
+            self.display(frame, HOLD).await;
+        }
+
+        let mut frame = Frame::empty();
+        for y in 0..ROWS {
+            for x in 0..COLS {
+                frame.set(x, y);
+            }
+        }
+        self.display(frame, HOLD).await;
+        self.clear();
+    }
+
+    /// Scroll the provided text across the LED display using default duration based on text length
+    pub async fn scroll(&mut self, text: &str) {
+        self.scroll_with_speed(text, default_scroll_duration(text)).await;
+    }
+
+    /// Scroll the provided text across the screen within the provided duration.
+    /// Durations too short for the refresh rate are stretched to the fastest
+    /// feasible speed. Glyphs sit side by side in 5-column cells whatever the
+    /// display width, so chained displays show several characters at once.
+    pub async fn scroll_with_speed(&mut self, text: &str, speed: Duration) {
+        self.scroll_spaced(text, speed, 0).await;
+    }
+
+    /// **Try to Scroll Text**
+    ///
+    /// Like [`scroll`](Self::scroll), but reports `AnimationError::TooFast`
+    /// instead of slowing the scroll down.
+    ///
+    /// # Returns
+    /// `Err(AnimationError::TooFast)` if the default duration is too short
+    /// for the refresh rate, in which case nothing is displayed
+    #[allow(dead_code)]
+    pub async fn try_scroll(&mut self, text: &str) -> Result<(), AnimationError> {
+        self.try_scroll_with_speed(text, default_scroll_duration(text)).await
+    }
+
+    /// **Try to Scroll Text Within a Duration**
+    ///
+    /// Like [`scroll_with_speed`](Self::scroll_with_speed), but reports
+    /// `AnimationError::TooFast` instead of slowing the scroll down.
+    ///
+    /// # Returns
+    /// `Err(AnimationError::TooFast)` if a column would be shown for less
+    /// than one refresh interval, in which case nothing is displayed
+    ///
+    /// # Example
+    /// ```ignore
+    /// if display.try_scroll_with_speed("Hi", Duration::from_micros(1)).await.is_err() {
+    ///     display.scroll("Hi").await;
+    /// }
+    /// ```
+    #[allow(dead_code)]
+    pub async fn try_scroll_with_speed(&mut self, text: &str, speed: Duration) -> Result<(), AnimationError> {
+        let columns = spaced_width(text, 0) as u32;
+        if columns > 0 && speed / columns < REFRESH_INTERVAL {
+            return Err(AnimationError::TooFast);
+        }
+        self.scroll_spaced(text, speed, 0).await;
+        Ok(())
+    }
+
+    /// **Scroll Right-to-Left Text**
+    ///
+    /// Scrolls `text` rightward for scripts read from right to left: the
+    /// first character starts on the display and each following character
+    /// enters from the left, so the text on screen reads right to left.
+    /// Characters are drawn from the built-in ASCII font in the order they
+    /// appear in `text`; no shaping or bidirectional reordering is done.
+    ///
+    /// # Arguments
+    /// * `text` - Text to scroll, first character first
+    /// * `speed` - Duration of the whole scroll, as for `scroll_with_speed`
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.scroll_rtl("SHALOM", Duration::from_secs(3)).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn scroll_rtl(&mut self, text: &str, speed: Duration) {
+        self.animate(text.as_bytes(), AnimationEffect::SlideRight, speed).await;
+    }
+
+    /// **Scroll Text Word by Word**
+    ///
+    /// Splits `text` at whitespace and scrolls each word on its own, with a
+    /// short blank pause (`WORD_PAUSE`) in between, which is easier to read
+    /// than one long stream. Runs of spaces count as a single break, and a
+    /// word is never split: long words simply take longer to scroll by.
+    ///
+    /// # Arguments
+    /// * `text` - Text to scroll
+    /// * `speed` - Time each character takes to scroll past; stretched if
+    ///   too short for the refresh rate
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.scroll_words("Hello  big world", Duration::from_millis(400)).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn scroll_words(&mut self, text: &str, speed: Duration) {
+        for (i, word) in text.split_whitespace().enumerate() {
+            // Each scroll ends with the display cleared, so this gap is blank
+            if i > 0 {
+                Timer::after(WORD_PAUSE).await;
+            }
+            self.scroll_with_speed(word, speed * word.len() as u32).await;
+        }
+    }
+
+    /// **Scroll a Playlist**
+    ///
+    /// Scrolls each message of `messages` in order, with the display blank
+    /// for `gap` after each one, then starts over from the first message,
+    /// until the future is dropped, e.g. by losing a `select`. Every scroll
+    /// clears the display when it ends or is cancelled, and so does
+    /// cancelling during a gap, so the display is always left blank.
+    ///
+    /// An empty `messages` slice returns straight away, and so does one
+    /// holding only empty strings; empty messages in a longer playlist are
+    /// skipped.
+    ///
+    /// # Arguments
+    /// * `messages` - Messages to scroll, first one first
+    /// * `speed` - Time each character takes to scroll past, as for
+    ///   `scroll_words`, so long and short messages move at the same rate
+    /// * `gap` - Blank pause after each message
+    ///
+    /// # Example
+    /// ```ignore
+    /// let messages = ["TEMP OK", "BATT 80%", "RADIO ON"];
+    /// select(
+    ///     display.scroll_playlist(&messages, Duration::from_millis(400), Duration::from_secs(1)),
+    ///     btn_a.wait_for_low(),
+    /// )
+    /// .await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn scroll_playlist(&mut self, messages: &[&str], speed: Duration, gap: Duration) {
+        if messages.iter().all(|message| message.is_empty()) {
+            return;
+        }
+        loop {
+            for message in messages.iter().filter(|message| !message.is_empty()) {
+                self.scroll_with_speed(message, speed * message.len() as u32).await;
+                Timer::after(gap).await;
+            }
+        }
+    }
+
+    /// **Scroll Text Repeatedly**
+    ///
+    /// Scrolls `text` like [`scroll_with_speed`](Self::scroll_with_speed),
+    /// but as a continuous banner: after the last character, a single blank
+    /// column goes by and the first character follows straight on, so the
+    /// text never jumps back to its starting position. The final pass ends
+    /// with the text sliding off the display, exactly like a single scroll.
+    ///
+    /// With `times == 0` the banner repeats until the future is dropped,
+    /// e.g. by losing a `select`, which clears the display.
+    ///
+    /// # Arguments
+    /// * `text` - Text to scroll
+    /// * `speed` - Duration of one pass, as for `scroll_with_speed`
+    ///   (too-short durations are stretched to the fastest feasible speed)
+    /// * `times` - Number of passes, or 0 to repeat indefinitely
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.scroll_loop("GO!", Duration::from_secs(2), 3).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn scroll_loop(&mut self, text: &str, speed: Duration, times: usize) {
+        if text.is_empty() {
+            return;
+        }
+        let period = text.len() * GLYPH_WIDTH + 1;
+        let wait = feasible_duration(period, speed) / period as u32;
+        let steps = scroll_loop_steps(text.len(), times);
+
+        let mut display = self.clear_on_drop();
+        let mut deadline = Instant::now();
+        let mut step = 0;
+        while steps.is_none_or(|steps| step < steps) {
+            display.apply(banner_window(text.as_bytes(), step % period));
+            deadline += wait;
+            display.refresh_until(deadline).await;
+            step += 1;
+        }
+    }
+
+    /// **Scroll Then Hold**
+    ///
+    /// Scrolls `text` like [`scroll_with_speed`](Self::scroll_with_speed),
+    /// but stops once the end of the text has slid fully into view and
+    /// holds the last screenful there for `hold` instead of scrolling it
+    /// off, then clears the display. Text no wider than the display is
+    /// simply shown and held. Good for status messages whose final word
+    /// matters most.
+    ///
+    /// # Arguments
+    /// * `text` - Text to scroll
+    /// * `speed` - Duration a full `scroll_with_speed` of `text` would take;
+    ///   the scroll moves at that rate but ends early
+    /// * `hold` - How long the last screenful stays on screen
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.scroll_and_hold("TEMP OK", Duration::from_secs(3), Duration::from_secs(2)).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn scroll_and_hold(&mut self, text: &str, speed: Duration, hold: Duration) {
+        if text.is_empty() {
+            return;
+        }
+        let columns = spaced_width(text, 0);
+        let wait = feasible_duration(columns, speed) / columns as u32;
+        // Offset at which the text ends at the right edge of the display
+        let last = columns.saturating_sub(COLS);
+
+        let mut display = self.clear_on_drop();
+        let mut deadline = Instant::now();
+        for offset in 0..last {
+            display.apply(spaced_window(text.as_bytes(), offset, 0));
+            deadline += wait;
+            display.refresh_until(deadline).await;
+        }
+        display.apply(spaced_window(text.as_bytes(), last, 0));
+        display.refresh_until(deadline + hold).await;
+    }
+
+    /// **Scroll Spaced Text**
+    ///
+    /// Scrolls `text` like [`scroll_with_speed`](Self::scroll_with_speed),
+    /// with `spacing` blank columns inserted between neighboring glyphs so
+    /// letters that fill their cell, such as "ll", do not run together.
+    /// `speed` covers the whole, now wider, scroll of
+    /// `spaced_width(text, spacing)` columns; a `spacing` of 0 scrolls
+    /// exactly like `scroll_with_speed`.
+    ///
+    /// # Arguments
+    /// * `text` - Text to scroll
+    /// * `speed` - Duration of the whole scroll (too-short durations are
+    ///   stretched to the fastest feasible speed)
+    /// * `spacing` - Blank columns between glyphs
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.scroll_spaced("Hello", Duration::from_secs(3), 1).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn scroll_spaced(&mut self, text: &str, speed: Duration, spacing: usize) {
+        let width = spaced_width(text, spacing);
+        if width == 0 {
+            return;
+        }
+        let wait = feasible_duration(width, speed) / width as u32;
+
+        let mut display = self.clear_on_drop();
+        let mut deadline = Instant::now();
+        for offset in 0..width {
+            display.apply(spaced_window(text.as_bytes(), offset, spacing));
+            deadline += wait;
+            display.refresh_until(deadline).await;
+        }
+    }
+
+    /// **Scroll Proportional Text**
+    ///
+    /// Scrolls `text` with each glyph trimmed to the columns it actually
+    /// uses and a single blank column between glyphs, so narrow letters
+    /// such as `i` and `l` pack tightly instead of sitting in a fixed
+    /// 5-column cell. Blank glyphs such as space become a fixed
+    /// `PROPORTIONAL_SPACE_WIDTH`-column gap. The scroll starts with the
+    /// first glyph at the left edge and ends once the last column has moved
+    /// off the display.
+    ///
+    /// # Arguments
+    /// * `text` - Text to scroll
+    /// * `speed` - Duration of the whole scroll (too-short durations are
+    ///   stretched to the fastest feasible speed)
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.scroll_proportional("Hill", Duration::from_secs(2)).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn scroll_proportional(&mut self, text: &str, speed: Duration) {
+        let width = proportional_width(text);
+        if width == 0 {
+            return;
+        }
+        let wait = feasible_duration(width, speed) / width as u32;
+
+        let mut display = self.clear_on_drop();
+        let mut deadline = Instant::now();
+        for offset in 0..width {
+            display.apply(proportional_window(text.as_bytes(), offset));
+            deadline += wait;
+            display.refresh_until(deadline).await;
+        }
+    }
+
+    /// **Scrolling Ticker**
+    ///
+    /// Scrolls messages received from a channel, forever. The ticker waits
+    /// for the first message, then scrolls the current message and checks
+    /// the channel each time a scroll completes: a queued message becomes
+    /// the new current one, otherwise the last message repeats.
+    ///
+    /// With `interrupt` set, a message that arrives mid-scroll cuts the
+    /// current scroll short and starts scrolling immediately, which suits
+    /// urgent alerts; otherwise messages wait for the running scroll to end.
+    ///
+    /// # Arguments
+    /// * `rx` - Receiving end of the channel carrying messages to show
+    /// * `interrupt` - Let a newly arrived message cut off the running scroll
+    ///
+    /// # Example
+    /// ```ignore
+    /// static TICKER: Channel<CriticalSectionRawMutex, heapless::String<16>, 4> = Channel::new();
+    /// display.ticker(&mut TICKER.receiver(), false).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn ticker<M: RawMutex, const N: usize, const Q: usize>(
+        &mut self,
+        rx: &mut Receiver<'_, M, String<N>, Q>,
+        interrupt: bool,
+    ) {
+        let mut current = rx.receive().await;
+        loop {
+            if interrupt {
+                // Dropping the unfinished scroll clears the display
+                if let Either::Second(next) = select(self.scroll(&current), rx.receive()).await {
+                    current = next;
+                    continue;
+                }
+            } else {
+                self.scroll(&current).await;
+            }
+            if let Ok(next) = rx.try_receive() {
+                current = next;
+            }
+        }
+    }
+
+    /// **Scroll Text at a Constant Rate**
+    ///
+    /// Scrolls text so that every column moves at the same visual speed,
+    /// regardless of how long the string is. The per-column wait is derived
+    /// from `pixels_per_sec` and the total duration becomes
+    /// `total_columns / pixels_per_sec`.
+    ///
+    /// # Arguments
+    /// * `text` - Text to scroll
+    /// * `pixels_per_sec` - Scroll rate in columns per second (0 is treated as 1)
+    ///
+    /// # Returns
+    /// `Err(AnimationError::TooFast)` if a column would be shown for less than
+    /// one refresh interval, in which case nothing is displayed
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.scroll_at("Hello", 10).await?; // 25 columns in 2.5 s
+    /// ```
+    #[allow(dead_code)]
+    pub async fn scroll_at(&mut self, text: &str, pixels_per_sec: u32) -> Result<(), AnimationError> {
+        let wait = column_wait(pixels_per_sec)?;
+        if text.is_empty() {
+            return Ok(());
+        }
+        let columns = spaced_width(text, 0) as u32;
+        self.try_scroll_with_speed(text, wait * columns).await
+    }
+
+    /// **Scroll Frames**
+    ///
+    /// Scrolls arbitrary frames from right to left exactly as `scroll`
+    /// scrolls text: the first frame starts on the display, each following
+    /// frame slides in from the right and the last one slides off to leave
+    /// a blank display. Frames built from characters with `into` scroll
+    /// identically to the same text, so the two can be mixed freely.
+    /// Durations too short for the refresh rate are stretched to the
+    /// fastest feasible speed, and an empty slice shows nothing.
+    ///
+    /// # Arguments
+    /// * `frames` - Frames to show, in order
+    /// * `speed` - Total duration of the scroll
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.scroll_frames(&[HEART, b'!'.into()], Duration::from_secs(2)).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn scroll_frames(&mut self, frames: &[Frame<COLS, ROWS>], speed: Duration) {
+        if frames.is_empty() {
+            return;
+        }
+        self.animate_frames(frames, AnimationEffect::Slide, speed).await;
+    }
+
+    /// **Scroll Frames Vertically**
+    ///
+    /// Slides through `frames` from bottom to top: each frame rises into
+    /// view as the previous one moves off the top edge, and the last frame
+    /// finally scrolls away to leave a blank display. Durations too short
+    /// for the refresh rate are stretched to the fastest feasible speed.
+    ///
+    /// # Arguments
+    /// * `frames` - Frames to show, in order
+    /// * `speed` - Total duration of the scroll
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.scroll_vertical(&[ARROW_LEFT, ARROW_RIGHT], Duration::from_secs(2)).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn scroll_vertical(&mut self, frames: &[Frame<COLS, ROWS>], speed: Duration) {
+        self.animate_frames(frames, AnimationEffect::SlideUp, speed).await;
+    }
+
+    /// **Show Short Text**
+    ///
+    /// Text narrow enough to fit on the display (a single font glyph on the
+    /// 5x5 matrix) is centered horizontally and held for `hold`; anything
+    /// wider is scrolled with the default speed instead. Useful for
+    /// single-letter status indicators that should sit still.
+    ///
+    /// # Arguments
+    /// * `text` - Text to show
+    /// * `hold` - How long fitting text stays on screen
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.show_text("A", Duration::from_secs(1)).await; // held, centered
+    /// display.show_text("AB", Duration::from_secs(1)).await; // scrolled
+    /// ```
+    #[allow(dead_code)]
+    pub async fn show_text(&mut self, text: &str, hold: Duration) {
+        if text_fits::<COLS>(text) {
+            let glyph = text.bytes().next().map_or(Frame::empty(), |b| b.into());
+            self.display(center_horizontally(glyph), hold).await;
+        } else {
+            self.scroll(text).await;
+        }
+    }
+
+    /// **Type Text**
+    ///
+    /// Reveals `text` one character at a time in place, like a teletype:
+    /// each glyph is centered and held for `per_char`, then the next one
+    /// replaces it. Spaces show a blank display for `per_char`, so words
+    /// stay visibly apart, and a short blank gap separates identical
+    /// consecutive characters. Text of any length works, as only one
+    /// character is on screen at a time. The display is cleared afterwards.
+    ///
+    /// # Arguments
+    /// * `text` - Text to type out
+    /// * `per_char` - How long each character stays on screen
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.type_text("OK", Duration::from_millis(500)).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn type_text(&mut self, text: &str, per_char: Duration) {
+        let mut previous = None;
+        for b in text.bytes() {
+            // Each `display` ends with the display cleared, so this gap is blank
+            if previous == Some(b) {
+                Timer::after(REPEAT_GAP).await;
+            }
+            self.display(center_horizontally(b.into()), per_char).await;
+            previous = Some(b);
+        }
+    }
+
+    /// **Show Integer Value**
+    ///
+    /// Formats a signed integer (with a leading minus glyph for negative
+    /// values) and scrolls it across the display within the provided duration.
+    /// Single-character values already fill the 5-column glyph cell, so they
+    /// are held in place for `speed` instead of sliding past.
+    ///
+    /// # Arguments
+    /// * `n` - Value to display
+    /// * `speed` - Total scroll duration, or hold time for a single digit
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.show_number(-42, Duration::from_secs(2)).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn show_number(&mut self, n: i32, speed: Duration) {
+        self.show_formatted(&format_number(n), speed).await;
+    }
+
+    /// **Show Fixed-Point Value**
+    ///
+    /// Displays a scaled integer with a decimal point glyph inserted
+    /// `decimals` digits from the right, e.g. `235` with `decimals = 1`
+    /// scrolls "23.5". Useful for sensor readings such as temperature.
+    ///
+    /// # Arguments
+    /// * `value` - Scaled value to display
+    /// * `decimals` - Number of digits after the decimal point
+    /// * `speed` - Total scroll duration
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.show_fixed(235, 1, Duration::from_secs(2)).await; // "23.5"
+    /// ```
+    #[allow(dead_code)]
+    pub async fn show_fixed(&mut self, value: i32, decimals: u8, speed: Duration) {
+        self.show_formatted(&format_fixed(value, decimals), speed).await;
+    }
+
+    /// **Show Hexadecimal Value**
+    ///
+    /// Scrolls `value` in uppercase hexadecimal, zero-padded to at least
+    /// `digits` digits (at most 8), e.g. `0x1F` with `digits = 4` scrolls
+    /// "001F". Handy for inspecting registers and raw sensor bytes.
+    ///
+    /// # Arguments
+    /// * `value` - Value to display
+    /// * `digits` - Minimum number of hex digits
+    /// * `speed` - Total scroll duration, or hold time for a single digit
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.show_hex(status as u32, 2, Duration::from_secs(2)).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn show_hex(&mut self, value: u32, digits: u8, speed: Duration) {
+        self.show_formatted(&format_hex(value, digits), speed).await;
+    }
+
+    /// **Show Clock**
+    ///
+    /// Scrolls a 24-hour time of day as `HH:MM`, e.g. `9:05` scrolls
+    /// "09:05". Hours above 23 and minutes above 59 are clamped, as for
+    /// `format_hh_mm`.
+    ///
+    /// # Arguments
+    /// * `hours` - Hour of the day, 0-23
+    /// * `minutes` - Minute of the hour, 0-59
+    /// * `speed` - Total scroll duration
+    ///
+    /// # Example
+    /// ```ignore
+    /// let minutes = (Instant::now().as_secs() / 60 + start_minutes) % (24 * 60);
+    /// display.show_clock((minutes / 60) as u8, (minutes % 60) as u8, Duration::from_secs(3)).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn show_clock(&mut self, hours: u8, minutes: u8, speed: Duration) {
+        self.scroll_with_speed(&format_hh_mm(hours, minutes), speed).await;
+    }
+
+    /// Hold single-character text in place, scroll anything longer
+    async fn show_formatted(&mut self, text: &str, speed: Duration) {
+        if text.len() == 1 {
+            self.display(text.as_bytes()[0].into(), speed).await;
+        } else {
+            self.scroll_with_speed(text, speed).await;
+        }
+    }
+
+    /// Apply animation based on data with the given effect during the provided duration.
+    /// Durations too short for the refresh rate are stretched to the fastest
+    /// feasible speed.
+    pub async fn animate(&mut self, data: &[u8], effect: AnimationEffect, duration: Duration) {
+        let duration = feasible_duration(animation_steps::<COLS, ROWS>(data.len(), effect), duration);
+        // Cannot fail: empty data is a no-op and the duration was clamped above
+        self.try_animate(data, effect, duration).await.ok();
+    }
+
+    /// Animate a slice of frames using the provided effect during the provided duration.
+    /// Durations too short for the refresh rate are stretched to the fastest
+    /// feasible speed.
+    #[allow(dead_code)]
+    pub async fn animate_frames(&mut self, data: &[Frame<COLS, ROWS>], effect: AnimationEffect, duration: Duration) {
+        let duration = feasible_duration(animation_steps::<COLS, ROWS>(data.len(), effect), duration);
+        // Cannot fail: empty data is a no-op and the duration was clamped above
+        self.try_animate_frames(data, effect, duration).await.ok();
+    }
+
+    /// **Animate Frames in Reverse**
+    ///
+    /// Like [`animate_frames`](Self::animate_frames), but plays `data` from
+    /// the last frame to the first. `duration` covers this reversed pass
+    /// alone. Calling it right after `animate_frames` with the same frames
+    /// gives a one-shot there-and-back effect. Empty `data` is a no-op.
+    ///
+    /// # Arguments
+    /// * `data` - Frames to play, last one first
+    /// * `effect` - Transition between consecutive frames
+    /// * `duration` - Total time for the reversed pass
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.animate_frames(&frames, AnimationEffect::Slide, Duration::from_secs(1)).await;
+    /// display.animate_frames_rev(&frames, AnimationEffect::Slide, Duration::from_secs(1)).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn animate_frames_rev(
+        &mut self,
+        data: &[Frame<COLS, ROWS>],
+        effect: AnimationEffect,
+        duration: Duration,
+    ) {
+        if data.is_empty() {
+            return;
+        }
+        let duration = feasible_duration(animation_steps::<COLS, ROWS>(data.len(), effect), duration);
+        if let Ok(animation) = Animation::new(AnimationData::Frames(data), effect, duration) {
+            self.run_animation(animation.with_playback(Playback::Reverse)).await;
+        }
+    }
+
+    /// **Try to Animate Bytes**
+    ///
+    /// Like [`animate`](Self::animate), but reports `AnimationError::TooFast`
+    /// instead of slowing the animation down.
+    ///
+    /// # Returns
+    /// `Err(AnimationError::TooFast)` if a step would be shown for less than
+    /// one refresh interval, in which case nothing is displayed. Empty
+    /// `data` shows nothing and returns `Ok`.
+    pub async fn try_animate(
+        &mut self,
+        data: &[u8],
+        effect: AnimationEffect,
+        duration: Duration,
+    ) -> Result<(), AnimationError> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        let animation = Animation::new(AnimationData::Bytes(data), effect, duration)?;
+        self.run_animation(animation).await;
+        Ok(())
+    }
+
+    /// **Try to Animate Frames**
+    ///
+    /// Like [`animate_frames`](Self::animate_frames), but reports
+    /// `AnimationError::TooFast` instead of slowing the animation down.
+    ///
+    /// # Returns
+    /// `Err(AnimationError::TooFast)` if a step would be shown for less than
+    /// one refresh interval, in which case nothing is displayed. Empty
+    /// `data` shows nothing and returns `Ok`.
+    pub async fn try_animate_frames(
+        &mut self,
+        data: &[Frame<COLS, ROWS>],
+        effect: AnimationEffect,
+        duration: Duration,
+    ) -> Result<(), AnimationError> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        let animation = Animation::new(AnimationData::Frames(data), effect, duration)?;
+        self.run_animation(animation).await;
+        Ok(())
+    }
+
+    /// **Scroll Inverted Text**
+    ///
+    /// Scrolls `text` in reverse video: the background is lit and the glyph
+    /// pixels are dark. The gaps between characters and the blank space the
+    /// text slides into are lit too, so it reads as one continuous banner.
+    /// Durations too short for the refresh rate are stretched to the
+    /// fastest feasible speed. Empty text shows nothing.
+    ///
+    /// # Arguments
+    /// * `text` - Text to scroll
+    /// * `speed` - Total scroll duration
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.scroll_inverted("ALERT", Duration::from_secs(3)).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn scroll_inverted(&mut self, text: &str, speed: Duration) {
+        if text.is_empty() {
+            return;
+        }
+        let data = text.as_bytes();
+        let effect = AnimationEffect::Slide;
+        let duration = feasible_duration(animation_steps::<COLS, ROWS>(data.len(), effect), speed);
+        if let Ok(animation) = Animation::new(AnimationData::Bytes(data), effect, duration) {
+            self.run_animation_with(animation, |mut frame| {
+                frame.invert();
+                frame
+            })
+            .await;
+        }
+    }
+
+    /// Drive an animation to completion, refreshing the display between steps
+    async fn run_animation(&mut self, animation: Animation<'_, COLS, ROWS>) {
+        self.run_animation_with(animation, |frame| frame).await;
+    }
+
+    /// Drive an animation to completion, passing each step through `map`
+    /// before it is displayed
+    async fn run_animation_with(
+        &mut self,
+        mut animation: Animation<'_, COLS, ROWS>,
+        map: impl Fn(Frame<COLS, ROWS>) -> Frame<COLS, ROWS>,
+    ) {
+        let mut display = self.clear_on_drop();
+        loop {
+            match animation.step(Instant::now()) {
+                AnimationState::Apply(f) => {
+                    display.apply(map(f));
+                }
+                AnimationState::Wait => {}
+                AnimationState::Done => {
+                    break;
+                }
+            }
+            display.render();
+            Timer::after(display.refresh_wait()).await;
+        }
+    }
+
+    /// **Clear On Drop**
+    ///
+    /// Borrows the display behind a guard that clears it when dropped. Every
+    /// built-in drawing method that ends with a blank display already holds
+    /// one across its awaits, so dropping such a future mid-animation (e.g.
+    /// by losing a `select`) leaves the display blank too. Use the guard
+    /// the same way in custom drawing loops that may be cancelled.
+    ///
+    /// # Returns
+    /// A guard that dereferences to this display
+    ///
+    /// # Example
+    /// ```ignore
+    /// let show = async {
+    ///     let mut display = display.clear_on_drop();
+    ///     loop {
+    ///         display.apply(next_frame());
+    ///         display.render();
+    ///         Timer::after_micros(500).await;
+    ///     }
+    /// };
+    /// select(show, button.wait_for_low()).await; // display is blank here
+    /// ```
+    pub fn clear_on_drop(&mut self) -> ClearOnDrop<'_, P, ROWS, COLS> {
+        ClearOnDrop { display: self }
+    }
+
+    /// **Is Blank**
+    ///
+    /// # Returns
+    /// `true` if no pixel of the frame buffer is lit, e.g. after `clear`
+    ///
+    /// # Example
+    /// ```ignore
+    /// select(display.scroll("Hello"), button.wait_for_low()).await;
+    /// assert!(display.is_blank());
+    /// ```
+    #[allow(dead_code)]
+    pub fn is_blank(&self) -> bool {
+        (0..ROWS).all(|y| (0..COLS).all(|x| !self.frame_buffer.is_set(x, y)))
+    }
+
+    /// Disassemble the `LedMatrix` and return the pins, as
+    /// an array of row pins and an array of column pins.
+    #[allow(dead_code)]
+    pub fn into_inner(self) -> ([P; ROWS], [P; COLS]) {
+        (self.pin_rows, self.pin_cols)
+    }
+}
+
+impl<P> LedMatrix<P, 5, 5>
+where
+    P: OutputPin,
+{
+    /// **Countdown Timer**
+    ///
+    /// Counts down `seconds` seconds while a spinner, seeded with the
+    /// upright `spinner_frame`, turns a quarter turn counter-clockwise
+    /// (`rotate_left`) each second, then blinks a check mark three times
+    /// and clears the display. Seconds are scheduled from the start, so the
+    /// total time does not drift.
+    ///
+    /// The countdown can be cancelled by dropping the future, e.g. by
+    /// racing it against a button in a `select`, which clears the display.
+    ///
+    /// # Arguments
+    /// * `seconds` - Length of the countdown
+    ///
+    /// # Example
+    /// ```ignore
+    /// match select(display.countdown(60), btn_b.wait_for_low()).await {
+    ///     Either::First(_) => defmt::info!("time's up"),
+    ///     Either::Second(_) => defmt::info!("cancelled"),
+    /// }
+    /// ```
+    #[allow(dead_code)]
+    pub async fn countdown(&mut self, seconds: u32) {
+        let mut display = self.clear_on_drop();
+        let mut spinner = spinner_frame(0);
+        let mut deadline = Instant::now();
+        for _ in 0..seconds {
+            deadline += Duration::from_secs(1);
+            display.apply(spinner);
+            display.refresh_until(deadline).await;
+            spinner = spinner.rotate_left();
+        }
+        display
+            .alternate(CHECK_MARK, Frame::empty(), Duration::from_millis(400), 3)
+            .await;
+    }
+
+    /// **Display Grayscale Image**
+    ///
+    /// Shows a grayscale image for `duration` by temporal dithering: every
+    /// full refresh of the matrix shows the next `dither_frame` sub-frame,
+    /// so over a cycle of `DITHER_STEPS` refreshes brighter pixels are lit
+    /// more often and the eye averages them into gray levels. Level 0 is
+    /// never lit and level 255 always is. The global brightness still
+    /// applies on top, and the display is cleared afterwards.
+    ///
+    /// # Arguments
+    /// * `levels` - Pixel levels, `levels[y][x]`, from 0 (off) to 255 (full)
+    /// * `duration` - How long the image stays on screen
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut gradient = [[0; 5]; 5];
+    /// for (y, row) in gradient.iter_mut().enumerate() {
+    ///     *row = [(y * 255 / 4) as u8; 5];
+    /// }
+    /// display.display_gray_dithered(&gradient, Duration::from_secs(3)).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn display_gray_dithered(&mut self, levels: &[[u8; 5]; 5], duration: Duration) {
+        let mut display = self.clear_on_drop();
+        let deadline = Instant::now() + duration;
+        let mut step = 0;
+        while Instant::now() < deadline {
+            display.apply(dither_frame(levels, step));
+            let sub_frame_end = Instant::now() + REFRESH_INTERVAL * 5;
+            display.refresh_until(sub_frame_end.min(deadline)).await;
+            step = (step + 1) % DITHER_STEPS;
+        }
+    }
+
+    /// **Show Spinner**
+    ///
+    /// Plays the `spinner_frame` busy indicator for `cycles` full turns,
+    /// then clears the display. Phases are scheduled from the start, so the
+    /// rotation stays even however long each refresh takes.
+    ///
+    /// Like `countdown`, it can be cancelled by dropping the future, e.g.
+    /// racing it against a radio reply in a `select`, which clears the
+    /// display.
+    ///
+    /// # Arguments
+    /// * `cycles` - Number of full turns
+    /// * `period` - Time for one full turn
+    ///
+    /// # Example
+    /// ```ignore
+    /// select(display.show_spinner(usize::MAX, Duration::from_millis(800)), radio.receive(&mut packet)).await;
+    /// ```
+    #[allow(dead_code)]
+    pub async fn show_spinner(&mut self, cycles: usize, period: Duration) {
+        let mut display = self.clear_on_drop();
+        let step = period / SPINNER_PHASES as u32;
+        let mut deadline = Instant::now();
+        for _ in 0..cycles {
+            for phase in 0..SPINNER_PHASES {
+                deadline += step;
+                display.apply(spinner_frame(phase));
+                display.refresh_until(deadline).await;
+            }
+        }
+    }
+}
+
+/// Restores the brightness and frame buffer saved when an overlay such as
+/// `alert` started, whenever the overlay ends
+struct Overlay<'a, P, const ROWS: usize, const COLS: usize>
+where
+    P: OutputPin + 'static,
+{
+    display: &'a mut LedMatrix<P, ROWS, COLS>,
+    brightness: Brightness,
+    frame: Frame<COLS, ROWS>,
+}
+
+impl<P, const ROWS: usize, const COLS: usize> Deref for Overlay<'_, P, ROWS, COLS>
+where
+    P: OutputPin + 'static,
+{
+    type Target = LedMatrix<P, ROWS, COLS>;
+
+    fn deref(&self) -> &Self::Target {
+        self.display
+    }
+}
+
+impl<P, const ROWS: usize, const COLS: usize> DerefMut for Overlay<'_, P, ROWS, COLS>
+where
+    P: OutputPin + 'static,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.display
+    }
+}
+
+impl<P, const ROWS: usize, const COLS: usize> Drop for Overlay<'_, P, ROWS, COLS>
+where
+    P: OutputPin + 'static,
+{
+    fn drop(&mut self) {
+        self.display.set_brightness(self.brightness);
+        self.display.reset_to(self.frame);
+    }
+}
+
+/// Puts the target frame of a transition such as `wipe` in the frame
+/// buffer and switches the LEDs off whenever the transition ends, so a
+/// cancelled transition neither leaves a row lit nor stops half way
+struct Transition<'a, P, const ROWS: usize, const COLS: usize>
+where
+    P: OutputPin + 'static,
+{
+    display: &'a mut LedMatrix<P, ROWS, COLS>,
+    to: Frame<COLS, ROWS>,
+}
+
+impl<P, const ROWS: usize, const COLS: usize> Deref for Transition<'_, P, ROWS, COLS>
+where
+    P: OutputPin + 'static,
+{
+    type Target = LedMatrix<P, ROWS, COLS>;
+
+    fn deref(&self) -> &Self::Target {
+        self.display
+    }
+}
+
+impl<P, const ROWS: usize, const COLS: usize> DerefMut for Transition<'_, P, ROWS, COLS>
+where
+    P: OutputPin + 'static,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.display
+    }
+}
+
+impl<P, const ROWS: usize, const COLS: usize> Drop for Transition<'_, P, ROWS, COLS>
+where
+    P: OutputPin + 'static,
+{
+    fn drop(&mut self) {
+        self.display.apply(self.to);
+        self.display.blank_pins();
+    }
+}
+
+/// **Clear-On-Drop Display Guard**
+///
+/// Returned by `LedMatrix::clear_on_drop`. Dereferences to the display and
+/// calls `clear` when dropped, whether the owning future ran to completion
+/// or was cancelled part way through, so no LED is left stuck on.
+pub struct ClearOnDrop<'a, P, const ROWS: usize, const COLS: usize>
+where
+    P: OutputPin + 'static,
+{
+    display: &'a mut LedMatrix<P, ROWS, COLS>,
+}
+
+impl<P, const ROWS: usize, const COLS: usize> Deref for ClearOnDrop<'_, P, ROWS, COLS>
+where
+    P: OutputPin + 'static,
+{
+    type Target = LedMatrix<P, ROWS, COLS>;
+
+    fn deref(&self) -> &Self::Target {
+        self.display
+    }
+}
+
+impl<P, const ROWS: usize, const COLS: usize> DerefMut for ClearOnDrop<'_, P, ROWS, COLS>
+where
+    P: OutputPin + 'static,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.display
+    }
+}
+
+impl<P, const ROWS: usize, const COLS: usize> Drop for ClearOnDrop<'_, P, ROWS, COLS>
+where
+    P: OutputPin + 'static,
+{
+    fn drop(&mut self) {
+        self.display.clear();
+    }
+}
+
+/// Guard held while `read_light` has the column pins switched to inputs.
+/// Dropping it, including when the reading is cancelled, turns the columns
+/// back into outputs and idles every pin so the display keeps working.
+struct LightSensing<'a, const ROWS: usize, const COLS: usize> {
+    display: &'a mut LedMatrix<Flex<'static>, ROWS, COLS>,
+}
+
+impl<const ROWS: usize, const COLS: usize> Drop for LightSensing<'_, ROWS, COLS> {
+    fn drop(&mut self) {
+        for col in self.display.pin_cols.iter_mut() {
+            col.set_as_output(OutputDrive::Standard);
+        }
+        self.display.blank_pins();
+    }
+}
+
+impl<const ROWS: usize, const COLS: usize> LedMatrix<Flex<'static>, ROWS, COLS> {
+    /// **Read Ambient Light**
+    ///
+    /// Uses the LEDs themselves as light sensors. Every LED is reverse
+    /// biased by driving all rows and columns to their idle levels (rows
+    /// low, columns high on the micro:bit) so its junction charges up, then
+    /// the column pins are switched to floating inputs and timed until
+    /// light falling on the LEDs has discharged them to the column active
+    /// level: the brighter the room, the faster they flip. The levels
+    /// follow `with_polarity`, so inverted boards are reverse biased too.
+    ///
+    /// The display goes dark for the measurement, up to
+    /// `LIGHT_MAX_DISCHARGE` in a dark room. The frame buffer is left alone,
+    /// so the next refresh shows the same frame as before. Cancelling the
+    /// reading, for example in a `select`, still restores the column pins.
+    ///
+    /// # Returns
+    /// Light level from 0 (dark) to `LIGHT_LEVEL_MAX` (bright); the scale
+    /// is relative, not calibrated in lux
+    ///
+    /// # Example
+    /// ```ignore
+    /// let light = display.read_light().await;
+    /// display.set_brightness(Brightness::new(1 + (light * 9 / 255) as u8));
+    /// ```
+    #[allow(dead_code)]
+    pub async fn read_light(&mut self) -> u16 {
+        self.blank_pins();
+        block_for(LIGHT_CHARGE_TIME);
+        let discharged_high = self.col_active == PinState::High;
+
+        // Restores the columns even if this future is dropped mid-reading
+        let sensing = LightSensing { display: self };
+        for col in sensing.display.pin_cols.iter_mut() {
+            col.set_as_input(Pull::None);
+        }
+        let start = Instant::now();
+        let mut discharged = [None; COLS];
+        while discharged.iter().any(Option::is_none) && start.elapsed() < LIGHT_MAX_DISCHARGE {
+            for (time, col) in discharged.iter_mut().zip(sensing.display.pin_cols.iter()) {
+                if time.is_none() && col.is_high() == discharged_high {
+                    *time = Some(start.elapsed());
+                }
+            }
+            yield_now().await;
+        }
+        drop(sensing);
+
+        let max = LIGHT_MAX_DISCHARGE.as_micros();
+        let total: u64 = discharged
+            .iter()
+            .map(|time| time.map_or(max, |t| t.as_micros().min(max)))
+            .sum();
+        let average = total / COLS.max(1) as u64;
+        ((max - average) * LIGHT_LEVEL_MAX as u64 / max) as u16
+    }
+
+    /// **Automatic Brightness**
+    ///
+    /// Reads the ambient light with `read_light` and sets the brightness to
+    /// match, dim in the dark and bright in daylight, never going fully
+    /// off. Call it every second or so from the display loop; the level
+    /// only changes once the light has clearly moved (see
+    /// `auto_brightness_level`).
+    ///
+    /// # Example
+    /// ```ignore
+    /// loop {
+    ///     display.auto_brightness().await;
+    ///     display.display(frame, Duration::from_secs(1)).await;
+    /// }
+    /// ```
+    #[allow(dead_code)]
+    pub async fn auto_brightness(&mut self) {
+        let light = self.read_light().await;
+        let brightness = auto_brightness_level(self.brightness, light);
+        self.set_brightness(brightness);
+    }
+}
+
+/// **Light To Brightness**
+///
+/// Maps a `read_light` value to a brightness level in proportion, with
+/// level 1 as the floor so the display stays readable in the dark. The
+/// current level is kept as long as it is still a valid mapping for any
+/// light within `AUTO_BRIGHTNESS_DEADBAND` of `light`, so a level only
+/// changes once the light is clearly past the boundary between two levels.
+///
+/// # Arguments
+/// * `current` - Brightness in use now
+/// * `light` - Ambient light, 0 to `LIGHT_LEVEL_MAX`
+///
+/// # Returns
+/// The brightness to use from now on
+///
+/// # Example
+/// ```ignore
+/// let level = auto_brightness_level(Brightness::new(5), 145);
+/// assert_eq!(level.level(), 5); // 145 is only just past the 5/6 boundary
+/// ```
+pub fn auto_brightness_level(current: Brightness, light: u16) -> Brightness {
+    let level_for = |light: u16| {
+        let ratio = light.min(LIGHT_LEVEL_MAX) as f32 / LIGHT_LEVEL_MAX as f32;
+        Brightness::from_ratio(ratio).level().max(1)
+    };
+    let low = level_for(light.saturating_sub(AUTO_BRIGHTNESS_DEADBAND));
+    let high = level_for(light.saturating_add(AUTO_BRIGHTNESS_DEADBAND));
+    if (low..=high).contains(&current.level()) {
+        current
+    } else {
+        Brightness::new(level_for(light))
+    }
+}
+
+/// Default scroll duration: half a second per character
+fn default_scroll_duration(text: &str) -> Duration {
+    Duration::from_secs((text.len() / 2) as u64)
+}
+
+/// Number of animation steps needed to show `frames` frames with `effect`
+fn animation_steps<const XSIZE: usize, const YSIZE: usize>(frames: usize, effect: AnimationEffect) -> usize {
+    match effect {
+        AnimationEffect::Slide | AnimationEffect::SlideRight => frames * XSIZE,
+        AnimationEffect::SlideUp => frames * YSIZE,
+        AnimationEffect::None => frames,
+    }
+}
+
+/// Whether `text` rendered in the built-in font is at most `XSIZE` columns wide
+fn text_fits<const XSIZE: usize>(text: &str) -> bool {
+    text.len() * GLYPH_WIDTH <= XSIZE
+}
+
+/// Shift a frame sideways so its lit columns sit in the middle of the
+/// display, leaning left when the spare columns cannot be split evenly
+fn center_horizontally<const XSIZE: usize, const YSIZE: usize>(mut frame: Frame<XSIZE, YSIZE>) -> Frame<XSIZE, YSIZE> {
+    let lit = |x: usize| (0..YSIZE).any(|y| frame.is_set(x, y));
+    let (Some(first), Some(last)) = ((0..XSIZE).find(|&x| lit(x)), (0..XSIZE).rfind(|&x| lit(x))) else {
+        return frame;
+    };
+    let target = (XSIZE - (last - first + 1)) / 2;
+    if first > target {
+        frame.shift_left(first - target);
+    } else {
+        frame.shift_right(target - first);
+    }
+    frame
+}
+
+/// Number of one-column steps `scroll_loop` takes for `times` passes over
+/// `len` characters, or `None` when it repeats forever. Every pass but the
+/// last also scrolls the blank separator column.
+fn scroll_loop_steps(len: usize, times: usize) -> Option<usize> {
+    let text_cols = len * GLYPH_WIDTH;
+    times
+        .checked_sub(1)
+        .map(|repeats| repeats * (text_cols + 1) + text_cols)
+}
+
+/// **Proportional Text Width**
+///
+/// Number of columns `scroll_proportional` lays `text` out in: every glyph
+/// trimmed to its lit columns (blank glyphs count as
+/// `PROPORTIONAL_SPACE_WIDTH`), plus one column between neighbors.
+///
+/// # Arguments
+/// * `text` - Text to measure
+///
+/// # Returns
+/// The total width in columns, 0 for empty text
+///
+/// # Example
+/// ```ignore
+/// assert!(proportional_width("ii") < "ii".len() * 5);
+/// ```
+pub fn proportional_width(text: &str) -> usize {
+    let glyphs: usize = text.bytes().map(|c| proportional_extent(c).1).sum();
+    glyphs + text.len().saturating_sub(1)
+}
+
+/// First lit column and width of glyph `c` in proportional layout
+fn proportional_extent(c: u8) -> (usize, usize) {
+    let glyph: Frame<GLYPH_WIDTH, GLYPH_WIDTH> = c.into();
+    let lit = |x: usize| (0..GLYPH_WIDTH).any(|y| glyph.is_set(x, y));
+    match ((0..GLYPH_WIDTH).find(|&x| lit(x)), (0..GLYPH_WIDTH).rfind(|&x| lit(x))) {
+        (Some(first), Some(last)) => (first, last - first + 1),
+        _ => (0, PROPORTIONAL_SPACE_WIDTH),
+    }
+}
+
+/// Frame showing proportional `text` from column `offset` onwards
+fn proportional_window<const XSIZE: usize, const YSIZE: usize>(text: &[u8], offset: usize) -> Frame<XSIZE, YSIZE> {
+    let mut frame = Frame::empty();
+    let mut start = 0;
+    for &c in text {
+        let (first, width) = proportional_extent(c);
+        if start >= offset + XSIZE {
+            break;
+        }
+        if start + width > offset {
+            let glyph: Frame<XSIZE, YSIZE> = c.into();
+            for column in start.max(offset)..(start + width).min(offset + XSIZE) {
+                for y in 0..YSIZE {
+                    if glyph.is_set(first + column - start, y) {
+                        frame.set(column - offset, y);
+                    }
+                }
+            }
+        }
+        start += width + 1;
+    }
+    frame
+}
+
+/// **Spaced Text Width**
+///
+/// Number of columns `scroll_spaced` lays `text` out in: one full
+/// `GLYPH_WIDTH` cell per character plus `spacing` columns between
+/// neighbors.
+///
+/// # Arguments
+/// * `text` - Text to measure
+/// * `spacing` - Blank columns between glyphs
+///
+/// # Returns
+/// The total width in columns, 0 for empty text
+///
+/// # Example
+/// ```ignore
+/// assert_eq!(spaced_width("ll", 0), 10);
+/// assert_eq!(spaced_width("ll", 1), 11);
+/// ```
+#[allow(dead_code)]
+pub fn spaced_width(text: &str, spacing: usize) -> usize {
+    let gaps = text.len().saturating_sub(1);
+    text.len() * GLYPH_WIDTH + gaps * spacing
+}
+
+/// Frame showing `text`, with `spacing` blank columns between glyphs, from
+/// column `offset` onwards
+fn spaced_window<const XSIZE: usize, const YSIZE: usize>(
+    text: &[u8],
+    offset: usize,
+    spacing: usize,
+) -> Frame<XSIZE, YSIZE> {
+    let pitch = GLYPH_WIDTH + spacing;
+    let mut frame = Frame::empty();
+    for x in 0..XSIZE {
+        let column = offset + x;
+        let (index, within) = (column / pitch, column % pitch);
+        if index >= text.len() || within >= GLYPH_WIDTH {
+            continue;
+        }
+        let glyph: Frame<XSIZE, YSIZE> = text[index].into();
+        for y in 0..YSIZE {
+            if glyph.is_set(within, y) {
+                frame.set(x, y);
+            }
+        }
+    }
+    frame
+}
+
+/// Visible window of a repeating text banner, starting `offset` columns
+/// into the repeating pattern of the glyphs followed by one blank column
+fn banner_window<const XSIZE: usize, const YSIZE: usize>(text: &[u8], offset: usize) -> Frame<XSIZE, YSIZE> {
+    let text_cols = text.len() * GLYPH_WIDTH;
+    let mut frame = Frame::empty();
+    for x in 0..XSIZE {
+        let column = (offset + x) % (text_cols + 1);
+        if column == text_cols {
+            continue;
+        }
+        let glyph: Frame<XSIZE, YSIZE> = text[column / GLYPH_WIDTH].into();
+        for y in 0..YSIZE {
+            if glyph.is_set(column % GLYPH_WIDTH, y) {
+                frame.set(x, y);
+            }
+        }
+    }
+    frame
+}
+
+/// Stretch `duration` so that each of `steps` steps lasts at least one
+/// refresh interval, logging a warning when it had to be adjusted
+fn feasible_duration(steps: usize, duration: Duration) -> Duration {
+    let minimum = REFRESH_INTERVAL * steps as u32;
+    if duration < minimum {
+        defmt::warn!(
+            "animation too fast ({} us), slowing to {} us",
+            duration.as_micros(),
+            minimum.as_micros()
+        );
+        minimum
+    } else {
+        duration
+    }
+}
+
+/// **Column Wait for Scroll Rate**
+///
+/// Converts a scroll rate into the time each column stays on screen.
+///
+/// # Arguments
+/// * `pixels_per_sec` - Scroll rate in columns per second (0 is treated as 1)
+///
+/// # Returns
+/// The per-column wait, or `AnimationError::TooFast` if it is shorter than
+/// the display refresh interval
+fn column_wait(pixels_per_sec: u32) -> Result<Duration, AnimationError> {
+    let wait = Duration::from_hz(pixels_per_sec.max(1) as u64);
+    if wait < REFRESH_INTERVAL {
+        return Err(AnimationError::TooFast);
+    }
+    Ok(wait)
+}
+
+/// **Wipe Step Frame**
+///
+/// Combines two frames for step `revealed` of a wipe: the first `revealed`
+/// columns (or rows) in the wipe direction come from `to` and the rest
+/// from `from`.
+fn wipe_frame<const XSIZE: usize, const YSIZE: usize>(
+    from: &Frame<XSIZE, YSIZE>,
+    to: &Frame<XSIZE, YSIZE>,
+    direction: WipeDir,
+    revealed: usize,
+) -> Frame<XSIZE, YSIZE> {
+    let mut frame = Frame::empty();
+    for y in 0..YSIZE {
+        for x in 0..XSIZE {
+            let uncovered = match direction {
+                WipeDir::LeftToRight => x < revealed,
+                WipeDir::RightToLeft => x + revealed >= XSIZE,
+                WipeDir::TopToBottom => y < revealed,
+                WipeDir::BottomToTop => y + revealed >= YSIZE,
+            };
+            let source = if uncovered { to } else { from };
+            if source.is_set(x, y) {
+                frame.set(x, y);
+            }
+        }
+    }
+    frame
+}
+
+/// **Dither Sub-Frame**
+///
+/// Thresholds a grayscale image into sub-frame `step` of a temporal
+/// dithering cycle. Each pixel walks through all 16 ranks of the 4x4 Bayer
+/// matrix once per `DITHER_STEPS` sub-frames, starting from its own cell,
+/// and is lit whenever its level exceeds the current rank's threshold, so
+/// 0 is always off, 255 always on and 128 on for exactly half the cycle.
+///
+/// # Arguments
+/// * `levels` - Pixel levels, `levels[y][x]`, from 0 (off) to 255 (full)
+/// * `step` - Sub-frame index (taken modulo `DITHER_STEPS`)
+///
+/// # Returns
+/// The binary frame to show during sub-frame `step`
+///
+/// # Example
+/// ```ignore
+/// let lit = (0..DITHER_STEPS).filter(|&step| dither_frame(&[[128; 5]; 5], step).is_set(0, 0)).count();
+/// assert_eq!(lit, 8);
+/// ```
+#[allow(dead_code)]
+pub fn dither_frame<const ROWS: usize, const COLS: usize>(
+    levels: &[[u8; COLS]; ROWS],
+    step: usize,
+) -> Frame<COLS, ROWS> {
+    let step = step % DITHER_STEPS;
+    let mut frame = Frame::empty();
+    for (y, row) in levels.iter().enumerate() {
+        for (x, &level) in row.iter().enumerate() {
+            let rank = BAYER_4X4[(y + step / 4) % 4][(x + step) % 4];
+            // Thresholds sit mid-way through each sixteenth: 8, 24, ..., 248
+            if level > rank * 16 + 8 {
+                frame.set(x, y);
+            }
+        }
+    }
+    frame
+}
+
+/// **Wipe Direction**
+///
+/// Direction a `wipe` transition travels across the display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[allow(dead_code)]
+pub enum WipeDir {
+    /// Uncover columns from the left edge
+    LeftToRight,
+    /// Uncover columns from the right edge
+    RightToLeft,
+    /// Uncover rows from the top edge
+    TopToBottom,
+    /// Uncover rows from the bottom edge
+    BottomToTop,
+}
+
+/// **Pixel Shuffle**
+///
+/// Yields every index below `count` exactly once in a scrambled order.
+/// Driven by an 8-bit maximal-length Galois LFSR, which steps through all
+/// 255 non-zero states before repeating; states outside the range are
+/// skipped, so `count` may be at most 255.
+struct PixelShuffle {
+    state: u8,
+    remaining: usize,
+    count: usize,
+}
+
+impl PixelShuffle {
+    /// Feedback taps for x^8 + x^6 + x^5 + x^4 + 1
+    const TAPS: u8 = 0xB8;
+
+    fn new(count: usize) -> Self {
+        debug_assert!(count <= u8::MAX as usize);
+        Self {
+            state: 1,
+            remaining: count,
+            count,
+        }
+    }
+}
+
+impl Iterator for PixelShuffle {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.remaining > 0 {
+            let lsb = self.state & 1;
+            self.state >>= 1;
+            if lsb != 0 {
+                self.state ^= Self::TAPS;
+            }
+            let index = self.state as usize - 1;
+            if index < self.count {
+                self.remaining -= 1;
+                return Some(index);
+            }
+        }
+        None
     }
 }
 
@@ -425,11 +2706,20 @@ pub enum AnimationEffect {
     None,
     /// Sliding effect
     Slide,
+    /// Vertical sliding effect, each frame rising in from the bottom
+    SlideUp,
+    /// Sliding effect moving rightward, each frame entering from the left
+    SlideRight,
 }
 
+/// **Animation Source Data**
+///
+/// The sequence an `Animation` steps through.
 #[allow(dead_code)]
-enum AnimationData<'a, const XSIZE: usize, const YSIZE: usize> {
+pub enum AnimationData<'a, const XSIZE: usize, const YSIZE: usize> {
+    /// Ready-made frames, shown in order
     Frames(&'a [Frame<XSIZE, YSIZE>]),
+    /// Characters, each rendered with the built-in font
     Bytes(&'a [u8]),
 }
 
@@ -449,75 +2739,193 @@ impl<'a, const XSIZE: usize, const YSIZE: usize> AnimationData<'a, XSIZE, YSIZE>
     }
 }
 
-struct Animation<'a, const XSIZE: usize, const YSIZE: usize> {
+/// **Animation State Machine**
+///
+/// Works out which frame of an animation is due at a given instant,
+/// without touching any display. `LedMatrix::animate` and friends drive one
+/// internally; build one yourself to advance an animation from your own
+/// loop, for example to mix it with game logic or show it on another
+/// display.
+///
+/// # Example
+/// ```ignore
+/// let mut animation = Animation::new(AnimationData::Bytes(b"Hi"), AnimationEffect::Slide, Duration::from_secs(1))?;
+/// loop {
+///     match animation.step(Instant::now()) {
+///         AnimationState::Apply(frame) => display.apply(frame),
+///         AnimationState::Wait => {}
+///         AnimationState::Done => break,
+///     }
+///     display.render();
+///     Timer::after(Duration::from_micros(500)).await;
+/// }
+/// ```
+pub struct Animation<'a, const XSIZE: usize, const YSIZE: usize> {
     frames: AnimationData<'a, XSIZE, YSIZE>,
     sequence: usize,
     frame_index: usize,
     index: usize,
     length: usize,
     effect: AnimationEffect,
+    playback: Playback,
     wait: Duration,
     next: Instant,
 }
 
+/// **Animation Step Result**
+///
+/// What the caller should do after `Animation::step`.
 #[derive(PartialEq, Debug)]
-enum AnimationState<const XSIZE: usize, const YSIZE: usize> {
+pub enum AnimationState<const XSIZE: usize, const YSIZE: usize> {
+    /// The current frame is not over yet; keep showing it
     Wait,
+    /// A new frame is due; render the carried frame
     Apply(Frame<XSIZE, YSIZE>),
+    /// Every frame has been shown
     Done,
 }
 
+/// **Playback Order**
+///
+/// Order in which an `Animation` walks through its frames. The transition
+/// effect itself is unchanged: a reversed `Slide` still moves to the left,
+/// it just slides the frames in from last to first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Playback {
+    /// First frame to last
+    #[default]
+    Forward,
+    /// Last frame to first
+    Reverse,
+}
+
+#[allow(dead_code)]
 impl<'a, const XSIZE: usize, const YSIZE: usize> Animation<'a, XSIZE, YSIZE> {
+    /// **Create Animation**
+    ///
+    /// Splits `duration` evenly over every step of the animation. The first
+    /// frame is due immediately.
+    ///
+    /// # Arguments
+    /// * `frames` - Frames or characters to animate
+    /// * `effect` - Transition between consecutive frames
+    /// * `duration` - Total time for the whole animation
+    ///
+    /// # Returns
+    /// `Err(AnimationError::Empty)` if `frames` is empty, or
+    /// `Err(AnimationError::TooFast)` if a step would be shown for less than
+    /// one display refresh interval (500 µs)
     pub fn new(
         frames: AnimationData<'a, XSIZE, YSIZE>,
         effect: AnimationEffect,
         duration: Duration,
     ) -> Result<Self, AnimationError> {
-        assert!(frames.len() > 0);
-        let length = match effect {
-            AnimationEffect::Slide => frames.len() * XSIZE,
-            AnimationEffect::None => frames.len(),
-        };
+        if frames.len() == 0 {
+            return Err(AnimationError::Empty);
+        }
+        let length = animation_steps::<XSIZE, YSIZE>(frames.len(), effect);
 
-        if let Some(wait) = duration.checked_div(length as u32) {
-            Ok(Self {
+        match duration.checked_div(length as u32) {
+            Some(wait) if wait >= REFRESH_INTERVAL => Ok(Self {
                 frames,
                 frame_index: 0,
                 sequence: 0,
                 index: 0,
                 length,
                 effect,
+                playback: Playback::Forward,
                 wait,
                 next: Instant::now(),
-            })
-        } else {
-            Err(AnimationError::TooFast)
+            }),
+            _ => Err(AnimationError::TooFast),
+        }
+    }
+
+    /// **Set Playback Order**
+    ///
+    /// Chooses whether the frames are played first to last (the default)
+    /// or last to first. Timing is unaffected: the whole reversed pass
+    /// still takes the `duration` given to `new`.
+    ///
+    /// # Arguments
+    /// * `playback` - Order to walk through the frames
+    ///
+    /// # Returns
+    /// The animation, ready to `step`
+    ///
+    /// # Example
+    /// ```ignore
+    /// let animation = Animation::new(AnimationData::Frames(&frames), AnimationEffect::Slide, duration)?
+    ///     .with_playback(Playback::Reverse);
+    /// ```
+    pub fn with_playback(mut self, playback: Playback) -> Self {
+        self.playback = playback;
+        self
+    }
+
+    /// Frame at position `idx` of the playback order
+    fn frame_at(&self, idx: usize) -> Frame<XSIZE, YSIZE> {
+        match self.playback {
+            Playback::Forward => self.frames.frame(idx),
+            Playback::Reverse => self.frames.frame(self.frames.len() - 1 - idx),
         }
     }
+
+    /// Compose the frame for the current step from the two frames it blends
     fn current(&self) -> Frame<XSIZE, YSIZE> {
-        let mut current = self.frames.frame(self.frame_index);
+        let mut current = self.frame_at(self.frame_index);
 
         let mut next = if self.frame_index < self.frames.len() - 1 {
-            self.frames.frame(self.frame_index + 1)
+            self.frame_at(self.frame_index + 1)
         } else {
             Frame::empty()
         };
 
-        current.shift_left(self.sequence);
-        next.shift_right(XSIZE - self.sequence);
+        match self.effect {
+            AnimationEffect::SlideUp => {
+                current.shift_up(self.sequence);
+                next.shift_down(YSIZE - self.sequence);
+            }
+            AnimationEffect::SlideRight => {
+                current.shift_right(self.sequence);
+                next.shift_left(XSIZE - self.sequence);
+            }
+            _ => {
+                current.shift_left(self.sequence);
+                next.shift_right(XSIZE - self.sequence);
+            }
+        }
 
         current.or(&next);
         current
     }
 
-    fn next(&mut self, now: Instant) -> AnimationState<XSIZE, YSIZE> {
+    /// **Advance Animation**
+    ///
+    /// Checks whether the next step is due at `now` and, if so, moves past
+    /// it. Call it at least once per step duration; a late call still
+    /// yields every step in order, so a slow loop delays the animation
+    /// instead of skipping frames.
+    ///
+    /// # Arguments
+    /// * `now` - Current time, usually `Instant::now()`
+    ///
+    /// # Returns
+    /// `Apply` with the frame to show when a step is due, `Wait` while the
+    /// current step lasts and `Done` after the last step
+    pub fn step(&mut self, now: Instant) -> AnimationState<XSIZE, YSIZE> {
         if self.next <= now {
             if self.index < self.length {
                 let current = self.current();
-                if self.sequence >= XSIZE - 1 {
+                let span = match self.effect {
+                    AnimationEffect::SlideUp => YSIZE,
+                    _ => XSIZE,
+                };
+                if self.sequence >= span - 1 {
                     self.sequence = match self.effect {
                         AnimationEffect::None => XSIZE,
-                        AnimationEffect::Slide => 0,
+                        AnimationEffect::Slide | AnimationEffect::SlideUp | AnimationEffect::SlideRight => 0,
                     };
                     self.frame_index += 1;
                 } else {
@@ -542,4 +2950,686 @@ impl<'a, const XSIZE: usize, const YSIZE: usize> Animation<'a, XSIZE, YSIZE> {
 pub enum AnimationError {
     /// Animation scroll is too fast to keep up with the refresh rate
     TooFast,
+    /// There are no frames or characters to animate
+    Empty,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::{border, frame_5x5, ARROW_LEFT, CHECK_MARK, CROSS_MARK};
+    use crate::test_support::{
+        advance, lit_pixels, mock_clock, mock_matrix, null_matrix, pin_levels, poll_once, MOCK_ROWS,
+    };
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::Poll;
+
+    /// Runs `future` against a `mock_matrix` one refresh at a time and
+    /// returns the frames it showed, merging each run of identical frames.
+    /// A frame is the LEDs lit over a whole scan of `MOCK_ROWS` refreshes,
+    /// so changes are only seen on scan boundaries.
+    fn frames_shown<const COLS: usize>(future: impl Future) -> Vec<Frame<COLS, MOCK_ROWS>> {
+        frames_shown_within(future, 10_000).expect("future did not finish")
+    }
+
+    /// Like `frames_shown`, but gives up after `scans` scans and returns
+    /// the frames seen so far as an `Err`, for futures that never finish
+    fn frames_shown_within<const COLS: usize>(
+        future: impl Future,
+        scans: usize,
+    ) -> Result<Vec<Frame<COLS, MOCK_ROWS>>, Vec<Frame<COLS, MOCK_ROWS>>> {
+        let mut future = pin!(future);
+        let mut shown = Vec::new();
+        for _ in 0..scans {
+            let mut scan = Frame::empty();
+            for _ in 0..MOCK_ROWS {
+                if poll_once(future.as_mut()).is_ready() {
+                    return Ok(shown);
+                }
+                scan.or(&lit_pixels());
+                advance(REFRESH_INTERVAL);
+            }
+            if shown.last() != Some(&scan) {
+                shown.push(scan);
+            }
+        }
+        Err(shown)
+    }
+
+    /// The frames a `COLS`-wide scroll over `glyphs` should show, merged
+    /// like `frames_shown`: step k shows columns k to k + COLS - 1 of the
+    /// glyphs laid side by side, followed by blank columns
+    fn strip_windows<const COLS: usize>(glyphs: &[Frame<5, 5>]) -> Vec<Frame<COLS, 5>> {
+        let mut windows: Vec<Frame<COLS, 5>> = Vec::new();
+        for k in 0..glyphs.len() * 5 {
+            let mut window = Frame::empty();
+            for (x, column) in (k..k + COLS).enumerate() {
+                for y in 0..5 {
+                    if glyphs.get(column / 5).is_some_and(|glyph| glyph.is_set(column % 5, y)) {
+                        window.set(x, y);
+                    }
+                }
+            }
+            if windows.last() != Some(&window) {
+                windows.push(window);
+            }
+        }
+        windows
+    }
+
+    #[test]
+    fn scroll_rate_sets_the_column_wait() {
+        let _clock = mock_clock();
+        let wait = column_wait(10).unwrap();
+        assert_eq!(wait, Duration::from_millis(100));
+        // "Hello" is 25 columns, so the scroll takes 2.5 s
+        let columns = spaced_width("Hello", 0) as u32;
+        assert_eq!(columns, 25);
+        let animation =
+            Animation::<5, 5>::new(AnimationData::Bytes(b"Hello"), AnimationEffect::Slide, wait * columns).unwrap();
+        assert_eq!(animation.wait, wait);
+        // A rate of 0 is treated as 1 column per second
+        assert_eq!(column_wait(0).unwrap(), Duration::from_secs(1));
+        // 2000 columns per second is one column per refresh, the fastest
+        assert!(column_wait(2000).is_ok());
+        assert!(matches!(column_wait(2500), Err(AnimationError::TooFast)));
+    }
+
+    #[test]
+    fn try_variants_report_too_fast() {
+        let _clock = mock_clock();
+        let mut display = null_matrix();
+        let too_short = Duration::from_micros(1);
+        {
+            let mut scroll = pin!(display.try_scroll_with_speed("Hello", too_short));
+            assert!(matches!(
+                poll_once(scroll.as_mut()),
+                Poll::Ready(Err(AnimationError::TooFast))
+            ));
+        }
+        {
+            let mut animate = pin!(display.try_animate(b"Hi", AnimationEffect::Slide, too_short));
+            assert!(matches!(
+                poll_once(animate.as_mut()),
+                Poll::Ready(Err(AnimationError::TooFast))
+            ));
+        }
+        {
+            let frames = [Frame::empty(); 3];
+            let mut animate = pin!(display.try_animate_frames(&frames, AnimationEffect::None, too_short));
+            assert!(matches!(
+                poll_once(animate.as_mut()),
+                Poll::Ready(Err(AnimationError::TooFast))
+            ));
+        }
+        assert!(display.is_blank());
+    }
+
+    #[test]
+    fn empty_animations_are_rejected_or_skipped() {
+        let _clock = mock_clock();
+        assert!(matches!(
+            Animation::<5, 5>::new(
+                AnimationData::Bytes(b""),
+                AnimationEffect::Slide,
+                Duration::from_secs(1)
+            ),
+            Err(AnimationError::Empty)
+        ));
+        let mut display = null_matrix();
+        let mut animate = pin!(display.try_animate(b"", AnimationEffect::Slide, Duration::from_micros(1)));
+        assert!(matches!(poll_once(animate.as_mut()), Poll::Ready(Ok(()))));
+    }
+
+    #[test]
+    fn dissolve_order_is_a_permutation() {
+        for count in [1, 25, 50, 255] {
+            let mut order: Vec<usize> = PixelShuffle::new(count).collect();
+            order.sort_unstable();
+            assert_eq!(order, (0..count).collect::<Vec<_>>());
+        }
+        // Filtered down to the set pixels, as `dissolve` does
+        let revealed: Vec<usize> = PixelShuffle::new(25)
+            .filter(|&i| CHECK_MARK.is_set(i % 5, i / 5))
+            .collect();
+        let mut sorted = revealed.clone();
+        sorted.sort_unstable();
+        let set: Vec<usize> = (0..25).filter(|&i| CHECK_MARK.is_set(i % 5, i / 5)).collect();
+        assert_eq!(sorted, set);
+        // Scrambled rather than in reading order
+        assert_ne!(revealed, set);
+    }
+
+    #[test]
+    fn left_to_right_wipe_reveals_columns_in_order() {
+        let mut from = Frame::<5, 5>::empty();
+        from.fill(true);
+        for revealed in 0..=5 {
+            let frame = wipe_frame(&from, &CHECK_MARK, WipeDir::LeftToRight, revealed);
+            for y in 0..5 {
+                for x in 0..5 {
+                    let want = if x < revealed { CHECK_MARK.is_set(x, y) } else { true };
+                    assert_eq!(frame.is_set(x, y), want, "step {revealed} ({x},{y})");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn instant_wipe_still_ends_on_the_target() {
+        let _clock = mock_clock();
+        let mut display = null_matrix();
+        display.apply(ARROW_LEFT);
+        {
+            let mut wipe = pin!(display.wipe(CHECK_MARK, WipeDir::LeftToRight, Duration::from_ticks(0)));
+            while poll_once(wipe.as_mut()).is_pending() {
+                advance(REFRESH_INTERVAL);
+            }
+        }
+        assert_eq!(display.frame_buffer, CHECK_MARK);
+    }
+
+    #[test]
+    fn alternate_shows_each_frame_once_per_cycle() {
+        let _clock = mock_clock();
+        let mut display = mock_matrix::<5>();
+        // 10 ms per cycle is two 5-refresh scans of each frame
+        let period = REFRESH_INTERVAL * 20;
+        let shown = frames_shown(display.alternate(CHECK_MARK, ARROW_LEFT, period, 2));
+        assert_eq!(shown, [CHECK_MARK, ARROW_LEFT, CHECK_MARK, ARROW_LEFT]);
+        assert!(display.is_blank());
+    }
+
+    #[test]
+    fn scroll_loop_three_times_takes_three_passes() {
+        let _clock = mock_clock();
+        // Two glyphs are 10 columns; every pass but the last adds a separator
+        assert_eq!(scroll_loop_steps(2, 3), Some(11 + 11 + 10));
+        assert_eq!(scroll_loop_steps(2, 1), Some(10));
+        assert_eq!(scroll_loop_steps(2, 0), None);
+        // After a pass and its separator the banner is back where it began
+        assert_eq!(banner_window::<5, 5>(b"HI", 11), banner_window::<5, 5>(b"HI", 0));
+
+        let mut display = mock_matrix::<5>();
+        // One column per 5-refresh scan
+        let speed = REFRESH_INTERVAL * 5 * 11;
+        let start = Instant::now();
+        frames_shown::<5>(display.scroll_loop("HI", speed, 3));
+        assert_eq!(Instant::now() - start, REFRESH_INTERVAL * 5 * 32);
+        assert!(display.is_blank());
+    }
+
+    #[test]
+    fn show_text_holds_one_char_and_scrolls_more() {
+        let _clock = mock_clock();
+        assert!(text_fits::<5>("A"));
+        assert!(!text_fits::<5>("AB"));
+        assert!(text_fits::<10>("AB"));
+
+        let mut display = mock_matrix::<5>();
+        let hold = REFRESH_INTERVAL * 10;
+        let held = frames_shown::<5>(display.show_text("I", hold));
+        assert_eq!(held, [center_horizontally(b'I'.into())]);
+        let scrolled = frames_shown::<5>(display.show_text("IA", hold));
+        assert!(scrolled.len() > 1);
+        assert!(display.is_blank());
+    }
+
+    #[test]
+    fn gamma_table_is_monotonic_within_the_refresh_budget() {
+        let budget = REFRESH_INTERVAL.as_micros();
+        assert_eq!(gamma_on_time(0), 0);
+        assert_eq!(gamma_on_time(10), budget);
+        assert_eq!(gamma_on_time(99), budget);
+        for pair in GAMMA_ON_TIME_US.windows(2) {
+            assert!(pair[0] < pair[1]);
+        }
+        // Each step is within a microsecond of the 2.2 gamma curve
+        for level in 0..=10 {
+            let exact = budget as f64 * (level as f64 / 10.0).powf(2.2);
+            assert!((exact - gamma_on_time(level) as f64).abs() <= 1.0, "level {level}");
+        }
+    }
+
+    #[test]
+    fn auto_brightness_maps_light_with_hysteresis() {
+        let level = |current, light| auto_brightness_level(Brightness::new(current), light).level();
+        // Proportional mapping with a floor of 1
+        assert_eq!(level(10, 0), 1);
+        assert_eq!(level(1, 255), 10);
+        assert_eq!(level(3, 128), 5);
+        // Near the 5/6 boundary the current level is kept
+        assert_eq!(level(5, 145), 5);
+        assert_eq!(level(6, 135), 6);
+        // Clearly past it the level changes
+        assert_eq!(level(5, 150), 6);
+        assert_eq!(level(6, 131), 5);
+        // Flicker around the boundary never toggles the level
+        for start in [5, 6] {
+            let mut current = Brightness::new(start);
+            for light in [138, 144, 137, 146, 139, 143] {
+                current = auto_brightness_level(current, light);
+                assert_eq!(current.level(), start);
+            }
+        }
+    }
+
+    #[test]
+    fn scroll_words_scrolls_each_word_in_order() {
+        let _clock = mock_clock();
+        let mut display = mock_matrix::<5>();
+        // One column per 5-refresh scan
+        let per_char = REFRESH_INTERVAL * 5 * 5;
+        let mut expected = Vec::new();
+        for word in ["Hi", "big", "A"] {
+            for frame in frames_shown::<5>(display.scroll_with_speed(word, per_char * word.len() as u32)) {
+                if expected.last() != Some(&frame) {
+                    expected.push(frame);
+                }
+            }
+        }
+        // Runs of whitespace are a single break
+        let shown = frames_shown::<5>(display.scroll_words("  Hi   big A ", per_char));
+        assert_eq!(shown, expected);
+    }
+
+    #[test]
+    fn rtl_scroll_is_the_ltr_scroll_reversed() {
+        let _clock = mock_clock();
+        let mut display = mock_matrix::<5>();
+        // One column per 5-refresh scan
+        let speed = REFRESH_INTERVAL * 5 * 15;
+        // Both scrolls pan over the strip "CBA", in opposite directions. The
+        // 11 windows that lie on the strip come in reverse order; after them
+        // each scroll slides the text off its own side.
+        let rtl = frames_shown::<5>(display.scroll_rtl("ABC", speed));
+        let mut ltr = frames_shown::<5>(display.scroll_with_speed("CBA", speed));
+        ltr.truncate(11);
+        ltr.reverse();
+        assert_eq!(rtl[..11], ltr);
+        assert_eq!(rtl[0], b'A'.into());
+        assert_eq!(rtl[10], b'C'.into());
+    }
+
+    #[test]
+    fn proportional_text_is_narrower_than_fixed() {
+        // Each "i" trims to one column, plus one spacing column between
+        assert_eq!(proportional_width("ii"), 3);
+        assert_eq!(spaced_width("ii", 0), 10);
+        // Blank glyphs keep a fixed gap
+        assert_eq!(proportional_width(" "), PROPORTIONAL_SPACE_WIDTH);
+        assert_eq!(proportional_width("i i"), 1 + 1 + PROPORTIONAL_SPACE_WIDTH + 1 + 1);
+        assert_eq!(proportional_width("W"), 5);
+        assert_eq!(proportional_width(""), 0);
+    }
+
+    #[test]
+    fn identical_frames_leave_the_display_clean() {
+        let _clock = mock_clock();
+        let mut display = null_matrix();
+        display.apply(CHECK_MARK);
+        assert!(display.is_dirty());
+        // A full pass over the rows cleans the display
+        for _ in 0..5 {
+            display.render();
+        }
+        assert!(!display.is_dirty());
+        display.apply(CHECK_MARK);
+        assert!(!display.is_dirty());
+        display.on(0, 3);
+        assert!(!display.is_dirty());
+        display.apply(CROSS_MARK);
+        assert!(display.is_dirty());
+    }
+
+    #[test]
+    fn late_renders_are_counted_and_paid_back() {
+        let _clock = mock_clock();
+        let mut display = mock_matrix::<5>();
+        display.apply(border());
+        // The row left lit by the last `render`, if any
+        let render = |display: &mut LedMatrix<_, 5, 5>| {
+            display.render();
+            let lit = lit_pixels::<5>();
+            (0..5).find(|&y| (0..5).any(|x| lit.is_set(x, y)))
+        };
+
+        for row in (0..5).chain(0..5) {
+            assert_eq!(render(&mut display), Some(row));
+            advance(REFRESH_INTERVAL);
+        }
+        assert_eq!(display.dropped_frames(), 0);
+
+        // Row 4 stays lit through a stall of 4 extra slots: 3 are dropped,
+        // and row 4 sits out its next 3 turns to pay back the extra time
+        advance(REFRESH_INTERVAL * 3);
+        let mut lit = Vec::new();
+        for _ in 0..15 {
+            lit.push(render(&mut display));
+            advance(REFRESH_INTERVAL);
+        }
+        assert_eq!(display.dropped_frames(), 3);
+        assert_eq!(lit.iter().filter(|&&row| row == Some(0)).count(), 3);
+        assert!(!lit.contains(&Some(4)));
+
+        // Idle time after `clear` is not lag
+        display.clear();
+        advance(Duration::from_millis(100));
+        render(&mut display);
+        assert_eq!(display.dropped_frames(), 3);
+
+        // Below full brightness a late render skips lighting its row
+        display.set_brightness(Brightness::new(5));
+        for _ in 0..4 {
+            advance(REFRESH_INTERVAL * 3);
+            assert_eq!(render(&mut display), None);
+        }
+        assert_eq!(display.dropped_frames(), 3 + 4 * 2);
+    }
+
+    #[test]
+    fn reversed_slide_plays_frames_last_to_first() {
+        let _clock = mock_clock();
+        let frames: [Frame<5, 5>; 4] = [b'A'.into(), b'B'.into(), b'C'.into(), b'D'.into()];
+        // Late enough for every step to be due, so `step` yields them in turn
+        let late = Instant::now() + Duration::from_secs(60);
+        let play = |playback| {
+            let mut animation = Animation::new(
+                AnimationData::Frames(&frames),
+                AnimationEffect::Slide,
+                Duration::from_secs(1),
+            )
+            .unwrap()
+            .with_playback(playback);
+            let mut steps = Vec::new();
+            while let AnimationState::Apply(frame) = animation.step(late) {
+                steps.push(frame);
+            }
+            steps
+        };
+        let reversed = play(Playback::Reverse);
+        assert_eq!(reversed.len(), 4 * 5);
+        // Every fifth step shows a whole frame: D, C, B, A
+        let whole: Vec<_> = reversed.iter().step_by(5).copied().collect();
+        assert_eq!(whole, [frames[3], frames[2], frames[1], frames[0]]);
+        let forward = play(Playback::Forward);
+        let whole: Vec<_> = forward.iter().step_by(5).copied().collect();
+        assert_eq!(whole, frames);
+    }
+
+    #[test]
+    fn polarity_sets_the_pin_levels_of_a_lit_pixel() {
+        let _clock = mock_clock();
+        // Rows are pins 0-4 and columns pins 5-9
+        let levels = |polarity: Option<(PinState, PinState)>| {
+            let mut display = mock_matrix::<5>();
+            if let Some((row_active, col_active)) = polarity {
+                display = display.with_polarity(row_active, col_active);
+            }
+            display.on(1, 0);
+            display.render();
+            pin_levels()[..10].to_vec()
+        };
+        // Row 0 on, column 1 lit, every other pin idle
+        let micro_bit = [true, false, false, false, false, true, false, true, true, true];
+        assert_eq!(levels(None), micro_bit);
+        assert_eq!(levels(Some((PinState::High, PinState::Low))), micro_bit);
+        assert_eq!(
+            levels(Some((PinState::Low, PinState::High))),
+            micro_bit.map(|high| !high)
+        );
+    }
+
+    #[test]
+    fn mid_gray_is_lit_for_half_the_dither_cycle() {
+        let mut image = [[0u8; 5]; 5];
+        image[0][0] = 128;
+        image[1][1] = 255;
+        let pattern: Vec<bool> = (0..DITHER_STEPS)
+            .map(|step| dither_frame(&image, step).is_set(0, 0))
+            .collect();
+        assert_eq!(
+            pattern,
+            [true, false, true, false, false, true, false, true, true, false, true, false, false, true, false, true]
+        );
+        // Full level is always on and zero always off
+        for step in 0..DITHER_STEPS {
+            let frame = dither_frame(&image, step);
+            assert!(frame.is_set(1, 1));
+            assert!(!frame.is_set(2, 2));
+        }
+    }
+
+    #[test]
+    fn scroll_and_hold_ends_on_the_last_screenful() {
+        let _clock = mock_clock();
+        let mut display = mock_matrix::<5>();
+        // One column per 5-refresh scan
+        let speed = REFRESH_INTERVAL * 5 * 10;
+        let hold = REFRESH_INTERVAL * 5 * 20;
+        let shown = frames_shown::<5>(display.scroll_and_hold("AB", speed, hold));
+        assert_eq!(shown.first(), Some(&b'A'.into()));
+        assert_eq!(shown.last(), Some(&b'B'.into()));
+        assert!(display.is_blank());
+    }
+
+    #[test]
+    fn scroll_frames_slides_custom_frames_like_text() {
+        let _clock = mock_clock();
+        let mut display = mock_matrix::<5>();
+        let mut bar = Frame::<5, 5>::empty();
+        (0..5).for_each(|y| bar.set(0, y));
+        let mut dot = Frame::<5, 5>::empty();
+        dot.set(2, 2);
+        // One column per 5-refresh scan
+        let speed = REFRESH_INTERVAL * 5 * 10;
+        let shown = frames_shown::<5>(display.scroll_frames(&[bar, dot], speed));
+        let expected = strip_windows::<5>(&[bar, dot]);
+        assert_eq!(shown, expected);
+
+        // Frames made from characters scroll exactly like the text
+        let text = frames_shown::<5>(display.scroll_with_speed("Hi", speed));
+        assert_eq!(
+            frames_shown::<5>(display.scroll_frames(&[b'H'.into(), b'i'.into()], speed)),
+            text
+        );
+    }
+
+    #[test]
+    fn spacing_widens_the_text() {
+        assert_eq!(spaced_width("ll", 0), 10);
+        assert_eq!(spaced_width("ll", 1), 11);
+        // Spacing only goes between characters
+        assert_eq!(spaced_width("l", 3), 5);
+        assert_eq!(spaced_width("", 3), 0);
+    }
+
+    #[test]
+    fn row_changes_never_light_a_ghost() {
+        use std::sync::Mutex;
+
+        /// Pin levels, and the pixels allowed to light up, checked after every write
+        static LEVELS: Mutex<[bool; 10]> = Mutex::new([false; 10]);
+        static ALLOWED: Mutex<Frame<5, 5>> = Mutex::new(Frame::empty());
+
+        /// A `MockPin` that fails the test the moment any LED outside
+        /// `ALLOWED`, or more than one row, is switched on
+        struct CheckedPin(usize);
+
+        impl CheckedPin {
+            fn set(&mut self, high: bool) {
+                let mut levels = LEVELS.lock().unwrap();
+                levels[self.0] = high;
+                let allowed = *ALLOWED.lock().unwrap();
+                assert!(
+                    levels[..5].iter().filter(|&&on| on).count() <= 1,
+                    "two rows on: {levels:?}"
+                );
+                for row in (0..5).filter(|&row| levels[row]) {
+                    for col in (0..5).filter(|&col| !levels[5 + col]) {
+                        assert!(allowed.is_set(col, row), "ghost at ({col}, {row})");
+                    }
+                }
+            }
+        }
+
+        impl embedded_hal::digital::ErrorType for CheckedPin {
+            type Error = core::convert::Infallible;
+        }
+
+        impl OutputPin for CheckedPin {
+            fn set_low(&mut self) -> Result<(), Self::Error> {
+                self.set(false);
+                Ok(())
+            }
+
+            fn set_high(&mut self) -> Result<(), Self::Error> {
+                self.set(true);
+                Ok(())
+            }
+        }
+
+        let _clock = mock_clock();
+        let mut display = LedMatrix::new(
+            core::array::from_fn(CheckedPin),
+            core::array::from_fn(|col| CheckedPin(5 + col)),
+        );
+        display.set_brightness(Brightness::MAX);
+        // One pixel per row, each in a different column, so a row showing
+        // its neighbor's pattern is caught
+        let diagonal = frame_5x5(&[0b10000, 0b00001, 0b00100, 0b01000, 0b00010]);
+        *ALLOWED.lock().unwrap() = diagonal;
+        display.apply(diagonal);
+        for _ in 0..15 {
+            display.render();
+        }
+        // Changing frames mid-scan shows the old or the new row, never a mix
+        let other = frame_5x5(&[0, 0b01000, 0, 0b00010, 0]);
+        let mut either = diagonal;
+        either.or(&other);
+        *ALLOWED.lock().unwrap() = either;
+        display.apply(other);
+        for _ in 0..15 {
+            display.render();
+        }
+    }
+
+    #[test]
+    fn alert_restores_brightness_and_frame() {
+        let _clock = mock_clock();
+        let mut display = mock_matrix::<5>();
+        display.set_brightness(Brightness::new(3));
+        display.apply(CHECK_MARK);
+        let mut all_on = Frame::empty();
+        all_on.fill(true);
+        // 10 ms per flash is two 5-refresh scans on and two off
+        let shown = frames_shown::<5>(display.alert(2, REFRESH_INTERVAL * 20));
+        assert_eq!(shown, [all_on, Frame::empty(), all_on, Frame::empty()]);
+        assert_eq!(display.brightness().level(), 3);
+        assert_eq!(display.frame_buffer, CHECK_MARK);
+
+        // Cancelled part way through
+        {
+            let mut alert = pin!(display.alert(5, REFRESH_INTERVAL * 20));
+            assert!(poll_once(alert.as_mut()).is_pending());
+        }
+        assert_eq!(display.brightness().level(), 3);
+        assert_eq!(display.frame_buffer, CHECK_MARK);
+        assert!(display.is_dirty());
+    }
+
+    #[test]
+    fn text_scrolls_across_a_wide_display() {
+        let _clock = mock_clock();
+        let mut display = mock_matrix::<10>();
+        // One column per 5-refresh scan
+        let speed = REFRESH_INTERVAL * 5 * 10;
+        let shown = frames_shown::<10>(display.scroll_with_speed("HI", speed));
+        let expected = strip_windows::<10>(&[b'H'.into(), b'I'.into()]);
+        assert_eq!(shown, expected);
+        assert!(display.is_blank());
+    }
+
+    #[test]
+    fn back_buffer_stays_hidden_until_swap() {
+        let _clock = mock_clock();
+        let mut display = mock_matrix::<5>();
+        // Everything lit over one full scan of the rows
+        let scan = |display: &mut LedMatrix<_, 5, 5>| {
+            let mut lit = Frame::empty();
+            for _ in 0..5 {
+                display.render();
+                lit.or(&lit_pixels());
+            }
+            lit
+        };
+        display.apply(CHECK_MARK);
+        let back = display.draw_to_back();
+        back.fill(true);
+        back.unset(2, 2);
+        assert_eq!(scan(&mut display), CHECK_MARK);
+        display.swap();
+        let mut expected = Frame::empty();
+        expected.fill(true);
+        expected.unset(2, 2);
+        assert_eq!(scan(&mut display), expected);
+        // The back buffer keeps its contents for the next edit
+        display.draw_to_back().set(2, 2);
+        display.swap();
+        expected.set(2, 2);
+        assert_eq!(scan(&mut display), expected);
+    }
+
+    #[test]
+    fn frame_counter_counts_each_render() {
+        let _clock = mock_clock();
+        let mut display = null_matrix();
+        assert_eq!(display.frames_rendered(), 0);
+        for count in 1..=12 {
+            display.render();
+            assert_eq!(display.frames_rendered(), count);
+        }
+        // Clearing and drawing do not count
+        display.clear();
+        display.apply(CHECK_MARK);
+        assert_eq!(display.frames_rendered(), 12);
+        // A displayed frame renders once per refresh interval
+        let start = display.frames_rendered();
+        {
+            let mut show = pin!(display.display(CHECK_MARK, REFRESH_INTERVAL * 10));
+            while poll_once(show.as_mut()).is_pending() {
+                advance(REFRESH_INTERVAL);
+            }
+        }
+        assert_eq!(display.frames_rendered().wrapping_sub(start), 10);
+        assert_eq!(refresh_rate(3000, Duration::from_millis(1500)), 2000);
+        assert_eq!(refresh_rate(10, Duration::from_ticks(0)), 0);
+    }
+
+    #[test]
+    fn playlist_scrolls_each_message_in_order_and_repeats() {
+        let _clock = mock_clock();
+        let mut display = mock_matrix::<5>();
+        // One column per 5-refresh scan
+        let per_char = REFRESH_INTERVAL * 5 * 5;
+        let gap = REFRESH_INTERVAL * 5 * 4;
+        let mut cycle = Vec::new();
+        for message in ["Hi", "OK"] {
+            let scroll = frames_shown::<5>(display.scroll_with_speed(message, per_char * message.len() as u32));
+            for frame in scroll.into_iter().chain([Frame::empty()]) {
+                if cycle.last() != Some(&frame) {
+                    cycle.push(frame);
+                }
+            }
+        }
+        // Two whole cycles and the start of a third; the empty message is skipped
+        let shown = frames_shown_within::<5>(display.scroll_playlist(&["Hi", "", "OK"], per_char, gap), 80)
+            .expect_err("a playlist never finishes");
+        let expected = [cycle.clone(), cycle].concat();
+        assert!(shown.len() > expected.len());
+        assert_eq!(shown[..expected.len()], expected);
+        // With nothing to scroll it returns straight away
+        assert_eq!(frames_shown::<5>(display.scroll_playlist(&["", ""], per_char, gap)), []);
+    }
 }
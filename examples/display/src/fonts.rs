@@ -12,6 +12,7 @@
 /// ## Available Graphics
 /// - **Arrows**: Left, Right directional indicators
 /// - **Symbols**: Check mark, Cross mark for status indication
+/// - **Patterns**: Checkerboard, border and cross generators for self-tests
 /// - **Custom Bitmaps**: Easy creation of 5x5 patterns
 ///
 /// ## Character Encoding
@@ -246,6 +247,34 @@ pub const CROSS_MARK: Frame<5, 5> = frame_5x5(&[
     0b00000,
 ]);
 
+#[rustfmt::skip]
+/// **Low Battery Bitmap**
+///
+/// A 5x5 bitmap of a battery with a single bar of charge left.
+/// Useful for warning that the supply voltage is running low.
+///
+/// **Pattern:**
+/// ```text
+/// ·▪▪▪·
+/// ▪···▪
+/// ▪···▪
+/// ▪···▪
+/// ▪▪▪▪▪
+/// ```
+///
+/// # Usage
+/// ```ignore
+/// display.display(fonts::BATTERY_LOW, Duration::from_secs(1)).await;
+/// ```
+#[allow(dead_code)]
+pub const BATTERY_LOW: Frame<5, 5> = frame_5x5(&[
+    0b01110,
+    0b10001,
+    0b10001,
+    0b10001,
+    0b11111,
+]);
+
 #[rustfmt::skip]
 /// **Left Arrow Bitmap ←**
 ///
@@ -300,6 +329,187 @@ pub const ARROW_RIGHT: Frame<5, 5> = frame_5x5(&[
     0b00100,
 ]);
 
+#[rustfmt::skip]
+/// **Dice Face Bitmaps ⚀-⚅**
+///
+/// Pip patterns for the six faces of a die, indexed by value minus one.
+/// Pips sit on the corners, edge midpoints and center of the matrix, so
+/// every face is symmetric and 6 is two columns of three.
+///
+/// **Patterns (1-6):**
+/// ```text
+/// ·····  ▪····  ▪····  ▪···▪  ▪···▪  ▪···▪
+/// ·····  ·····  ·····  ·····  ·····  ·····
+/// ··▪··  ·····  ··▪··  ·····  ··▪··  ▪···▪
+/// ·····  ·····  ·····  ·····  ·····  ·····
+/// ·····  ····▪  ····▪  ▪···▪  ▪···▪  ▪···▪
+/// ```
+pub const DICE_FACES: [Frame<5, 5>; 6] = [
+    frame_5x5(&[0b00000, 0b00000, 0b00100, 0b00000, 0b00000]),
+    frame_5x5(&[0b10000, 0b00000, 0b00000, 0b00000, 0b00001]),
+    frame_5x5(&[0b10000, 0b00000, 0b00100, 0b00000, 0b00001]),
+    frame_5x5(&[0b10001, 0b00000, 0b00000, 0b00000, 0b10001]),
+    frame_5x5(&[0b10001, 0b00000, 0b00100, 0b00000, 0b10001]),
+    frame_5x5(&[0b10001, 0b00000, 0b10001, 0b00000, 0b10001]),
+];
+
+/// **Dice Face**
+///
+/// Returns the standard pip pattern for a die value.
+///
+/// # Arguments
+/// * `value` - Die value, 1-6 (0 is treated as 1 and values above 6 as 6)
+///
+/// # Returns
+/// The matching entry of `DICE_FACES`
+///
+/// # Example
+/// ```ignore
+/// display.display(fonts::dice_face(5), Duration::from_secs(2)).await;
+/// ```
+pub fn dice_face(value: u8) -> Frame<5, 5> {
+    DICE_FACES[value.clamp(1, 6) as usize - 1]
+}
+
+/// **Checkerboard Pattern**
+///
+/// Alternating lit and dark LEDs. The top-left LED is lit unless `invert`
+/// is set, and `checkerboard(true)` is the exact complement of
+/// `checkerboard(false)`.
+///
+/// **Pattern (`invert = false`):**
+/// ```text
+/// ▪·▪·▪
+/// ·▪·▪·
+/// ▪·▪·▪
+/// ·▪·▪·
+/// ▪·▪·▪
+/// ```
+///
+/// # Arguments
+/// * `invert` - Start with a dark LED in the top-left corner
+///
+/// # Example
+/// ```ignore
+/// display.display(fonts::checkerboard(false), Duration::from_millis(500)).await;
+/// display.display(fonts::checkerboard(true), Duration::from_millis(500)).await;
+/// ```
+#[allow(dead_code)]
+pub const fn checkerboard(invert: bool) -> Frame<5, 5> {
+    let mut rows = [0u8; 5];
+    let mut y = 0;
+    while y < 5 {
+        rows[y] = if (y % 2 == 0) != invert { 0b10101 } else { 0b01010 };
+        y += 1;
+    }
+    frame_5x5(&rows)
+}
+
+/// **Border Pattern**
+///
+/// The outer ring of 16 LEDs.
+///
+/// **Pattern:**
+/// ```text
+/// ▪▪▪▪▪
+/// ▪···▪
+/// ▪···▪
+/// ▪···▪
+/// ▪▪▪▪▪
+/// ```
+///
+/// # Example
+/// ```ignore
+/// display.display(fonts::border(), Duration::from_secs(1)).await;
+/// ```
+#[allow(dead_code)]
+pub const fn border() -> Frame<5, 5> {
+    let mut rows = [0b10001u8; 5];
+    rows[0] = 0b11111;
+    rows[4] = 0b11111;
+    frame_5x5(&rows)
+}
+
+/// **Cross Pattern**
+///
+/// The middle row and middle column, forming a plus sign.
+///
+/// **Pattern:**
+/// ```text
+/// ··▪··
+/// ··▪··
+/// ▪▪▪▪▪
+/// ··▪··
+/// ··▪··
+/// ```
+///
+/// # Example
+/// ```ignore
+/// display.display(fonts::cross(), Duration::from_secs(1)).await;
+/// ```
+#[allow(dead_code)]
+pub const fn cross() -> Frame<5, 5> {
+    let mut rows = [0b00100u8; 5];
+    rows[2] = 0b11111;
+    frame_5x5(&rows)
+}
+
+/// **Spinner Phases**
+///
+/// Number of distinct `spinner_frame` phases in one full turn.
+pub const SPINNER_PHASES: u8 = 8;
+
+#[rustfmt::skip]
+/// **Spinner Bitmaps**
+///
+/// The steps of a busy indicator: a three-LED needle from the center to
+/// the edge, turned clockwise by an eighth of a turn per step, starting
+/// straight up. These are the eight compass points of
+/// `widgets::needle_frame`. Consecutive steps share the center LED and have
+/// neighboring tips, including the wrap from the last step back to the
+/// first.
+///
+/// **Patterns (phases 0-7):**
+/// ```text
+/// ··▪··  ····▪  ·····  ·····  ·····  ·····  ·····  ▪····
+/// ··▪··  ···▪·  ·····  ·····  ·····  ·····  ·····  ·▪···
+/// ··▪··  ··▪··  ··▪▪▪  ··▪··  ··▪··  ··▪··  ▪▪▪··  ··▪··
+/// ·····  ·····  ·····  ···▪·  ··▪··  ·▪···  ·····  ·····
+/// ·····  ·····  ·····  ····▪  ··▪··  ▪····  ·····  ·····
+/// ```
+pub const SPINNER_FRAMES: [Frame<5, 5>; SPINNER_PHASES as usize] = [
+    frame_5x5(&[0b00100, 0b00100, 0b00100, 0b00000, 0b00000]),
+    frame_5x5(&[0b00001, 0b00010, 0b00100, 0b00000, 0b00000]),
+    frame_5x5(&[0b00000, 0b00000, 0b00111, 0b00000, 0b00000]),
+    frame_5x5(&[0b00000, 0b00000, 0b00100, 0b00010, 0b00001]),
+    frame_5x5(&[0b00000, 0b00000, 0b00100, 0b00100, 0b00100]),
+    frame_5x5(&[0b00000, 0b00000, 0b00100, 0b01000, 0b10000]),
+    frame_5x5(&[0b00000, 0b00000, 0b11100, 0b00000, 0b00000]),
+    frame_5x5(&[0b10000, 0b01000, 0b00100, 0b00000, 0b00000]),
+];
+
+/// **Spinner Frame**
+///
+/// Returns one step of the busy indicator drawn by `SPINNER_FRAMES`.
+///
+/// # Arguments
+/// * `phase` - Animation step; only `phase % SPINNER_PHASES` matters, so a
+///   free-running counter can be passed straight in
+///
+/// # Returns
+/// The matching entry of `SPINNER_FRAMES`
+///
+/// # Example
+/// ```ignore
+/// for phase in 0..SPINNER_PHASES {
+///     display.display(fonts::spinner_frame(phase), Duration::from_millis(100)).await;
+/// }
+/// ```
+#[allow(dead_code)]
+pub fn spinner_frame(phase: u8) -> Frame<5, 5> {
+    SPINNER_FRAMES[(phase % SPINNER_PHASES) as usize]
+}
+
 /// **Create 5x5 Frame from Byte Array**
 ///
 /// Constructs a Frame from a 5-element byte array where each byte
@@ -309,7 +519,9 @@ pub const ARROW_RIGHT: Frame<5, 5> = frame_5x5(&[
 /// * `input` - Array of 5 bytes, each representing a row bitmap
 ///
 /// # Returns
-/// A `Frame<XSIZE, YSIZE>` ready for display on the LED matrix
+/// A `Frame<XSIZE, YSIZE>` ready for display on the LED matrix. In a frame
+/// larger than 5x5 the pattern fills the top-left corner and the rest stays
+/// blank; a smaller frame shows only the part of the pattern that fits.
 ///
 /// # Bit Encoding
 /// Each byte encodes 5 pixels (bits 7-3), with bits 2-0 unused:
@@ -332,12 +544,13 @@ pub const ARROW_RIGHT: Frame<5, 5> = frame_5x5(&[
 /// display.display(heart, Duration::from_secs(2)).await;
 /// ```
 pub const fn frame_5x5<const XSIZE: usize, const YSIZE: usize>(input: &[u8; 5]) -> Frame<XSIZE, YSIZE> {
-    let mut data = [Bitmap::empty(5); YSIZE];
-    data[0] = Bitmap::new(input[0], 5);
-    data[1] = Bitmap::new(input[1], 5);
-    data[2] = Bitmap::new(input[2], 5);
-    data[3] = Bitmap::new(input[3], 5);
-    data[4] = Bitmap::new(input[4], 5);
+    let mut data = [Bitmap::empty(XSIZE); YSIZE];
+    let mut y = 0;
+    while y < 5 && y < YSIZE {
+        // Left-align the 5 pattern bits; narrower frames drop the extra columns
+        data[y] = Bitmap::from_raw(input[y] << 3, XSIZE);
+        y += 1;
+    }
     Frame::new(data)
 }
 
@@ -370,6 +583,9 @@ impl<const XSIZE: usize, const YSIZE: usize> Into<Frame<XSIZE, YSIZE>> for u8 {
 /// 3. Converts to Frame using frame_5x5 helper
 /// 4. Returns blank frame for unsupported characters
 ///
+/// Frames of other sizes get the glyph in their top-left 5x5 corner, like
+/// `frame_5x5`, so text renders on chained or cropped displays too.
+///
 /// # Example
 /// ```ignore
 /// let a_frame: Frame<5, 5> = 'A'.into();
@@ -378,14 +594,112 @@ impl<const XSIZE: usize, const YSIZE: usize> Into<Frame<XSIZE, YSIZE>> for u8 {
 /// ```
 impl<const XSIZE: usize, const YSIZE: usize> Into<Frame<XSIZE, YSIZE>> for char {
     fn into(self) -> Frame<XSIZE, YSIZE> {
-        assert!(XSIZE == 5);
-        assert!(YSIZE == 5);
+        glyph(self as usize)
+    }
+}
+
+/// Font bitmap for character code `n`, blank outside the printable range
+const fn glyph<const XSIZE: usize, const YSIZE: usize>(n: usize) -> Frame<XSIZE, YSIZE> {
+    if n > PRINTABLE_START && n < PRINTABLE_START + PRINTABLE_COUNT {
+        frame_5x5(&PENDOLINO3[n - PRINTABLE_START])
+    } else {
+        frame_5x5(&[0, 0, 0, 0, 0])
+    }
+}
+
+/// **Compile-Time Text Frames**
+///
+/// Looks up the glyph for every byte of `text` during constant evaluation,
+/// giving the same frames as converting each byte with `into()` at run
+/// time, including blank frames for bytes outside the printable range.
+/// Assigned to a `static`, the frames live in flash and showing them needs
+/// no font lookup at all.
+///
+/// `N` must equal `text.len()`; a mismatch fails to compile when used in a
+/// `const` or `static`.
+///
+/// # Arguments
+/// * `text` - Text to convert, one frame per byte
+///
+/// # Returns
+/// The glyph frames of `text`, in order
+///
+/// # Example
+/// ```ignore
+/// static BANNER: [Frame<5, 5>; 5] = fonts::text_frames("HELLO");
+/// for frame in BANNER.iter() {
+///     display.display(*frame, Duration::from_millis(400)).await;
+/// }
+/// ```
+#[allow(dead_code)]
+pub const fn text_frames<const N: usize>(text: &str) -> [Frame<5, 5>; N] {
+    let bytes = text.as_bytes();
+    assert!(bytes.len() == N, "text length must equal N");
+
+    let mut frames = [Frame::empty(); N];
+    let mut i = 0;
+    while i < N {
+        frames[i] = glyph(bytes[i] as usize);
+        i += 1;
+    }
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dice_faces_match_their_pips() {
+        assert_eq!(dice_face(5), frame_5x5(&[0b10001, 0b00000, 0b00100, 0b00000, 0b10001]));
+        // Six is two columns of three pips
+        assert_eq!(dice_face(6), frame_5x5(&[0b10001, 0b00000, 0b10001, 0b00000, 0b10001]));
+        // Out-of-range values clamp to the nearest face
+        assert_eq!(dice_face(0), dice_face(1));
+        assert_eq!(dice_face(9), dice_face(6));
+        for value in 1..=6 {
+            let face = dice_face(value);
+            assert_eq!(face.difference(&Frame::empty()), value as u32);
+            // Every face looks the same upside down
+            assert_eq!(face.rotate_right().rotate_right(), face);
+        }
+    }
+
+    #[test]
+    fn patterns_match_their_bitmaps() {
+        let light_corner = frame_5x5(&[0b10101, 0b01010, 0b10101, 0b01010, 0b10101]);
+        let dark_corner = frame_5x5(&[0b01010, 0b10101, 0b01010, 0b10101, 0b01010]);
+        assert_eq!(checkerboard(false), light_corner);
+        assert_eq!(checkerboard(true), dark_corner);
+        assert_eq!(border(), frame_5x5(&[0b11111, 0b10001, 0b10001, 0b10001, 0b11111]));
+        assert_eq!(cross(), frame_5x5(&[0b00100, 0b00100, 0b11111, 0b00100, 0b00100]));
+        // The generators also work in const context
+        const INVERTED: Frame<5, 5> = checkerboard(true);
+        assert_eq!(INVERTED, dark_corner);
+    }
+
+    #[test]
+    fn spinner_phases_are_distinct() {
+        for phase in 0..SPINNER_PHASES {
+            for earlier in 0..phase {
+                assert_ne!(spinner_frame(phase), spinner_frame(earlier));
+            }
+            assert_eq!(spinner_frame(phase + SPINNER_PHASES), spinner_frame(phase));
+        }
+        let up = frame_5x5(&[0b00100, 0b00100, 0b00100, 0, 0]);
+        assert_eq!(spinner_frame(0), up);
+        assert_eq!(spinner_frame(2), up.rotate_right());
+    }
 
-        let n = self as usize;
-        if n > PRINTABLE_START && n < PRINTABLE_START + PRINTABLE_COUNT {
-            frame_5x5(&PENDOLINO3[n - PRINTABLE_START])
-        } else {
-            frame_5x5(&[0, 0, 0, 0, 0])
+    #[test]
+    fn const_text_frames_match_runtime_glyphs() {
+        const BANNER: [Frame<5, 5>; 5] = text_frames("Hi 7~");
+        for (frame, c) in BANNER.iter().zip("Hi 7~".chars()) {
+            let runtime: Frame<5, 5> = c.into();
+            assert_eq!(*frame, runtime);
         }
+        // Bytes outside the font are blank, as at runtime
+        const UNPRINTABLE: [Frame<5, 5>; 2] = text_frames("\t\x7f");
+        assert_eq!(UNPRINTABLE, [Frame::empty(); 2]);
     }
 }
@@ -0,0 +1,273 @@
+//! # Number Formatting Module
+//!
+//! This module converts numeric values into short strings that can be rendered
+//! with the LED matrix font. Everything is written into fixed-capacity
+//! `heapless::String` buffers, so no heap allocation is ever required.
+//!
+//! ## Features
+//! - **Integers**: Signed whole numbers, including `i32::MIN`
+//! - **Fixed-Point**: Scaled integers rendered with a decimal point
+//! - **Hexadecimal**: Zero-padded uppercase hex for debugging raw values
+//! - **Elapsed Time**: Minutes and seconds separated by a colon
+//! - **Time of Day**: Zero-padded 24-hour `HH:MM` clock readings
+//! - **Bounded Buffers**: Capacities sized for the worst-case input
+//!
+//! ## Usage Example
+//! ```ignore
+//! let text = format_number(-42);
+//! display.scroll(&text).await;
+//!
+//! let text = format_fixed(235, 1); // "23.5"
+//! display.scroll(&text).await;
+//!
+//! let text = format_hex(0x1F, 4); // "001F"
+//! display.scroll(&text).await;
+//!
+//! let text = format_mm_ss(95); // "01:35"
+//! display.scroll(&text).await;
+//!
+//! let text = format_hh_mm(9, 5); // "09:05"
+//! display.scroll(&text).await;
+//! ```
+
+use core::fmt::Write;
+
+use heapless::String;
+
+/// **Integer Text Capacity**
+///
+/// Number of characters needed for the longest `i32` value,
+/// `"-2147483648"` (a minus sign followed by 10 digits).
+pub const NUMBER_CAPACITY: usize = 11;
+
+/// **Format Signed Integer**
+///
+/// Formats a signed integer in decimal with a leading minus sign for
+/// negative values. The buffer is sized so every `i32` fits, so the
+/// full range (including `i32::MIN`) formats without overflow.
+///
+/// # Arguments
+/// * `n` - Value to format
+///
+/// # Returns
+/// The decimal representation of `n`
+///
+/// # Example
+/// ```ignore
+/// assert_eq!(format_number(-42), "-42");
+/// ```
+pub fn format_number(n: i32) -> String<NUMBER_CAPACITY> {
+    let mut text = String::new();
+    // Cannot fail: every i32 fits within NUMBER_CAPACITY characters
+    write!(text, "{}", n).ok();
+    text
+}
+
+/// **Fixed-Point Text Capacity**
+///
+/// Number of characters needed for the longest fixed-point value: a minus
+/// sign, ten digits and the decimal point (e.g. `"-2.147483648"`).
+pub const FIXED_CAPACITY: usize = 12;
+
+/// **Maximum Fixed-Point Decimals**
+///
+/// An `i32` has at most ten digits, so more than nine decimals would only
+/// ever add leading zeros. Larger requests are clamped to this value.
+pub const MAX_DECIMALS: u8 = 9;
+
+/// **Format Fixed-Point Value**
+///
+/// Formats a scaled integer as a decimal number, inserting the decimal point
+/// `decimals` digits from the right. Digits are extracted manually so no
+/// floating point support is needed.
+///
+/// # Arguments
+/// * `value` - Scaled value (e.g. `235` for 23.5 with one decimal)
+/// * `decimals` - Number of digits after the decimal point (clamped to `MAX_DECIMALS`)
+///
+/// # Returns
+/// The decimal representation, always with at least one digit before the point
+///
+/// # Example
+/// ```ignore
+/// assert_eq!(format_fixed(235, 1), "23.5");
+/// assert_eq!(format_fixed(0, 1), "0.0");
+/// assert_eq!(format_fixed(5, 2), "0.05");
+/// assert_eq!(format_fixed(-5, 2), "-0.05");
+/// ```
+pub fn format_fixed(value: i32, decimals: u8) -> String<FIXED_CAPACITY> {
+    let decimals = decimals.min(MAX_DECIMALS) as usize;
+
+    // Collect digits least-significant first, padding with zeros until there
+    // is at least one digit in front of the decimal point
+    let mut digits = [0u8; 10];
+    let mut count = 0;
+    let mut magnitude = value.unsigned_abs();
+    while magnitude > 0 || count <= decimals {
+        digits[count] = b'0' + (magnitude % 10) as u8;
+        magnitude /= 10;
+        count += 1;
+    }
+
+    // Cannot fail: sign, ten digits and the point fit within FIXED_CAPACITY
+    let mut text = String::new();
+    if value < 0 {
+        text.push('-').ok();
+    }
+    for i in (0..count).rev() {
+        text.push(digits[i] as char).ok();
+        if i == decimals && decimals > 0 {
+            text.push('.').ok();
+        }
+    }
+    text
+}
+
+/// **Hexadecimal Text Capacity**
+///
+/// Number of characters needed for the longest `u32` value, `"FFFFFFFF"`.
+pub const HEX_CAPACITY: usize = 8;
+
+/// **Format Hexadecimal Value**
+///
+/// Formats an unsigned value in uppercase hexadecimal, padded with leading
+/// zeros to at least `digits` characters. Values needing more digits are
+/// never truncated.
+///
+/// # Arguments
+/// * `value` - Value to format
+/// * `digits` - Minimum number of digits (clamped to `HEX_CAPACITY`)
+///
+/// # Returns
+/// The zero-padded hexadecimal representation, without a `0x` prefix
+///
+/// # Example
+/// ```ignore
+/// assert_eq!(format_hex(0x1F, 4), "001F");
+/// assert_eq!(format_hex(0xBEEF, 2), "BEEF");
+/// ```
+pub fn format_hex(value: u32, digits: u8) -> String<HEX_CAPACITY> {
+    let digits = (digits as usize).min(HEX_CAPACITY);
+    let mut text = String::new();
+    // Cannot fail: every u32 fits within HEX_CAPACITY hex digits
+    write!(text, "{:01$X}", value, digits).ok();
+    text
+}
+
+/// **Elapsed Time Text Capacity**
+///
+/// Number of characters needed for the longest `u32` second count: eight
+/// minute digits (`u32::MAX / 60`, wider than the two-digit padding), the
+/// colon and two second digits.
+pub const MM_SS_CAPACITY: usize = 11;
+
+/// **Format Minutes and Seconds**
+///
+/// Formats a number of seconds as `mm:ss`. Minutes and seconds are padded
+/// to two digits; minutes keep counting past 59 rather than rolling over
+/// into hours, growing to as many digits as they need.
+///
+/// # Arguments
+/// * `total_secs` - Elapsed time in whole seconds
+///
+/// # Returns
+/// The elapsed time as two-digit minutes, a colon and two-digit seconds
+///
+/// # Example
+/// ```ignore
+/// assert_eq!(format_mm_ss(7), "00:07");
+/// assert_eq!(format_mm_ss(95), "01:35");
+/// assert_eq!(format_mm_ss(6000), "100:00");
+/// ```
+pub fn format_mm_ss(total_secs: u32) -> String<MM_SS_CAPACITY> {
+    let mut text = String::new();
+    // Cannot fail: every u32 second count fits within MM_SS_CAPACITY characters
+    write!(text, "{:02}:{:02}", total_secs / 60, total_secs % 60).ok();
+    text
+}
+
+/// **Time of Day Text Capacity**
+///
+/// Number of characters in a `HH:MM` reading: two hour digits, the colon
+/// and two minute digits.
+pub const HH_MM_CAPACITY: usize = 5;
+
+/// **Format Time of Day**
+///
+/// Formats a 24-hour clock reading as `HH:MM`, zero-padding both fields so
+/// the colon always sits in the middle. Out-of-range values are clamped to
+/// the last valid one, so hours above 23 show as `23` and minutes above 59
+/// as `59`.
+///
+/// # Arguments
+/// * `hours` - Hour of the day, 0-23
+/// * `minutes` - Minute of the hour, 0-59
+///
+/// # Returns
+/// The time as two-digit hours, a colon and two-digit minutes
+///
+/// # Example
+/// ```ignore
+/// assert_eq!(format_hh_mm(9, 5), "09:05");
+/// assert_eq!(format_hh_mm(23, 59), "23:59");
+/// assert_eq!(format_hh_mm(25, 70), "23:59");
+/// ```
+pub fn format_hh_mm(hours: u8, minutes: u8) -> String<HH_MM_CAPACITY> {
+    let mut text = String::new();
+    // Cannot fail: clamped fields always give exactly HH_MM_CAPACITY characters
+    write!(text, "{:02}:{:02}", hours.min(23), minutes.min(59)).ok();
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_point_places_the_decimal_point() {
+        assert_eq!(format_fixed(235, 1), "23.5");
+        assert_eq!(format_fixed(0, 1), "0.0");
+        assert_eq!(format_fixed(5, 2), "0.05");
+        assert_eq!(format_fixed(-5, 2), "-0.05");
+        assert_eq!(format_fixed(7, 0), "7");
+        assert_eq!(format_fixed(-1234, 0), "-1234");
+    }
+
+    #[test]
+    fn extremes_fit_their_buffers() {
+        assert_eq!(format_fixed(i32::MIN, 9), "-2.147483648");
+        assert_eq!(format_number(i32::MIN), "-2147483648");
+    }
+
+    #[test]
+    fn hex_is_zero_padded_and_clamped() {
+        assert_eq!(format_hex(0x1F, 4), "001F");
+        assert_eq!(format_hex(1, 8), "00000001");
+        // Too few digits never truncates the value
+        assert_eq!(format_hex(0xBEEF, 2), "BEEF");
+        assert_eq!(format_hex(0, 0), "0");
+        // More than eight digits are clamped to eight
+        assert_eq!(format_hex(u32::MAX, 200), "FFFFFFFF");
+    }
+
+    #[test]
+    fn mm_ss_pads_minutes_and_seconds() {
+        assert_eq!(format_mm_ss(0), "00:00");
+        assert_eq!(format_mm_ss(7), "00:07");
+        assert_eq!(format_mm_ss(60), "01:00");
+        assert_eq!(format_mm_ss(95), "01:35");
+        // Minutes keep counting past an hour, and past two digits
+        assert_eq!(format_mm_ss(3600), "60:00");
+        assert_eq!(format_mm_ss(6000), "100:00");
+        assert_eq!(format_mm_ss(u32::MAX), "71582788:15");
+    }
+
+    #[test]
+    fn hh_mm_pads_both_fields() {
+        assert_eq!(format_hh_mm(9, 5), "09:05");
+        assert_eq!(format_hh_mm(0, 0), "00:00");
+        assert_eq!(format_hh_mm(23, 59), "23:59");
+        // Out-of-range values are clamped
+        assert_eq!(format_hh_mm(255, 60), "23:59");
+    }
+}
@@ -0,0 +1,457 @@
+//! # Games Module
+//!
+//! This module holds the pure game logic behind the interactive demos. Nothing
+//! here touches hardware: each game advances its own state and hands back a
+//! `Frame<5, 5>`, leaving timing and input to the caller's display loop.
+//!
+//! ## Features
+//! - **Bouncing Ball**: A single pixel moving diagonally and reflecting off
+//!   the edges of the matrix
+//! - **Snake**: The classic game on a 5x5 grid, with food placed from a
+//!   caller-supplied random number so games can be replayed exactly
+//!
+//! ## Usage Example
+//! ```ignore
+//! let mut ball = BouncingBall::new(0, 0, 1, 1);
+//! loop {
+//!     display.display(ball.step(), Duration::from_millis(150)).await;
+//! }
+//!
+//! let mut snake = Snake::new();
+//! snake.spawn_food(random);
+//! while snake.step(Direction::Right) != GameState::GameOver {
+//!     display.display(snake.render(), Duration::from_millis(400)).await;
+//! }
+//! ```
+
+use heapless::Deque;
+
+use crate::types::{Direction, Frame};
+
+/// **Matrix Size**
+///
+/// Number of LEDs along each side of the micro:bit display.
+const SIZE: i8 = 5;
+
+/// **Cell Count**
+///
+/// Number of LEDs on the matrix, and so the longest a snake can grow.
+const CELLS: usize = (SIZE * SIZE) as usize;
+
+/// **Bouncing Ball**
+///
+/// A one-pixel ball with a velocity of at most one LED per step on each
+/// axis. When the next step would leave the matrix the offending component
+/// of the velocity is reversed, so hitting a side reflects one axis and
+/// hitting a corner reflects both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BouncingBall {
+    x: i8,
+    y: i8,
+    dx: i8,
+    dy: i8,
+}
+
+#[allow(dead_code)]
+impl BouncingBall {
+    /// **Create Ball**
+    ///
+    /// # Arguments
+    /// * `x` - Starting column (clamped to 0-4)
+    /// * `y` - Starting row (clamped to 0-4)
+    /// * `dx` - Horizontal velocity; only the sign is used
+    /// * `dy` - Vertical velocity; only the sign is used
+    ///
+    /// # Example
+    /// ```ignore
+    /// let ball = BouncingBall::new(2, 0, 1, 1); // top middle, heading down-right
+    /// ```
+    pub fn new(x: i8, y: i8, dx: i8, dy: i8) -> Self {
+        Self {
+            x: x.clamp(0, SIZE - 1),
+            y: y.clamp(0, SIZE - 1),
+            dx: dx.signum(),
+            dy: dy.signum(),
+        }
+    }
+
+    /// **Ball Position**
+    ///
+    /// # Returns
+    /// The current `(x, y)` position, always within the matrix
+    pub fn position(&self) -> (usize, usize) {
+        (self.x as usize, self.y as usize)
+    }
+
+    /// **Ball Velocity**
+    ///
+    /// # Returns
+    /// The current `(dx, dy)` velocity, each component -1, 0 or 1
+    pub fn velocity(&self) -> (i8, i8) {
+        (self.dx, self.dy)
+    }
+
+    /// **Advance One Step**
+    ///
+    /// Moves the ball by its velocity, first reversing any component that
+    /// would carry it past an edge.
+    ///
+    /// # Returns
+    /// A frame with only the ball's new position lit
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut ball = BouncingBall::new(3, 3, 1, 1);
+    /// ball.step(); // (4, 4)
+    /// ball.step(); // corner bounce back to (3, 3)
+    /// ```
+    pub fn step(&mut self) -> Frame<5, 5> {
+        if !(0..SIZE).contains(&(self.x + self.dx)) {
+            self.dx = -self.dx;
+        }
+        if !(0..SIZE).contains(&(self.y + self.dy)) {
+            self.dy = -self.dy;
+        }
+        self.x += self.dx;
+        self.y += self.dy;
+
+        let (x, y) = self.position();
+        let mut frame = Frame::empty();
+        frame.set(x, y);
+        frame
+    }
+}
+
+/// **Snake Step Result**
+///
+/// Outcome of one `Snake::step`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum GameState {
+    /// The snake moved one cell
+    Running,
+    /// The snake moved onto the food and grew by one cell; place new food
+    /// with `Snake::spawn_food`
+    Ate,
+    /// The snake ran into a wall or itself; further steps do nothing
+    GameOver,
+}
+
+/// **Snake Game**
+///
+/// A snake on the 5x5 grid, starting two cells long in the middle row and
+/// heading right. Each step moves the head one cell; eating food makes the
+/// snake one cell longer. Leaving the grid or running into its own body
+/// ends the game.
+#[derive(Debug, Clone)]
+pub struct Snake {
+    /// Occupied cells, head first
+    body: Deque<(i8, i8), CELLS>,
+    heading: Direction,
+    food: Option<(i8, i8)>,
+    over: bool,
+}
+
+#[allow(dead_code)]
+impl Snake {
+    /// **Create Snake**
+    ///
+    /// # Returns
+    /// A two-cell snake with its head at (1, 2), heading right, and no food
+    /// on the grid yet
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut snake = Snake::new();
+    /// snake.spawn_food(random);
+    /// ```
+    pub fn new() -> Self {
+        let mut body = Deque::new();
+        // Cannot fail: the deque has room for every cell
+        body.push_back((1, 2)).ok();
+        body.push_back((0, 2)).ok();
+        Self {
+            body,
+            heading: Direction::Right,
+            food: None,
+            over: false,
+        }
+    }
+
+    /// **Heading**
+    ///
+    /// # Returns
+    /// The direction the snake moved on its last step
+    pub fn heading(&self) -> Direction {
+        self.heading
+    }
+
+    /// **Head Position**
+    ///
+    /// # Returns
+    /// The `(x, y)` cell of the snake's head
+    pub fn head(&self) -> (usize, usize) {
+        let (x, y) = self.head_cell();
+        (x as usize, y as usize)
+    }
+
+    /// **Snake Length**
+    ///
+    /// # Returns
+    /// Number of cells the snake occupies, 2 at the start
+    pub fn length(&self) -> usize {
+        self.body.len()
+    }
+
+    /// **Food Position**
+    ///
+    /// # Returns
+    /// The `(x, y)` cell holding food, or `None` if none has been placed
+    pub fn food(&self) -> Option<(usize, usize)> {
+        self.food.map(|(x, y)| (x as usize, y as usize))
+    }
+
+    /// **Check Game Over**
+    ///
+    /// # Returns
+    /// `true` once the snake has hit a wall or itself
+    pub fn is_over(&self) -> bool {
+        self.over
+    }
+
+    /// **Place Food**
+    ///
+    /// Puts the food on one of the cells the snake does not cover, chosen
+    /// by `random`. The same value always picks the same cell for the same
+    /// snake, so tests can place food exactly where they want it.
+    ///
+    /// # Arguments
+    /// * `random` - Random number, e.g. from the hardware RNG
+    ///
+    /// # Returns
+    /// `false` if the snake fills the whole grid and there is nowhere left
+    /// to put food
+    ///
+    /// # Example
+    /// ```ignore
+    /// if snake.step(heading) == GameState::Ate && !snake.spawn_food(rng.next_u32()) {
+    ///     display.scroll("WIN").await;
+    /// }
+    /// ```
+    pub fn spawn_food(&mut self, random: u32) -> bool {
+        let free = CELLS - self.body.len();
+        self.food = None;
+        if free == 0 {
+            return false;
+        }
+
+        let mut skip = random as usize % free;
+        for y in 0..SIZE {
+            for x in 0..SIZE {
+                if self.body.iter().any(|&cell| cell == (x, y)) {
+                    continue;
+                }
+                if skip == 0 {
+                    self.food = Some((x, y));
+                    return true;
+                }
+                skip -= 1;
+            }
+        }
+        false
+    }
+
+    /// **Advance One Step**
+    ///
+    /// Turns the snake to `direction` and moves the head one cell. Asking
+    /// the snake to reverse straight back into its own neck is ignored and
+    /// it keeps its heading. Moving into the cell the tail is leaving is
+    /// allowed, as the tail moves on in the same step.
+    ///
+    /// # Arguments
+    /// * `direction` - Direction to move in
+    ///
+    /// # Returns
+    /// `Ate` if the head reached the food, `GameOver` if it left the grid or
+    /// hit the body (and on every step after that), `Running` otherwise
+    ///
+    /// # Example
+    /// ```ignore
+    /// match snake.step(Direction::Up) {
+    ///     GameState::Ate => { snake.spawn_food(random); }
+    ///     GameState::GameOver => break,
+    ///     GameState::Running => {}
+    /// }
+    /// ```
+    pub fn step(&mut self, direction: Direction) -> GameState {
+        if self.over {
+            return GameState::GameOver;
+        }
+        if direction != self.heading.opposite() {
+            self.heading = direction;
+        }
+
+        let (dx, dy) = match self.heading {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        };
+        let (x, y) = self.head_cell();
+        let next = (x + dx, y + dy);
+        if !(0..SIZE).contains(&next.0) || !(0..SIZE).contains(&next.1) {
+            self.over = true;
+            return GameState::GameOver;
+        }
+
+        let eats = self.food == Some(next);
+        // Unless the snake grows, its tail moves out of the way this step
+        let solid = if eats { self.body.len() } else { self.body.len() - 1 };
+        if self.body.iter().take(solid).any(|&cell| cell == next) {
+            self.over = true;
+            return GameState::GameOver;
+        }
+
+        if eats {
+            self.food = None;
+        } else {
+            self.body.pop_back();
+        }
+        // Cannot fail: the head moved onto a cell no part of the body covers
+        self.body.push_front(next).ok();
+
+        if eats {
+            GameState::Ate
+        } else {
+            GameState::Running
+        }
+    }
+
+    /// Cell of the head; the body always holds at least two cells
+    fn head_cell(&self) -> (i8, i8) {
+        self.body.front().copied().unwrap_or_default()
+    }
+
+    /// **Render Game**
+    ///
+    /// # Returns
+    /// A frame with the snake's body and the food lit
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.display(snake.render(), Duration::from_millis(400)).await;
+    /// ```
+    pub fn render(&self) -> Frame<5, 5> {
+        let mut frame = Frame::empty();
+        for &(x, y) in self.body.iter() {
+            frame.set(x as usize, y as usize);
+        }
+        if let Some((x, y)) = self.food() {
+            frame.set(x, y);
+        }
+        frame
+    }
+}
+
+impl Default for Snake {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Places the food on `cell`, trying random values in turn
+    fn feed_at(snake: &mut Snake, cell: (usize, usize)) {
+        let placed = (0..CELLS as u32).any(|random| snake.spawn_food(random) && snake.food() == Some(cell));
+        assert!(placed, "{cell:?} is not free");
+    }
+
+    #[test]
+    fn ball_reflects_off_walls_and_corners() {
+        // Side wall: only the horizontal velocity flips
+        let mut ball = BouncingBall::new(3, 1, 1, 1);
+        let frame = ball.step();
+        assert_eq!(ball.position(), (4, 2));
+        assert!(frame.is_set(4, 2));
+        ball.step();
+        assert_eq!(ball.position(), (3, 3));
+        assert_eq!(ball.velocity(), (-1, 1));
+        // Corner: both components flip
+        let mut ball = BouncingBall::new(3, 3, 1, 1);
+        ball.step();
+        ball.step();
+        assert_eq!(ball.position(), (3, 3));
+        assert_eq!(ball.velocity(), (-1, -1));
+        // Out-of-range starts are clamped and never leave the grid
+        let mut ball = BouncingBall::new(9, -3, 1, -1);
+        for _ in 0..200 {
+            let frame = ball.step();
+            let (x, y) = ball.position();
+            assert!(x < 5 && y < 5);
+            assert_eq!(frame.difference(&Frame::empty()), 1);
+        }
+    }
+
+    #[test]
+    fn snake_grows_when_it_eats() {
+        let mut snake = Snake::new();
+        // The same random value always picks the same free cell
+        assert!(snake.spawn_food(0));
+        assert_eq!(snake.food(), Some((0, 0)));
+        feed_at(&mut snake, (2, 2));
+        assert_eq!(snake.step(Direction::Right), GameState::Ate);
+        assert_eq!(snake.length(), 3);
+        assert_eq!(snake.food(), None);
+        assert_eq!(snake.step(Direction::Right), GameState::Running);
+        assert_eq!(snake.length(), 3);
+        assert_eq!(snake.head(), (3, 2));
+        let frame = snake.render();
+        assert!(frame.is_set(1, 2) && frame.is_set(2, 2) && frame.is_set(3, 2));
+        assert!(!frame.is_set(0, 2));
+    }
+
+    #[test]
+    fn snake_dies_at_the_wall() {
+        let mut snake = Snake::new();
+        assert_eq!(snake.step(Direction::Right), GameState::Running);
+        assert_eq!(snake.step(Direction::Right), GameState::Running);
+        // Reversing into the neck is ignored
+        assert_eq!(snake.step(Direction::Left), GameState::Running);
+        assert_eq!(snake.head(), (4, 2));
+        assert_eq!(snake.step(Direction::Right), GameState::GameOver);
+        assert!(snake.is_over());
+        // The game stays over and the snake stays put
+        assert_eq!(snake.step(Direction::Up), GameState::GameOver);
+        assert_eq!(snake.head(), (4, 2));
+    }
+
+    #[test]
+    fn snake_dies_on_its_own_body() {
+        let mut snake = Snake::new();
+        for _ in 0..3 {
+            let (x, y) = snake.head();
+            feed_at(&mut snake, (x + 1, y));
+            assert_eq!(snake.step(Direction::Right), GameState::Ate);
+        }
+        assert_eq!(snake.length(), 5);
+        assert_eq!(snake.step(Direction::Down), GameState::Running);
+        assert_eq!(snake.step(Direction::Left), GameState::Running);
+        assert_eq!(snake.step(Direction::Up), GameState::GameOver);
+
+        // Four cells can chase their own tail
+        let mut snake = Snake::new();
+        feed_at(&mut snake, (2, 2));
+        snake.step(Direction::Right);
+        feed_at(&mut snake, (3, 2));
+        snake.step(Direction::Right);
+        assert_eq!(snake.length(), 4);
+        // Food out of the way at (0, 0)
+        snake.spawn_food(0);
+        assert_eq!(snake.step(Direction::Down), GameState::Running);
+        assert_eq!(snake.step(Direction::Left), GameState::Running);
+        assert_eq!(snake.step(Direction::Up), GameState::Running);
+    }
+}
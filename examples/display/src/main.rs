@@ -23,41 +23,80 @@
 //!
 //! ## Usage
 //! 1. Flash the program to your micro:bit
-//! 2. The device will display "Hello, World!" on startup
+//! 2. The device will light each LED in turn as a self-test, play a short
+//!    jingle and display "Hello, World!" on startup
 //! 3. Press button A to show a left arrow
 //! 4. Press button B to show a right arrow
 //! 5. Watch the scrolling text and button responses
 //!
 //! ## Architecture
 //! This example is now organized into separate modules:
+//! - `analog`: SAADC readings from the edge connector pins and microphone
 //! - `board`: Hardware abstraction and peripheral initialization
 //! - `button`: Button event handling and visual feedback logic
+//! - `console`: Line-based display commands over the debug UART
+//! - `demos`: Self-contained demonstrations of individual features
 //! - `display`: LED matrix driver with graphics and animation support
 //! - `fonts`: Character bitmaps and predefined graphics
+//! - `format`: Number-to-text formatting for display output
+//! - `games`: Host-testable game logic such as a bouncing ball and snake
+//! - `motion`: LSM303AGR accelerometer and compass readings, shake detection
+//! - `packet_radio`: micro:bit-compatible group radio messaging between boards
+//! - `serial`: Frames pushed from a PC over the debug UART
+//! - `servo`: 50 Hz PWM hobby servo control on edge pins
+//! - `speaker`: PWM tones and melodies on the onboard speaker
+//! - `stopwatch`: Start/stop elapsed-time measurement shown as mm:ss
+//! - `storage`: Settings persisted to internal flash
+//! - `system`: Chip status such as the last reset reason and temperature
+//! - `touch`: Capacitive touch sensing on the front logo
 //! - `types`: Core data structures for bitmaps and frames
+//! - `widgets`: Value visualizations such as progress bars
 //!
 //! The main.rs file contains only the core application logic and imports from
 //! the modular components for better code organization and maintainability.
 
-#![no_std]
-#![no_main]
+#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(test), no_main)]
 #![warn(missing_docs)]
 #![doc(html_root_url = "https://github.com/embassy-rs/embassy")]
 
+#[cfg(not(test))]
 use embassy_executor::Spawner;
-use embassy_futures::select::{select, Either};
+#[cfg(not(test))]
 use {defmt_rtt as _, panic_probe as _};
 
 // Import the modules we created
+mod analog;
 mod board;
 mod button;
+mod console;
+mod demos;
 mod display;
 mod fonts;
+mod format;
+mod games;
+mod motion;
+mod packet_radio;
+mod serial;
+mod servo;
+mod speaker;
+mod stopwatch;
+mod storage;
+mod system;
+#[cfg(test)]
+mod test_support;
+mod touch;
 mod types;
+mod widgets;
 
 // Import the types we need from our modules
+#[cfg(not(test))]
 use board::Microbit;
-use button::{handle_button_a_press, handle_button_b_press};
+#[cfg(not(test))]
+use button::{handle_button_a_press, handle_button_b_press, wait_any, Which};
+#[cfg(not(test))]
+use speaker::{Speaker, STARTUP_JINGLE};
+#[cfg(not(test))]
 use types::Brightness;
 
 /// **Main Application Entry Point**
@@ -66,15 +105,19 @@ use types::Brightness;
 /// runs the main application loop. This function demonstrates:
 ///
 /// ## Initialization Sequence
-/// 1. **Hardware Setup**: Initialize micro:bit board with default config
+/// 1. **Hardware Setup**: Initialize micro:bit board with default config and
+///    log the device ID and reset reason
 /// 2. **Peripheral Access**: Extract display and button peripherals
 /// 3. **Display Config**: Set maximum brightness for clear visibility
-/// 4. **Welcome Message**: Show "Hello, World!" greeting with scrolling text
-/// 5. **Ready State**: Log startup completion and begin button monitoring
+/// 4. **Self-Test**: Light every LED, row and column in turn to confirm
+///    the matrix wiring
+/// 5. **Startup Jingle**: Play a short arpeggio on the speaker
+/// 6. **Welcome Message**: Show "Hello, World!" greeting with scrolling text
+/// 7. **Ready State**: Log startup completion and begin button monitoring
 ///
 /// ## Main Loop Operation
 /// The application runs an infinite loop that:
-/// - **Waits for Input**: Uses `select()` to wait for either button press
+/// - **Waits for Input**: Uses `wait_any()` to wait for either button press
 /// - **Handles Events**: Responds immediately to button A or B activation
 /// - **Shows Feedback**: Displays appropriate arrow for pressed button
 /// - **Continues**: Returns to waiting state after handling each press
@@ -101,7 +144,8 @@ use types::Brightness;
 ///
 /// # Example Interaction
 /// ```text
-/// 1. Device boots and shows "Hello, World!" scrolling
+/// 1. Device boots, runs the LED self-test, plays a jingle and shows
+///    "Hello, World!" scrolling
 /// 2. User sees "Application started, press buttons!" in debug log
 /// 3. Pressing button A shows left arrow (←) for 1 second
 /// 4. Pressing button B shows right arrow (→) for 1 second
@@ -115,23 +159,29 @@ use types::Brightness;
 /// - Interrupt-driven I/O
 /// - Power management during idle periods
 /// - Async/await coordination
+#[cfg(not(test))]
 #[embassy_executor::main]
 async fn main(_spawner: Spawner) {
     let board = Microbit::default();
+    defmt::info!("Device ID: {:016x}", Microbit::device_id());
+    defmt::info!("Reset reason: {}", Microbit::reset_reason());
 
     let mut display = board.display;
     let mut btn_a = board.btn_a;
     let mut btn_b = board.btn_b;
+    let mut speaker = Speaker::new(board.pwm0, board.speaker);
 
     display.set_brightness(Brightness::MAX);
+    display.self_test().await;
+    speaker.play_melody(&STARTUP_JINGLE).await;
     display.scroll("Hello, World!").await;
     defmt::info!("Application started, press buttons!");
     loop {
-        match select(btn_a.wait_for_low(), btn_b.wait_for_low()).await {
-            Either::First(_) => {
+        match wait_any(&mut btn_a, &mut btn_b).await {
+            Which::A => {
                 handle_button_a_press(&mut display).await;
             }
-            Either::Second(_) => {
+            Which::B => {
                 handle_button_b_press(&mut display).await;
             }
         }
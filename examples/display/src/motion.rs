@@ -0,0 +1,1194 @@
+//! # Motion Sensor Module
+//!
+//! This module drives the micro:bit v2's onboard LSM303AGR motion sensor,
+//! an accelerometer and magnetometer sharing the internal I2C bus (TWISPI0
+//! on P0_16/P0_08). It talks to the sensor registers directly, so no extra
+//! driver crate is required.
+//!
+//! ## Features
+//! - **Acceleration Readings**: X/Y/Z samples in milli-g at 100 Hz
+//! - **Shake Detection**: Async wait for a sustained, debounced shake
+//! - **Free-Fall Detection**: Async wait for the board to be dropped
+//! - **Tilt Direction**: Maps X/Y tilt to an up/down/left/right direction
+//! - **Gestures**: Classifies the board's orientation (screen up/down, logo
+//!   up/down, tilt left/right) and waits for the board to be turned into one
+//! - **Tilt Cursor**: Steers a cursor around the 5x5 grid by tilting, at a
+//!   steady rate while the tilt is held
+//! - **Compass Heading**: Magnetometer readings, hard-iron calibration and
+//!   heading in degrees
+//! - **Sample Stream**: Background task sampling at a fixed rate into a
+//!   channel, with a choice of what to drop when the reader falls behind
+//!
+//! ## Sensor Configuration
+//! - **Output Data Rate**: 100 Hz, all three axes enabled
+//! - **Resolution**: 12-bit high-resolution mode
+//! - **Full Scale**: ±2 g (1 mg per digit)
+//! - **Magnetometer**: 50 Hz continuous mode, 1.5 mG per digit
+//!
+//! ## Bus Ownership
+//! `Accelerometer` and `Magnetometer` each take the TWISPI0 peripheral, so
+//! only one of them can be in use at a time.
+//!
+//! ## Usage Example
+//! ```ignore
+//! let board = Microbit::default();
+//! let mut accel = Accelerometer::new(board.twispi0, board.i2c_int_sda, board.i2c_int_scl).await?;
+//! let sample = accel.read().await?;
+//! accel.wait_for_shake(SHAKE_THRESHOLD).await?;
+//!
+//! let mut mag = Magnetometer::new(board.twispi0, board.i2c_int_sda, board.i2c_int_scl).await?;
+//! let offsets = mag.calibrate(&mut display).await?;
+//! let heading = mag.heading(&offsets).await?;
+//! ```
+//!
+//! ## Sample Stream Usage
+//! ```ignore
+//! spawner.spawn(accel_task(accel, 50, Overflow::DropOldest)).unwrap();
+//! let stream = AccelStream::new();
+//! loop {
+//!     let sample = stream.recv().await;
+//!     scope.push((sample.magnitude() / 8).min(255) as u8);
+//! }
+//! ```
+
+use embassy_nrf::peripherals::{P0_08, P0_16, TWISPI0};
+use embassy_nrf::twim::{self, Twim};
+use embassy_sync::blocking_mutex::raw::{CriticalSectionRawMutex, RawMutex};
+use embassy_sync::channel::{Channel, Receiver, TrySendError};
+use embassy_time::{Duration, Instant, Timer};
+
+use crate::board::{Irqs, LedMatrix};
+use crate::fonts::CHECK_MARK;
+use crate::types::{Direction, Frame};
+
+/// **Accelerometer I2C Address**
+///
+/// 7-bit address of the LSM303AGR accelerometer on the internal bus.
+const ACCEL_ADDR: u8 = 0x19;
+
+/// **Control Register 1**
+///
+/// Selects the output data rate, power mode and enabled axes.
+const CTRL_REG1_A: u8 = 0x20;
+
+/// **Control Register 4**
+///
+/// Selects block data update, full scale and high-resolution mode.
+const CTRL_REG4_A: u8 = 0x23;
+
+/// **X-Axis Output Register (Low Byte)**
+///
+/// First of the six output registers, with the auto-increment bit set so a
+/// single read returns X, Y and Z.
+const OUT_X_L_A_AUTO_INC: u8 = 0x28 | 0x80;
+
+/// 100 Hz output data rate, normal power mode, X/Y/Z enabled
+const CTRL_REG1_100HZ_XYZ: u8 = 0x57;
+
+/// Block data update, ±2 g full scale, high-resolution (12-bit) mode
+const CTRL_REG4_BDU_2G_HR: u8 = 0x88;
+
+/// **Magnetometer I2C Address**
+///
+/// 7-bit address of the LSM303AGR magnetometer on the internal bus.
+const MAG_ADDR: u8 = 0x1E;
+
+/// **Magnetometer Configuration Register A**
+///
+/// Selects temperature compensation, output data rate and operating mode.
+const CFG_REG_A_M: u8 = 0x60;
+
+/// **Magnetometer Configuration Register C**
+///
+/// Selects block data update among other interface options.
+const CFG_REG_C_M: u8 = 0x62;
+
+/// **Magnetometer X-Axis Output Register (Low Byte)**
+///
+/// First of the six output registers; the magnetometer auto-increments on
+/// its own, so a single read returns X, Y and Z.
+const OUTX_L_REG_M: u8 = 0x68;
+
+/// Temperature compensation, 50 Hz output data rate, continuous mode
+const CFG_REG_A_M_50HZ_CONTINUOUS: u8 = 0x88;
+
+/// Block data update
+const CFG_REG_C_M_BDU: u8 = 0x10;
+
+/// **Sample Interval**
+///
+/// Time between samples while waiting for motion, matching the 100 Hz
+/// output data rate of the sensor.
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(10);
+
+/// **Default Shake Threshold**
+///
+/// Acceleration magnitude in milli-g that a typical hand shake exceeds.
+/// At rest the sensor reads about 1000 mg from gravity alone.
+#[allow(dead_code)]
+pub const SHAKE_THRESHOLD: i32 = 1800;
+
+/// **Default Tilt Deadzone**
+///
+/// Tilt in milli-g (roughly 17°) that must be exceeded before
+/// `tilt_direction` reports a direction, so a board resting on a slightly
+/// uneven table reads as level.
+#[allow(dead_code)]
+pub const TILT_DEADZONE: i32 = 300;
+
+/// **Minimum Shake Threshold**
+///
+/// Lower bound applied to any requested threshold so that gravity plus
+/// sensor noise on a device at rest can never be reported as a shake.
+const MIN_SHAKE_THRESHOLD: i32 = 1300;
+
+/// **Shake Sustain Samples**
+///
+/// Consecutive samples above the threshold required to report a shake,
+/// filtering out single-sample knocks.
+const SHAKE_SAMPLES: usize = 3;
+
+/// **Calm Samples**
+///
+/// Consecutive samples below the threshold required before a new shake can
+/// be detected, so one long shake is not reported repeatedly.
+const CALM_SAMPLES: usize = 20;
+
+/// **Free-Fall Threshold**
+///
+/// Acceleration magnitude in milli-g below which the board is taken to be
+/// falling. A falling board reads close to 0 mg; one at rest or being
+/// handled stays near the 1000 mg of gravity.
+const FREEFALL_THRESHOLD: i32 = 350;
+
+/// **Default Free-Fall Duration**
+///
+/// Time the magnitude must stay below `FREEFALL_THRESHOLD` to report a
+/// drop. A board dropped from 10 cm falls for about 140 ms.
+#[allow(dead_code)]
+pub const FREEFALL_DURATION: Duration = Duration::from_millis(80);
+
+/// **Minimum Free-Fall Duration**
+///
+/// Lower bound applied to any requested duration, so a single low sample,
+/// such as a quick jerk of the hand, is never reported as a drop.
+const MIN_FREEFALL_DURATION: Duration = Duration::from_millis(30);
+
+/// **Gesture Deadzone**
+///
+/// Margin in milli-g by which the gravity reading on one axis must exceed
+/// both others for `classify_gesture` to name a gesture. Orientations
+/// within about 10° of halfway between two gestures report
+/// `Gesture::Unknown` instead of flipping between them with sensor noise.
+pub const GESTURE_DEADZONE: i32 = 250;
+
+/// **Gesture Hold Samples**
+///
+/// Consecutive samples a gesture must be held before `wait_for_gesture`
+/// reports it, so swinging the board through an orientation does not count.
+const GESTURE_SAMPLES: usize = 10;
+
+/// **Magnetometer Sample Interval**
+///
+/// Time between samples during calibration, matching the 50 Hz output data
+/// rate of the magnetometer.
+const MAG_SAMPLE_INTERVAL: Duration = Duration::from_millis(20);
+
+/// **Calibration Timeout**
+///
+/// Longest time `Magnetometer::calibrate` collects samples before settling
+/// for whatever coverage it has reached.
+const CALIBRATION_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// **Minimum Calibration Span**
+///
+/// Spread in milligauss both horizontal axes must show before headings are
+/// counted towards coverage. Turning the board through a full circle spans
+/// roughly twice the local horizontal field, several hundred milligauss.
+const MIN_CALIBRATION_SPAN: i32 = 200;
+
+/// **Calibration Progress Ring**
+///
+/// The 16 border LEDs, clockwise from the top middle. Each one stands for a
+/// 22.5° slice of heading and lights once the board has pointed that way.
+#[rustfmt::skip]
+const CALIBRATION_RING: [(usize, usize); 16] = [
+    (2, 0), (3, 0), (4, 0), (4, 1),
+    (4, 2), (4, 3), (4, 4), (3, 4),
+    (2, 4), (1, 4), (0, 4), (0, 3),
+    (0, 2), (0, 1), (0, 0), (1, 0),
+];
+
+/// **Stream Queue Depth**
+///
+/// Number of samples buffered between `accel_task` and `AccelStream`, a
+/// little under 200 ms at the sensor's full 100 Hz.
+const STREAM_QUEUE_DEPTH: usize = 16;
+
+/// **Maximum Stream Rate**
+///
+/// Highest sample rate `accel_task` accepts, matching the 100 Hz output
+/// data rate of the sensor; faster polling would only repeat samples.
+pub const MAX_STREAM_RATE_HZ: u32 = 100;
+
+/// Samples published by `accel_task`, waiting to be received
+static ACCEL_SAMPLES: Channel<CriticalSectionRawMutex, Acceleration, STREAM_QUEUE_DEPTH> = Channel::new();
+
+/// **Acceleration Sample**
+///
+/// A single accelerometer reading, in milli-g per axis. With the board lying
+/// flat and face up, gravity reads roughly `z = -1000`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Acceleration {
+    /// Acceleration along the X axis (left/right) in milli-g
+    pub x: i32,
+    /// Acceleration along the Y axis (top/bottom) in milli-g
+    pub y: i32,
+    /// Acceleration along the Z axis (front/back) in milli-g
+    pub z: i32,
+}
+
+impl Acceleration {
+    /// **Squared Magnitude**
+    ///
+    /// Sum of the squared axis readings. Comparing this against a squared
+    /// threshold avoids computing a square root.
+    ///
+    /// # Returns
+    /// `x² + y² + z²` in milli-g squared
+    pub fn magnitude_squared(&self) -> i32 {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    /// **Magnitude**
+    ///
+    /// Length of the acceleration vector, rounded down. About 1000 at rest.
+    ///
+    /// # Returns
+    /// `√(x² + y² + z²)` in milli-g
+    ///
+    /// # Example
+    /// ```ignore
+    /// scope.push((sample.magnitude() / 8).min(255) as u8); // 2 g full scale
+    /// ```
+    #[allow(dead_code)]
+    pub fn magnitude(&self) -> u32 {
+        (self.magnitude_squared() as u32).isqrt()
+    }
+}
+
+/// **Magnetic Field Sample**
+///
+/// A single magnetometer reading in milligauss per axis, using the same
+/// axis directions as `Acceleration`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MagneticField {
+    /// Field along the X axis (left/right) in milligauss
+    pub x: i32,
+    /// Field along the Y axis (top/bottom) in milligauss
+    pub y: i32,
+    /// Field along the Z axis (front/back) in milligauss
+    pub z: i32,
+}
+
+/// **Magnetometer Offsets**
+///
+/// Hard-iron offsets in milligauss: the constant field added by magnetized
+/// parts on and near the board. They are subtracted from every reading so
+/// that turning the board traces a circle centered on zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MagOffsets {
+    /// Offset along the X axis in milligauss
+    pub x: i32,
+    /// Offset along the Y axis in milligauss
+    pub y: i32,
+    /// Offset along the Z axis in milligauss
+    pub z: i32,
+}
+
+impl MagOffsets {
+    /// **Offsets From Extremes**
+    ///
+    /// Computes the offsets as the midpoint of the smallest and largest
+    /// reading seen on each axis while the board was turned in every
+    /// direction.
+    ///
+    /// # Arguments
+    /// * `min` - Smallest reading seen on each axis
+    /// * `max` - Largest reading seen on each axis
+    ///
+    /// # Returns
+    /// The center of the readings on each axis
+    ///
+    /// # Example
+    /// ```ignore
+    /// let min = MagneticField { x: -300, y: -150, z: -500 };
+    /// let max = MagneticField { x: 100, y: 450, z: 500 };
+    /// assert_eq!(MagOffsets::from_extremes(&min, &max), MagOffsets { x: -100, y: 150, z: 0 });
+    /// ```
+    pub fn from_extremes(min: &MagneticField, max: &MagneticField) -> Self {
+        Self {
+            x: (min.x + max.x) / 2,
+            y: (min.y + max.y) / 2,
+            z: (min.z + max.z) / 2,
+        }
+    }
+
+    /// **Apply Offsets**
+    ///
+    /// # Arguments
+    /// * `field` - Raw reading
+    ///
+    /// # Returns
+    /// The reading with the hard-iron offsets removed
+    pub fn correct(&self, field: MagneticField) -> MagneticField {
+        MagneticField {
+            x: field.x - self.x,
+            y: field.y - self.y,
+            z: field.z - self.z,
+        }
+    }
+}
+
+/// **LSM303AGR Accelerometer Driver**
+///
+/// Owns the internal I2C bus and reads acceleration samples from the
+/// onboard motion sensor.
+pub struct Accelerometer {
+    twim: Twim<'static, TWISPI0>,
+}
+
+#[allow(dead_code)]
+impl Accelerometer {
+    /// **Create Accelerometer**
+    ///
+    /// Brings up the internal I2C bus and configures the accelerometer for
+    /// 100 Hz, ±2 g, high-resolution sampling.
+    ///
+    /// # Arguments
+    /// * `twispi0` - TWISPI0 peripheral for the internal I2C bus
+    /// * `sda` - Internal I2C data pin (P0_16)
+    /// * `scl` - Internal I2C clock pin (P0_08)
+    ///
+    /// # Returns
+    /// A ready-to-use accelerometer, or the bus error if the sensor did not respond
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut accel = Accelerometer::new(board.twispi0, board.i2c_int_sda, board.i2c_int_scl).await?;
+    /// ```
+    pub async fn new(twispi0: TWISPI0, sda: P0_16, scl: P0_08) -> Result<Self, twim::Error> {
+        let twim = Twim::new(twispi0, Irqs, sda, scl, twim::Config::default());
+        let mut accel = Self { twim };
+        accel.write_register(CTRL_REG1_A, CTRL_REG1_100HZ_XYZ).await?;
+        accel.write_register(CTRL_REG4_A, CTRL_REG4_BDU_2G_HR).await?;
+        Ok(accel)
+    }
+
+    /// **Read Acceleration**
+    ///
+    /// Reads the latest X/Y/Z sample from the sensor.
+    ///
+    /// # Returns
+    /// The acceleration in milli-g, or the bus error
+    pub async fn read(&mut self) -> Result<Acceleration, twim::Error> {
+        let mut raw = [0u8; 6];
+        self.twim
+            .write_read(ACCEL_ADDR, &[OUT_X_L_A_AUTO_INC], &mut raw)
+            .await?;
+
+        // Samples are 12-bit, left-justified in 16 bits, 1 mg per digit
+        let axis = |lo: u8, hi: u8| (i16::from_le_bytes([lo, hi]) >> 4) as i32;
+        Ok(Acceleration {
+            x: axis(raw[0], raw[1]),
+            y: axis(raw[2], raw[3]),
+            z: axis(raw[4], raw[5]),
+        })
+    }
+
+    /// **Wait for Shake**
+    ///
+    /// Samples the accelerometer at the sensor's data rate and returns once
+    /// the acceleration magnitude stays above `threshold` for a brief
+    /// sustained window. Before looking for a shake the device must first
+    /// be calm for a moment, so a single shake is never reported twice by
+    /// back-to-back calls. The task sleeps between samples.
+    ///
+    /// # Arguments
+    /// * `threshold` - Magnitude in milli-g to exceed; values too close to
+    ///   1 g are raised so gravity alone never triggers (see `SHAKE_THRESHOLD`)
+    ///
+    /// # Returns
+    /// `Ok(())` once a shake is detected, or the bus error
+    ///
+    /// # Example
+    /// ```ignore
+    /// accel.wait_for_shake(SHAKE_THRESHOLD).await?;
+    /// defmt::info!("shaken!");
+    /// ```
+    pub async fn wait_for_shake(&mut self, threshold: i32) -> Result<(), twim::Error> {
+        let threshold = threshold.max(MIN_SHAKE_THRESHOLD);
+        let threshold_squared = threshold.saturating_mul(threshold);
+
+        // Debounce: let any ongoing shake settle first
+        let mut calm = 0;
+        while calm < CALM_SAMPLES {
+            if self.read().await?.magnitude_squared() < threshold_squared {
+                calm += 1;
+            } else {
+                calm = 0;
+            }
+            Timer::after(SAMPLE_INTERVAL).await;
+        }
+
+        let mut strong = 0;
+        while strong < SHAKE_SAMPLES {
+            if self.read().await?.magnitude_squared() >= threshold_squared {
+                strong += 1;
+            } else {
+                strong = 0;
+            }
+            Timer::after(SAMPLE_INTERVAL).await;
+        }
+        Ok(())
+    }
+
+    /// **Wait for Free Fall**
+    ///
+    /// Samples the accelerometer at the sensor's data rate and returns once
+    /// the acceleration magnitude has stayed below `FREEFALL_THRESHOLD` for
+    /// `duration`, meaning the board is falling. Resting or handling the
+    /// board keeps the magnitude near 1 g and never triggers it, and a dip
+    /// that recovers before `duration` starts the timing again. The task
+    /// sleeps between samples.
+    ///
+    /// # Arguments
+    /// * `duration` - How long the fall must last, e.g. `FREEFALL_DURATION`;
+    ///   values below 30 ms are raised to 30 ms
+    ///
+    /// # Returns
+    /// `Ok(())` once a fall is detected, or the bus error
+    ///
+    /// # Example
+    /// ```ignore
+    /// accel.wait_for_freefall(FREEFALL_DURATION).await?;
+    /// defmt::info!("dropped!");
+    /// ```
+    pub async fn wait_for_freefall(&mut self, duration: Duration) -> Result<(), twim::Error> {
+        let duration = duration.max(MIN_FREEFALL_DURATION);
+        let threshold_squared = FREEFALL_THRESHOLD * FREEFALL_THRESHOLD;
+
+        let mut falling_since = None;
+        loop {
+            let now = Instant::now();
+            if self.read().await?.magnitude_squared() < threshold_squared {
+                let since = *falling_since.get_or_insert(now);
+                if now - since >= duration {
+                    return Ok(());
+                }
+            } else {
+                falling_since = None;
+            }
+            Timer::after(SAMPLE_INTERVAL).await;
+        }
+    }
+
+    /// **Wait for Gesture**
+    ///
+    /// Samples the accelerometer at the sensor's data rate and returns once
+    /// the board is turned into `gesture` and held there briefly. Like
+    /// `wait_for_shake`, it first waits for the board to be out of
+    /// `gesture`, so a board already lying that way has to be moved away and
+    /// back again; back-to-back calls never report the same placement twice.
+    /// The task sleeps between samples.
+    ///
+    /// # Arguments
+    /// * `gesture` - Orientation to wait for
+    ///
+    /// # Returns
+    /// `Ok(())` once the gesture is detected, or the bus error
+    ///
+    /// # Example
+    /// ```ignore
+    /// accel.wait_for_gesture(Gesture::ScreenDown).await?;
+    /// display.clear(); // board placed face down
+    /// ```
+    pub async fn wait_for_gesture(&mut self, gesture: Gesture) -> Result<(), twim::Error> {
+        while self.gesture().await? == gesture {
+            Timer::after(SAMPLE_INTERVAL).await;
+        }
+
+        let mut held = 0;
+        while held < GESTURE_SAMPLES {
+            Timer::after(SAMPLE_INTERVAL).await;
+            if self.gesture().await? == gesture {
+                held += 1;
+            } else {
+                held = 0;
+            }
+        }
+        Ok(())
+    }
+
+    /// Classify the current orientation
+    async fn gesture(&mut self) -> Result<Gesture, twim::Error> {
+        let a = self.read().await?;
+        Ok(classify_gesture(a.x, a.y, a.z))
+    }
+
+    /// Write a single sensor register
+    async fn write_register(&mut self, register: u8, value: u8) -> Result<(), twim::Error> {
+        self.twim.write(ACCEL_ADDR, &[register, value]).await
+    }
+}
+
+/// **Stream Overflow Policy**
+///
+/// What `accel_task` does with a new sample when `AccelStream` has fallen
+/// behind and the queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[allow(dead_code)]
+pub enum Overflow {
+    /// Discard the oldest queued sample to make room, so the reader always
+    /// sees the latest motion. Best for live plots.
+    DropOldest,
+    /// Discard the new sample, keeping the queued ones. The reader sees a
+    /// gap in the recent data instead of in the old.
+    DropNewest,
+    /// Hold the sample until there is room, pausing sampling. Nothing is
+    /// lost, but the rate is no longer kept while the reader is slow.
+    Wait,
+}
+
+/// **Accelerometer Streaming Task**
+///
+/// Samples the accelerometer at a fixed rate forever and publishes every
+/// sample for `AccelStream` to read. Spawn it once, handing over the
+/// accelerometer. Sample times are scheduled from the start of the
+/// stream, so a slow bus read delays one sample instead of the rest.
+/// Samples that fail to read are skipped.
+///
+/// # Arguments
+/// * `accel` - Accelerometer to sample
+/// * `rate_hz` - Samples per second, clamped to `1..=MAX_STREAM_RATE_HZ`
+/// * `overflow` - What to drop when the reader falls behind
+///
+/// # Example
+/// ```ignore
+/// let accel = Accelerometer::new(board.twispi0, board.i2c_int_sda, board.i2c_int_scl).await?;
+/// spawner.spawn(accel_task(accel, 50, Overflow::DropOldest)).unwrap();
+/// ```
+#[embassy_executor::task]
+#[allow(dead_code)]
+pub async fn accel_task(mut accel: Accelerometer, rate_hz: u32, overflow: Overflow) {
+    let period = Duration::from_hz(rate_hz.clamp(1, MAX_STREAM_RATE_HZ) as u64);
+    let mut next = Instant::now();
+    loop {
+        match accel.read().await {
+            Ok(sample) => publish(&ACCEL_SAMPLES, sample, overflow).await,
+            Err(e) => defmt::warn!("accelerometer read failed: {}", e),
+        }
+        next += period;
+        Timer::at(next).await;
+    }
+}
+
+/// **Publish Sample**
+///
+/// Queues `sample` on `channel`, applying `overflow` if it is full. This is
+/// the queueing step behind `accel_task`.
+///
+/// # Arguments
+/// * `channel` - Channel the samples are read from
+/// * `sample` - New sample
+/// * `overflow` - What to drop if the channel is full
+///
+/// # Example
+/// ```ignore
+/// publish(&SAMPLES, accel.read().await?, Overflow::DropOldest).await;
+/// ```
+pub async fn publish<M: RawMutex, const N: usize>(
+    channel: &Channel<M, Acceleration, N>,
+    sample: Acceleration,
+    overflow: Overflow,
+) {
+    match overflow {
+        Overflow::Wait => channel.send(sample).await,
+        Overflow::DropNewest => {
+            let _ = channel.try_send(sample);
+        }
+        Overflow::DropOldest => {
+            let mut sample = sample;
+            while let Err(TrySendError::Full(rejected)) = channel.try_send(sample) {
+                let _ = channel.try_receive();
+                sample = rejected;
+            }
+        }
+    }
+}
+
+/// **Acceleration Stream Receiver**
+///
+/// Handle for reading samples published by `accel_task`. Handles are cheap
+/// and several can exist at once; each sample is delivered to exactly one
+/// of them, whichever asks first.
+#[derive(Clone, Copy)]
+pub struct AccelStream {
+    receiver: Receiver<'static, CriticalSectionRawMutex, Acceleration, STREAM_QUEUE_DEPTH>,
+}
+
+#[allow(dead_code)]
+impl AccelStream {
+    /// **Create Receiver**
+    ///
+    /// # Example
+    /// ```ignore
+    /// let stream = AccelStream::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            receiver: ACCEL_SAMPLES.receiver(),
+        }
+    }
+
+    /// **Receive Sample**
+    ///
+    /// Waits for the next sample. Cancel-safe: if the returned future is
+    /// dropped (e.g. losing a `select`), no sample is consumed.
+    ///
+    /// # Returns
+    /// The oldest unread sample
+    ///
+    /// # Example
+    /// ```ignore
+    /// let a = stream.recv().await;
+    /// defmt::info!("{} {} {}", a.x, a.y, a.z);
+    /// ```
+    pub async fn recv(&self) -> Acceleration {
+        self.receiver.receive().await
+    }
+
+    /// **Try Receive Sample**
+    ///
+    /// # Returns
+    /// The oldest unread sample, or `None` if none is queued
+    pub fn try_recv(&self) -> Option<Acceleration> {
+        self.receiver.try_receive().ok()
+    }
+}
+
+/// **LSM303AGR Magnetometer Driver**
+///
+/// Owns the internal I2C bus and reads the magnetic field from the onboard
+/// motion sensor, turning it into a compass heading.
+pub struct Magnetometer {
+    twim: Twim<'static, TWISPI0>,
+}
+
+#[allow(dead_code)]
+impl Magnetometer {
+    /// **Create Magnetometer**
+    ///
+    /// Brings up the internal I2C bus and starts the magnetometer in 50 Hz
+    /// continuous mode.
+    ///
+    /// # Arguments
+    /// * `twispi0` - TWISPI0 peripheral for the internal I2C bus
+    /// * `sda` - Internal I2C data pin (P0_16)
+    /// * `scl` - Internal I2C clock pin (P0_08)
+    ///
+    /// # Returns
+    /// A ready-to-use magnetometer, or the bus error if the sensor did not respond
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut mag = Magnetometer::new(board.twispi0, board.i2c_int_sda, board.i2c_int_scl).await?;
+    /// ```
+    pub async fn new(twispi0: TWISPI0, sda: P0_16, scl: P0_08) -> Result<Self, twim::Error> {
+        let twim = Twim::new(twispi0, Irqs, sda, scl, twim::Config::default());
+        let mut mag = Self { twim };
+        mag.write_register(CFG_REG_A_M, CFG_REG_A_M_50HZ_CONTINUOUS).await?;
+        mag.write_register(CFG_REG_C_M, CFG_REG_C_M_BDU).await?;
+        Ok(mag)
+    }
+
+    /// **Read Magnetic Field**
+    ///
+    /// Reads the latest X/Y/Z sample from the sensor, without any offsets
+    /// applied.
+    ///
+    /// # Returns
+    /// The field in milligauss, or the bus error
+    pub async fn read(&mut self) -> Result<MagneticField, twim::Error> {
+        let mut raw = [0u8; 6];
+        self.twim.write_read(MAG_ADDR, &[OUTX_L_REG_M], &mut raw).await?;
+
+        // Samples are 16-bit two's complement, 1.5 mG per digit
+        let axis = |lo: u8, hi: u8| i16::from_le_bytes([lo, hi]) as i32 * 3 / 2;
+        Ok(MagneticField {
+            x: axis(raw[0], raw[1]),
+            y: axis(raw[2], raw[3]),
+            z: axis(raw[4], raw[5]),
+        })
+    }
+
+    /// **Read Heading**
+    ///
+    /// Reads the field, removes the hard-iron offsets and converts it to a
+    /// heading with `heading_degrees`. The board should be lying flat.
+    ///
+    /// # Arguments
+    /// * `offsets` - Offsets from `calibrate`, or `MagOffsets::default()`
+    ///   for uncalibrated readings
+    ///
+    /// # Returns
+    /// Degrees clockwise from magnetic north (0-359), or the bus error
+    ///
+    /// # Example
+    /// ```ignore
+    /// let heading = mag.heading(&offsets).await?;
+    /// display.apply(needle_frame((360 - heading) % 360)); // needle points north
+    /// ```
+    pub async fn heading(&mut self, offsets: &MagOffsets) -> Result<u16, twim::Error> {
+        let field = offsets.correct(self.read().await?);
+        Ok(heading_degrees(field.x, field.y))
+    }
+
+    /// **Calibrate Hard-Iron Offsets**
+    ///
+    /// Scrolls "TURN" and then records the smallest and largest reading on
+    /// each axis while the user turns and tilts the board. The display
+    /// border fills in as a ring: each LED lights once the board has
+    /// pointed in that direction. Calibration ends when the ring is full
+    /// or after `CALIBRATION_TIMEOUT`, whichever comes first, and finishes
+    /// by showing a check mark.
+    ///
+    /// # Arguments
+    /// * `display` - LED matrix used for prompts and progress
+    ///
+    /// # Returns
+    /// The offsets to pass to `heading`, or the bus error
+    ///
+    /// # Example
+    /// ```ignore
+    /// let offsets = mag.calibrate(&mut display).await?;
+    /// ```
+    pub async fn calibrate(&mut self, display: &mut LedMatrix) -> Result<MagOffsets, twim::Error> {
+        display.scroll("TURN").await;
+
+        let first = self.read().await?;
+        let (mut min, mut max) = (first, first);
+        let mut progress = Frame::<5, 5>::empty();
+        let mut covered = 0u16;
+        let deadline = Instant::now() + CALIBRATION_TIMEOUT;
+
+        while covered != u16::MAX && Instant::now() < deadline {
+            display.display(progress, MAG_SAMPLE_INTERVAL).await;
+
+            let field = self.read().await?;
+            min = MagneticField {
+                x: min.x.min(field.x),
+                y: min.y.min(field.y),
+                z: min.z.min(field.z),
+            };
+            max = MagneticField {
+                x: max.x.max(field.x),
+                y: max.y.max(field.y),
+                z: max.z.max(field.z),
+            };
+
+            // Until both axes have swung far enough the center is unreliable
+            if max.x - min.x < MIN_CALIBRATION_SPAN || max.y - min.y < MIN_CALIBRATION_SPAN {
+                continue;
+            }
+            let centered = MagOffsets::from_extremes(&min, &max).correct(field);
+            let slice = (heading_degrees(centered.x, centered.y) as usize * 2 + 22) / 45 % 16;
+            covered |= 1 << slice;
+            let (x, y) = CALIBRATION_RING[slice];
+            progress.set(x, y);
+        }
+
+        let offsets = MagOffsets::from_extremes(&min, &max);
+        defmt::info!("magnetometer offsets: {} {} {}", offsets.x, offsets.y, offsets.z);
+        display.display(CHECK_MARK, Duration::from_secs(1)).await;
+        Ok(offsets)
+    }
+
+    /// Write a single sensor register
+    async fn write_register(&mut self, register: u8, value: u8) -> Result<(), twim::Error> {
+        self.twim.write(MAG_ADDR, &[register, value]).await
+    }
+}
+
+/// **Field to Heading**
+///
+/// Converts the horizontal components of an offset-corrected magnetic field
+/// into a compass heading, assuming the board lies flat and face up. The
+/// heading is the direction the top edge of the board points: 0° when it
+/// faces magnetic north, 90° when it faces east.
+///
+/// # Arguments
+/// * `x` - X-axis field in milligauss
+/// * `y` - Y-axis field in milligauss
+///
+/// # Returns
+/// Degrees clockwise from magnetic north, rounded to 0-359; 0 if the
+/// field is zero
+///
+/// # Example
+/// ```ignore
+/// assert_eq!(heading_degrees(0, -300), 0); // field points out of the top edge
+/// assert_eq!(heading_degrees(-300, 0), 90);
+/// ```
+#[allow(dead_code)]
+pub fn heading_degrees(x: i32, y: i32) -> u16 {
+    // North lies along the field, so pointing the top edge (-Y) north gives
+    // a field of (0, -f); turning the board clockwise by θ swings the field
+    // round to (-f·sin θ, -f·cos θ)
+    let (east, north) = (-(x as f32), -(y as f32));
+    let (abs_east, abs_north) = (east.abs(), north.abs());
+    if abs_east == 0.0 && abs_north == 0.0 {
+        return 0;
+    }
+
+    // atan(t) for t in [0, 1], within 0.25° of the true value
+    let t = abs_east.min(abs_north) / abs_east.max(abs_north);
+    let mut angle = 45.0 * t + 15.66 * t * (1.0 - t);
+    if abs_east > abs_north {
+        angle = 90.0 - angle;
+    }
+    let angle = match (east >= 0.0, north >= 0.0) {
+        (true, true) => angle,
+        (true, false) => 180.0 - angle,
+        (false, false) => 180.0 + angle,
+        (false, true) => 360.0 - angle,
+    };
+    ((angle + 0.5) as u16) % 360
+}
+
+/// **Tilt to Direction**
+///
+/// Converts the X/Y accelerometer readings into the direction the board is
+/// tilted. The axis with the larger tilt wins, so a diagonal tilt reports
+/// whichever way it leans more; an exact diagonal favors the horizontal
+/// axis. Positive X maps to `Right` and positive Y to `Down`.
+///
+/// # Arguments
+/// * `x` - X-axis acceleration in milli-g
+/// * `y` - Y-axis acceleration in milli-g
+/// * `deadzone` - Tilt in milli-g that must be exceeded to report a direction
+///
+/// # Returns
+/// The dominant tilt direction, or `None` while the board is within the deadzone
+///
+/// # Example
+/// ```ignore
+/// let a = accel.read().await?;
+/// if let Some(direction) = tilt_direction(a.x, a.y, TILT_DEADZONE) {
+///     defmt::info!("tilted {}", direction);
+/// }
+/// ```
+#[allow(dead_code)]
+pub fn tilt_direction(x: i32, y: i32, deadzone: i32) -> Option<Direction> {
+    let (ax, ay) = (x.unsigned_abs(), y.unsigned_abs());
+    if ax.max(ay) <= deadzone.unsigned_abs() {
+        None
+    } else if ax >= ay {
+        Some(if x > 0 { Direction::Right } else { Direction::Left })
+    } else {
+        Some(if y > 0 { Direction::Down } else { Direction::Up })
+    }
+}
+
+/// **Board Gesture**
+///
+/// Orientation of the board, named after the MakeCode gestures. Each one is
+/// the side of the board facing up, or for the tilts, facing down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[allow(dead_code)]
+pub enum Gesture {
+    /// Lying flat with the LEDs facing up
+    ScreenUp,
+    /// Lying flat with the LEDs facing down
+    ScreenDown,
+    /// Standing upright with the logo edge at the top
+    LogoUp,
+    /// Standing upright with the logo edge at the bottom
+    LogoDown,
+    /// Standing on its left edge
+    TiltLeft,
+    /// Standing on its right edge
+    TiltRight,
+    /// Halfway between two gestures, or moving too hard to tell
+    Unknown,
+}
+
+/// **Classify Gesture**
+///
+/// Maps an accelerometer reading to the gesture whose axis gravity pulls
+/// along most strongly. That axis must lead the other two by
+/// `GESTURE_DEADZONE`, so a board lying slightly tilted still reads as
+/// `ScreenUp`, and one held near 45° between two gestures reads as
+/// `Unknown` instead of alternating.
+///
+/// # Arguments
+/// * `x` - X-axis acceleration in milli-g
+/// * `y` - Y-axis acceleration in milli-g
+/// * `z` - Z-axis acceleration in milli-g
+///
+/// # Returns
+/// The gesture matching the reading, or `Gesture::Unknown`
+///
+/// # Example
+/// ```ignore
+/// assert_eq!(classify_gesture(0, 0, -1000), Gesture::ScreenUp);
+/// assert_eq!(classify_gesture(0, 1000, 0), Gesture::LogoUp);
+/// assert_eq!(classify_gesture(700, 0, -700), Gesture::Unknown);
+/// ```
+#[allow(dead_code)]
+pub fn classify_gesture(x: i32, y: i32, z: i32) -> Gesture {
+    let (ax, ay, az) = (x.saturating_abs(), y.saturating_abs(), z.saturating_abs());
+    let leads = |a: i32, b: i32, c: i32| a.saturating_sub(b.max(c)) > GESTURE_DEADZONE;
+
+    // The reading points toward the ground: along -Z (out of the back of
+    // the board) when the screen faces up, +Y when the logo is up
+    if leads(az, ax, ay) {
+        if z < 0 {
+            Gesture::ScreenUp
+        } else {
+            Gesture::ScreenDown
+        }
+    } else if leads(ay, ax, az) {
+        if y > 0 {
+            Gesture::LogoUp
+        } else {
+            Gesture::LogoDown
+        }
+    } else if leads(ax, ay, az) {
+        if x < 0 {
+            Gesture::TiltLeft
+        } else {
+            Gesture::TiltRight
+        }
+    } else {
+        Gesture::Unknown
+    }
+}
+
+/// **Cursor Limit**
+///
+/// Highest row or column index a tilt cursor can reach on the 5x5 matrix.
+const CURSOR_MAX: usize = 4;
+
+/// **Move Cursor by Tilt**
+///
+/// Moves `pos` one cell in the direction reported by `tilt_direction`,
+/// stopping at the edges of the 5x5 grid. A position already outside the
+/// grid is pulled back onto it first. Each call moves at most one cell; use
+/// `TiltCursor` to limit how often that happens while a tilt is held.
+///
+/// # Arguments
+/// * `pos` - Cursor `(x, y)` position, updated in place
+/// * `ax` - X-axis acceleration in milli-g
+/// * `ay` - Y-axis acceleration in milli-g
+/// * `deadzone` - Tilt in milli-g that must be exceeded to move
+///
+/// # Returns
+/// `true` if the cursor moved
+///
+/// # Example
+/// ```ignore
+/// let a = accel.read().await?;
+/// move_cursor(&mut pos, a.x, a.y, TILT_DEADZONE);
+/// display.apply(cursor_frame(pos));
+/// ```
+#[allow(dead_code)]
+pub fn move_cursor(pos: &mut (usize, usize), ax: i32, ay: i32, deadzone: i32) -> bool {
+    let (x, y) = (pos.0.min(CURSOR_MAX), pos.1.min(CURSOR_MAX));
+    let moved = match tilt_direction(ax, ay, deadzone) {
+        Some(Direction::Up) => (x, y.saturating_sub(1)),
+        Some(Direction::Down) => (x, (y + 1).min(CURSOR_MAX)),
+        Some(Direction::Left) => (x.saturating_sub(1), y),
+        Some(Direction::Right) => ((x + 1).min(CURSOR_MAX), y),
+        None => (x, y),
+    };
+    let changed = moved != *pos;
+    *pos = moved;
+    changed
+}
+
+/// **Tilt Cursor**
+///
+/// A cursor on the 5x5 grid steered by tilting the board. Feed it every
+/// accelerometer sample: a new tilt moves it straight away, and while the
+/// tilt is held it keeps moving one cell per `interval` however fast the
+/// samples arrive. Levelling the board stops it, so the next tilt again
+/// responds immediately.
+#[derive(Debug, Clone, Copy)]
+pub struct TiltCursor {
+    pos: (usize, usize),
+    interval: Duration,
+    /// Earliest time the held tilt may move the cursor again
+    next_move: Instant,
+}
+
+#[allow(dead_code)]
+impl TiltCursor {
+    /// **Create Tilt Cursor**
+    ///
+    /// # Arguments
+    /// * `pos` - Starting `(x, y)` position, clamped to the grid
+    /// * `interval` - Time between moves while a tilt is held
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut cursor = TiltCursor::new((2, 2), Duration::from_millis(150));
+    /// ```
+    pub fn new(pos: (usize, usize), interval: Duration) -> Self {
+        Self {
+            pos: (pos.0.min(CURSOR_MAX), pos.1.min(CURSOR_MAX)),
+            interval,
+            next_move: Instant::from_ticks(0),
+        }
+    }
+
+    /// **Cursor Position**
+    ///
+    /// # Returns
+    /// The current `(x, y)` position, always within the grid
+    pub fn position(&self) -> (usize, usize) {
+        self.pos
+    }
+
+    /// **Update From Sample**
+    ///
+    /// Moves the cursor for one accelerometer sample, if a move is due.
+    ///
+    /// # Arguments
+    /// * `ax` - X-axis acceleration in milli-g
+    /// * `ay` - Y-axis acceleration in milli-g
+    /// * `deadzone` - Tilt in milli-g that must be exceeded to move
+    /// * `now` - Time of the sample, usually `Instant::now()`
+    ///
+    /// # Returns
+    /// `true` if the cursor moved
+    ///
+    /// # Example
+    /// ```ignore
+    /// let a = accel.read().await?;
+    /// if cursor.update(a.x, a.y, TILT_DEADZONE, Instant::now()) {
+    ///     display.apply(cursor_frame(cursor.position()));
+    /// }
+    /// ```
+    pub fn update(&mut self, ax: i32, ay: i32, deadzone: i32, now: Instant) -> bool {
+        if tilt_direction(ax, ay, deadzone).is_none() {
+            self.next_move = now;
+            return false;
+        }
+        if now < self.next_move {
+            return false;
+        }
+        let moved = move_cursor(&mut self.pos, ax, ay, deadzone);
+        if moved {
+            self.next_move = now + self.interval;
+        }
+        moved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tilt_maps_to_the_dominant_axis() {
+        // Inside the deadzone, including its edge
+        assert_eq!(tilt_direction(0, 0, 300), None);
+        assert_eq!(tilt_direction(300, -300, 300), None);
+        assert_eq!(tilt_direction(500, 100, 300), Some(Direction::Right));
+        assert_eq!(tilt_direction(-500, 100, 300), Some(Direction::Left));
+        assert_eq!(tilt_direction(100, 600, 300), Some(Direction::Down));
+        assert_eq!(tilt_direction(100, -600, 300), Some(Direction::Up));
+        // An exact diagonal picks the horizontal axis
+        assert_eq!(tilt_direction(-500, 500, 300), Some(Direction::Left));
+        assert_eq!(tilt_direction(i32::MIN, 0, 0), Some(Direction::Left));
+    }
+
+    #[test]
+    fn calibration_centers_the_readings() {
+        // Board turned to north, east, south and west inside a hard-iron
+        // offset of (120, -80, 30); the field is (-f·sin θ, -f·cos θ)
+        let center = MagneticField { x: 120, y: -80, z: 30 };
+        let readings =
+            [(0, -400, 300), (-400, 0, -300), (0, 400, 300), (400, 0, -300)].map(|(x, y, z)| MagneticField {
+                x: center.x + x,
+                y: center.y + y,
+                z: center.z + z,
+            });
+        let min = readings.iter().fold(readings[0], |min, f| MagneticField {
+            x: min.x.min(f.x),
+            y: min.y.min(f.y),
+            z: min.z.min(f.z),
+        });
+        let max = readings.iter().fold(readings[0], |max, f| MagneticField {
+            x: max.x.max(f.x),
+            y: max.y.max(f.y),
+            z: max.z.max(f.z),
+        });
+        let offsets = MagOffsets::from_extremes(&min, &max);
+        assert_eq!(offsets, MagOffsets { x: 120, y: -80, z: 30 });
+        // Corrected readings give the headings the board was turned to
+        for (reading, expected) in readings.iter().zip([0, 90, 180, 270]) {
+            let field = offsets.correct(*reading);
+            assert_eq!(heading_degrees(field.x, field.y), expected);
+        }
+    }
+
+    #[test]
+    fn cursor_clamps_at_every_corner() {
+        const TILT: i32 = 1000;
+        // Each corner, pushed against both of its walls
+        let pushes = [
+            ((0, 0), -TILT, 0),
+            ((0, 0), 0, -TILT),
+            ((4, 0), TILT, 0),
+            ((4, 0), 0, -TILT),
+            ((0, 4), -TILT, 0),
+            ((0, 4), 0, TILT),
+            ((4, 4), TILT, 0),
+            ((4, 4), 0, TILT),
+        ];
+        for (corner, ax, ay) in pushes {
+            let mut pos = corner;
+            for _ in 0..10 {
+                assert!(!move_cursor(&mut pos, ax, ay, 300));
+            }
+            assert_eq!(pos, corner);
+        }
+        // Out-of-range positions are pulled back onto the grid
+        let mut pos = (9, 7);
+        assert!(move_cursor(&mut pos, 0, 0, 300));
+        assert_eq!(pos, (4, 4));
+    }
+
+    #[test]
+    fn orientations_map_to_gestures() {
+        assert_eq!(classify_gesture(0, 0, -1000), Gesture::ScreenUp);
+        assert_eq!(classify_gesture(150, -200, -950), Gesture::ScreenUp);
+        assert_eq!(classify_gesture(0, 0, 1000), Gesture::ScreenDown);
+        assert_eq!(classify_gesture(30, 1000, 40), Gesture::LogoUp);
+        assert_eq!(classify_gesture(0, -1000, 0), Gesture::LogoDown);
+        assert_eq!(classify_gesture(-1000, 0, 0), Gesture::TiltLeft);
+        assert_eq!(classify_gesture(980, 0, -100), Gesture::TiltRight);
+        // No axis clearly dominates
+        assert_eq!(classify_gesture(700, 0, -700), Gesture::Unknown);
+        assert_eq!(classify_gesture(0, 600, -750), Gesture::Unknown);
+        assert_eq!(classify_gesture(0, 0, 0), Gesture::Unknown);
+        assert_eq!(classify_gesture(i32::MIN, 0, 0), Gesture::TiltLeft);
+    }
+}
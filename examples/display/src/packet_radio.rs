@@ -0,0 +1,339 @@
+//! # Packet Radio Module
+//!
+//! This module sends and receives datagrams in the micro:bit radio format,
+//! the one used by the CODAL runtime and MicroPython, so a board running
+//! this firmware can message other micro:bits. Boards only hear packets
+//! sent to their own *group*, so several classrooms of devices can share
+//! the air.
+//!
+//! ## Features
+//! - **Groups**: 8-bit group number used as the radio address prefix
+//! - **Channels**: Configurable frequency channel, 0-83 (2400-2483 MHz)
+//! - **Small Payloads**: Up to `MAX_PAYLOAD` bytes per message
+//!
+//! ## Air Interface
+//! - **Mode**: Nordic proprietary 1 Mbit/s
+//! - **Address**: Base address `0x75626974` ("ubit") with the group as the
+//!   prefix byte, so the hardware drops other groups' packets
+//! - **Integrity**: 16-bit CRC (polynomial `0x11021`, initial `0xFFFF`)
+//!   over address and packet, data whitening with initial value `0x18`
+//!
+//! ## Packet Format
+//! ```text
+//! +--------+---------+-------+----------+-------------------------+
+//! | length | version | group | protocol | payload (0-29 bytes)    |
+//! +--------+---------+-------+----------+-------------------------+
+//! ```
+//! `length` counts the bytes after itself. `version` is always 1 and
+//! `protocol` is 1 (datagram). Receivers filter on the address prefix, not
+//! on the `group` byte. The radio hardware appends and checks the CRC.
+//!
+//! ## Compatibility
+//! Boards hear each other when they use the same group and channel. The
+//! defaults match MicroPython's `radio` module and CODAL's
+//! `uBit.radio.datagram`: group 0 and channel `DEFAULT_CHANNEL`. MakeCode
+//! radio blocks send datagrams too, with their own layout inside the
+//! payload, which `receive` hands over as raw bytes.
+//!
+//! ## Clock Requirement
+//! The radio needs the external high-frequency crystal. Create the board
+//! with `HfclkSource::ExternalXtal`:
+//! ```ignore
+//! let mut config = embassy_nrf::config::Config::default();
+//! config.hfclk_source = embassy_nrf::config::HfclkSource::ExternalXtal;
+//! let board = Microbit::new(config);
+//! let mut radio = Radio::new(board.radio, 1, DEFAULT_CHANNEL);
+//! radio.send(1, b"hi").await?;
+//! ```
+
+use embassy_nrf::pac;
+use embassy_nrf::pac::radio::vals::{Crcstatus, Endian, Len, Mode, Skipaddr};
+use embassy_nrf::peripherals::RADIO;
+use embassy_nrf::radio::ble;
+
+use crate::board::Irqs;
+
+/// **Maximum Length Field**
+///
+/// Largest `length` byte micro:bits accept, the radio's `MAXLEN` setting.
+const MAX_LENGTH: usize = 32;
+
+/// **Header Length**
+///
+/// Length, version, group and protocol bytes.
+const HEADER_LEN: usize = 4;
+
+/// **Maximum Payload Size**
+///
+/// Largest message, in bytes, that fits in one packet.
+pub const MAX_PAYLOAD: usize = MAX_LENGTH - (HEADER_LEN - 1);
+
+/// **Frame Buffer Length**
+///
+/// Length byte plus the longest packet that can follow it.
+const FRAME_LEN: usize = 1 + MAX_LENGTH;
+
+/// **Default Channel**
+///
+/// Channel 7 (2407 MHz), the micro:bit runtime's default.
+#[allow(dead_code)]
+pub const DEFAULT_CHANNEL: u8 = 7;
+
+/// **Highest Channel**
+///
+/// Channel 83 is 2483 MHz, the top of the 2.4 GHz band.
+pub const MAX_CHANNEL: u8 = 83;
+
+/// **Base Address**
+///
+/// Shared by every micro:bit; the group is the prefix in front of it.
+const BASE_ADDRESS: u32 = 0x7562_6974;
+
+/// **Protocol Version**
+const VERSION: u8 = 1;
+
+/// **Datagram Protocol Number**
+const PROTOCOL_DATAGRAM: u8 = 1;
+
+/// **Radio Errors**
+///
+/// Failures reported when sending a packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RadioError {
+    /// The payload is longer than `MAX_PAYLOAD`
+    PayloadTooLong,
+    /// The radio failed to transmit
+    Transmit,
+}
+
+/// **Encode Datagram**
+///
+/// Builds the packet that carries `data` to `group`.
+///
+/// # Arguments
+/// * `group` - Destination group, written to the header
+/// * `data` - Payload, at most `MAX_PAYLOAD` bytes
+///
+/// # Returns
+/// The packet, length byte first, or `RadioError::PayloadTooLong`
+fn encode(group: u8, data: &[u8]) -> Result<[u8; FRAME_LEN], RadioError> {
+    if data.len() > MAX_PAYLOAD {
+        return Err(RadioError::PayloadTooLong);
+    }
+
+    let mut frame = [0u8; FRAME_LEN];
+    frame[0] = (HEADER_LEN - 1 + data.len()) as u8;
+    frame[1] = VERSION;
+    frame[2] = group;
+    frame[3] = PROTOCOL_DATAGRAM;
+    frame[HEADER_LEN..HEADER_LEN + data.len()].copy_from_slice(data);
+    Ok(frame)
+}
+
+/// **Decode Datagram**
+///
+/// # Arguments
+/// * `frame` - Received packet, length byte first
+///
+/// # Returns
+/// The payload, or `None` if `frame` is not a well-formed datagram
+fn decode(frame: &[u8]) -> Option<&[u8]> {
+    let length = usize::from(*frame.first()?);
+    if !(HEADER_LEN - 1..=MAX_LENGTH).contains(&length) || frame.len() <= length {
+        return None;
+    }
+    if frame[1] != VERSION || frame[3] != PROTOCOL_DATAGRAM {
+        return None;
+    }
+    Some(&frame[HEADER_LEN..=length])
+}
+
+/// **Group Messaging Radio**
+///
+/// Thin wrapper over the nRF radio driver that sets up the micro:bit air
+/// interface and frames datagrams with a fixed maximum payload.
+pub struct Radio {
+    radio: ble::Radio<'static, RADIO>,
+    group: u8,
+}
+
+#[allow(dead_code)]
+impl Radio {
+    /// **Create Radio**
+    ///
+    /// Initializes the radio on the given channel, listening to `group`.
+    ///
+    /// # Arguments
+    /// * `radio` - RADIO peripheral (`board.radio`)
+    /// * `group` - Group whose packets `receive` returns
+    /// * `channel` - Frequency channel, 0-83 (higher values are clamped)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut radio = Radio::new(board.radio, 1, DEFAULT_CHANNEL);
+    /// ```
+    pub fn new(radio: RADIO, group: u8, channel: u8) -> Self {
+        let mut radio = ble::Radio::new(radio, Irqs);
+
+        // The driver has no setters for the micro:bit mode, packet layout
+        // and address, so those are written to the registers directly
+        let r = pac::RADIO;
+        r.mode().write(|w| w.set_mode(Mode::NRF_1MBIT));
+        // 8-bit length field, no S0/S1 fields, 8-bit preamble
+        r.pcnf0().write(|w| w.set_lflen(8));
+        r.pcnf1().write(|w| {
+            w.set_maxlen(MAX_LENGTH as u8);
+            w.set_statlen(0);
+            w.set_balen(4);
+            w.set_endian(Endian::LITTLE);
+            w.set_whiteen(true);
+        });
+        r.crccnf().write(|w| {
+            w.set_len(Len::TWO);
+            w.set_skipaddr(Skipaddr::INCLUDE);
+        });
+        r.base0().write_value(BASE_ADDRESS);
+        r.txaddress().write(|w| w.set_txaddress(0));
+        r.rxaddresses().write(|w| w.set_addr0(true));
+        radio.set_crc_init(0xFFFF);
+        radio.set_crc_poly(0x11021);
+        radio.set_whitening_init(0x18);
+
+        let mut radio = Self { radio, group };
+        radio.set_channel(channel);
+        radio
+    }
+
+    /// **Set Channel**
+    ///
+    /// # Arguments
+    /// * `channel` - Frequency channel, 0-83 (higher values are clamped)
+    pub fn set_channel(&mut self, channel: u8) {
+        self.radio.set_frequency(2400 + u32::from(channel.min(MAX_CHANNEL)));
+    }
+
+    /// **Set Receive Group**
+    ///
+    /// # Arguments
+    /// * `group` - Group whose packets `receive` returns
+    pub fn set_group(&mut self, group: u8) {
+        self.group = group;
+    }
+
+    /// **Receive Group**
+    ///
+    /// # Returns
+    /// The group whose packets `receive` returns
+    pub fn group(&self) -> u8 {
+        self.group
+    }
+
+    /// **Send Message**
+    ///
+    /// Sends `data` to every board listening to `group` on this channel.
+    ///
+    /// # Arguments
+    /// * `group` - Destination group
+    /// * `data` - Payload, at most `MAX_PAYLOAD` bytes
+    ///
+    /// # Returns
+    /// `Ok(())` once the packet is on the air, or the reason it was not sent
+    ///
+    /// # Example
+    /// ```ignore
+    /// radio.send(1, &[42]).await?;
+    /// ```
+    pub async fn send(&mut self, group: u8, data: &[u8]) -> Result<(), RadioError> {
+        let frame = encode(group, data)?;
+        Self::set_address_prefix(group);
+        self.radio.transmit(&frame).await.map_err(|_| RadioError::Transmit)
+    }
+
+    /// **Receive Message**
+    ///
+    /// Waits for the next valid datagram addressed to this radio's group
+    /// and copies its payload into `buf`. Packets that fail the CRC and
+    /// other protocols' packets are skipped.
+    ///
+    /// # Arguments
+    /// * `buf` - Destination for the payload; longer payloads are truncated
+    ///
+    /// # Returns
+    /// The number of payload bytes copied into `buf`
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut buf = [0u8; MAX_PAYLOAD];
+    /// let len = radio.receive(&mut buf).await;
+    /// ```
+    pub async fn receive(&mut self, buf: &mut [u8]) -> usize {
+        let mut frame = [0u8; FRAME_LEN];
+        loop {
+            // A send may have left another group's prefix behind
+            Self::set_address_prefix(self.group);
+            if self.radio.receive(&mut frame).await.is_err() {
+                continue;
+            }
+            if pac::RADIO.crcstatus().read().crcstatus() != Crcstatus::CRCOK {
+                continue;
+            }
+            let Some(payload) = decode(&frame) else {
+                continue;
+            };
+
+            let len = payload.len().min(buf.len());
+            buf[..len].copy_from_slice(&payload[..len]);
+            return len;
+        }
+    }
+
+    /// Points the radio's address at `group`; only valid while it is idle
+    fn set_address_prefix(group: u8) {
+        pac::RADIO.prefix0().write(|w| w.set_ap0(group));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_the_micro_bit_datagram_header() {
+        let frame = encode(7, b"hi").unwrap();
+        assert_eq!(frame[..6], [5, 1, 7, 1, b'h', b'i']);
+        assert!(frame[6..].iter().all(|&byte| byte == 0));
+        // What MicroPython's `radio.send_bytes(b"A")` puts on the air
+        assert_eq!(encode(0, b"A").unwrap()[..5], [4, 1, 0, 1, b'A']);
+    }
+
+    #[test]
+    fn decode_returns_the_encoded_payload() {
+        let longest = [0x5A; MAX_PAYLOAD];
+        for data in [&b""[..], b"x", b"hello", &longest] {
+            assert_eq!(decode(&encode(3, data).unwrap()), Some(data));
+        }
+        assert_eq!(encode(3, &[0; MAX_PAYLOAD + 1]), Err(RadioError::PayloadTooLong));
+        assert_eq!(usize::from(encode(3, &longest).unwrap()[0]), MAX_LENGTH);
+    }
+
+    #[test]
+    fn decode_rejects_malformed_packets() {
+        let good = encode(0, b"ok").unwrap();
+        assert_eq!(decode(&good), Some(&b"ok"[..]));
+        let mut wrong_version = good;
+        wrong_version[1] = 2;
+        let mut wrong_protocol = good;
+        wrong_protocol[3] = 2;
+        let mut too_short = good;
+        too_short[0] = 2;
+        let mut too_long = good;
+        too_long[0] = MAX_LENGTH as u8 + 1;
+        for frame in [wrong_version, wrong_protocol, too_short, too_long] {
+            assert_eq!(decode(&frame), None);
+        }
+        // A length that runs past the end of what was received
+        assert_eq!(decode(&good[..5]), None);
+        assert_eq!(decode(&[]), None);
+    }
+}
@@ -0,0 +1,266 @@
+//! # Serial Frame Module
+//!
+//! This module receives 5x5 frames pushed from a PC over the micro:bit's
+//! internal debug UART, so patterns can be designed on the host and shown
+//! on the display live. The interface chip bridges that UART to the USB
+//! serial port (115200 baud, 8N1).
+//!
+//! ## Features
+//! - **Framed Packets**: A start byte, five row bytes and a checksum
+//! - **Resync**: Corrupted or truncated packets are skipped and the parser
+//!   locks back on at the next start byte, never waiting on a bad packet
+//! - **Host-Testable Parsing**: `PacketParser` is fed one byte at a time
+//!   and needs no hardware
+//! - **Buffered Reception**: Bytes keep arriving in a ring buffer while the
+//!   display refreshes, so back-to-back packets are not lost
+//!
+//! ## Packet Format
+//! | Byte | Value                                                 |
+//! |------|-------------------------------------------------------|
+//! | 0    | `START_BYTE` (`0xA5`)                                 |
+//! | 1-5  | Rows 0-4, top first, leftmost pixel in bit 7          |
+//! | 6    | Checksum: wrapping 8-bit sum of bytes 1-5             |
+//!
+//! Rows use the `Frame::from_bytes` layout, so a row byte only uses bits
+//! 7-3 (`0b10100000` lights columns 0 and 2); a packet with any of bits 2-0
+//! set is rejected as corrupt. For example, a centered dot is
+//! `A5 00 00 20 00 00 20`.
+//!
+//! ## Bus Ownership
+//! `FrameReceiver` and `console::Console` each take the `DebugUart`, so only
+//! one of them can be in use at a time.
+//!
+//! ## Usage Example
+//! ```ignore
+//! // `uart` is a `board::DebugUart` holding the debug UART peripherals
+//! let (mut rx_buffer, mut tx_buffer) = ([0u8; 64], [0u8; 16]);
+//! let mut receiver = FrameReceiver::new(uart, &mut rx_buffer, &mut tx_buffer);
+//! let mut frame = receiver.next_frame().await;
+//! loop {
+//!     frame = display.display_while(frame, receiver.next_frame()).await;
+//! }
+//! ```
+
+use embassy_nrf::buffered_uarte::BufferedUarte;
+use embassy_nrf::peripherals::{TIMER0, UARTE0};
+use embassy_time::{with_timeout, Duration};
+
+use crate::board::DebugUart;
+use crate::types::Frame;
+
+/// **Packet Start Byte**
+///
+/// Marks the beginning of every packet.
+pub const START_BYTE: u8 = 0xA5;
+
+/// **Packet Length**
+///
+/// Start byte, five row bytes and the checksum.
+pub const PACKET_LEN: usize = 7;
+
+/// **Row Padding Mask**
+///
+/// Low bits of a row byte that lie outside a 5-pixel row and must be clear.
+const PADDING_MASK: u8 = 0b0000_0111;
+
+/// **Inter-Byte Timeout**
+///
+/// Longest silence allowed inside a packet. At 115200 baud a whole packet
+/// takes well under a millisecond, so a longer gap means the sender gave
+/// up part way and the partial packet is dropped.
+const BYTE_TIMEOUT: Duration = Duration::from_millis(20);
+
+/// **Packet Checksum**
+///
+/// # Arguments
+/// * `rows` - The five row bytes of a packet
+///
+/// # Returns
+/// The wrapping 8-bit sum of `rows`, as sent in the last packet byte
+///
+/// # Example
+/// ```ignore
+/// assert_eq!(checksum(&[0x00, 0x00, 0x20, 0x00, 0x00]), 0x20);
+/// ```
+pub fn checksum(rows: &[u8; 5]) -> u8 {
+    rows.iter().fold(0, |sum, &row| sum.wrapping_add(row))
+}
+
+/// **Packet Parser**
+///
+/// Assembles frames from the incoming byte stream one byte at a time.
+/// Bytes before a start byte are ignored. A completed packet that fails the
+/// checksum or padding check is discarded, and parsing resumes from the
+/// next start byte inside it, if any, so a packet that followed a
+/// truncated one is still found.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PacketParser {
+    /// Bytes of the packet being assembled, starting with `START_BYTE`
+    buffer: [u8; PACKET_LEN],
+    /// Number of valid bytes in `buffer`
+    len: usize,
+}
+
+#[allow(dead_code)]
+impl PacketParser {
+    /// **Create Parser**
+    ///
+    /// # Returns
+    /// A parser waiting for a start byte
+    pub const fn new() -> Self {
+        Self {
+            buffer: [0; PACKET_LEN],
+            len: 0,
+        }
+    }
+
+    /// **Feed Byte**
+    ///
+    /// Adds the next received byte.
+    ///
+    /// # Arguments
+    /// * `byte` - Byte read from the UART
+    ///
+    /// # Returns
+    /// The decoded frame if `byte` completed a valid packet, else `None`
+    ///
+    /// # Example
+    /// ```ignore
+    /// for &byte in &[0xA5, 0x00, 0x00, 0x20, 0x00, 0x00, 0x20] {
+    ///     if let Some(frame) = parser.push(byte) {
+    ///         display.apply(frame);
+    ///     }
+    /// }
+    /// ```
+    pub fn push(&mut self, byte: u8) -> Option<Frame<5, 5>> {
+        if self.len == 0 && byte != START_BYTE {
+            return None;
+        }
+        self.buffer[self.len] = byte;
+        self.len += 1;
+        if self.len < PACKET_LEN {
+            return None;
+        }
+
+        let mut rows = [0; 5];
+        rows.copy_from_slice(&self.buffer[1..PACKET_LEN - 1]);
+        let valid = checksum(&rows) == self.buffer[PACKET_LEN - 1] && rows.iter().all(|row| row & PADDING_MASK == 0);
+        if valid {
+            self.len = 0;
+            return Some(Frame::from_bytes(&rows));
+        }
+
+        // Resync: restart from the next start byte after the rejected one
+        match self.buffer[1..].iter().position(|&b| b == START_BYTE) {
+            Some(i) => {
+                self.buffer.copy_within(i + 1.., 0);
+                self.len = PACKET_LEN - (i + 1);
+            }
+            None => self.len = 0,
+        }
+        None
+    }
+
+    /// **Reset Parser**
+    ///
+    /// Drops any partial packet and waits for the next start byte.
+    pub fn reset(&mut self) {
+        self.len = 0;
+    }
+
+    /// **Packet In Progress**
+    ///
+    /// # Returns
+    /// `true` if a start byte has been seen and the packet is incomplete
+    pub fn in_packet(&self) -> bool {
+        self.len > 0
+    }
+}
+
+/// **Serial Frame Receiver**
+///
+/// Reads packets from the debug UART and decodes them into frames.
+pub struct FrameReceiver<'d> {
+    uarte: BufferedUarte<'d, UARTE0, TIMER0>,
+    parser: PacketParser,
+}
+
+#[allow(dead_code)]
+impl<'d> FrameReceiver<'d> {
+    /// **Create Receiver**
+    ///
+    /// Starts the debug UART at 115200 baud, 8N1. Reception runs in the
+    /// background from here on, so packets sent while the caller is busy
+    /// refreshing the display wait in `rx_buffer` until `next_frame`.
+    ///
+    /// # Arguments
+    /// * `uart` - Debug UART peripherals
+    /// * `rx_buffer` - Ring buffer for received bytes; its length must be
+    ///   even, and a few packets long is plenty
+    /// * `tx_buffer` - Transmit ring buffer, unused by the receiver
+    ///
+    /// # Example
+    /// ```ignore
+    /// let (mut rx_buffer, mut tx_buffer) = ([0u8; 64], [0u8; 16]);
+    /// let mut receiver = FrameReceiver::new(uart, &mut rx_buffer, &mut tx_buffer);
+    /// ```
+    pub fn new(uart: DebugUart, rx_buffer: &'d mut [u8], tx_buffer: &'d mut [u8]) -> Self {
+        Self {
+            uarte: uart.into_buffered(rx_buffer, tx_buffer),
+            parser: PacketParser::new(),
+        }
+    }
+
+    /// **Next Frame**
+    ///
+    /// Reads bytes until a valid packet arrives, skipping malformed ones,
+    /// and returns as soon as its last byte is in. Bytes after that packet
+    /// stay buffered for the next call. A packet interrupted by more than
+    /// `BYTE_TIMEOUT` of silence is dropped. Dropping the future loses no
+    /// bytes, since they stay in the receive buffer.
+    ///
+    /// # Returns
+    /// The frame carried by the next valid packet
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.apply(receiver.next_frame().await);
+    /// ```
+    pub async fn next_frame(&mut self) -> Frame<5, 5> {
+        loop {
+            let read = if self.parser.in_packet() {
+                match with_timeout(BYTE_TIMEOUT, self.uarte.fill_buf()).await {
+                    Ok(read) => read,
+                    Err(_) => {
+                        self.parser.reset();
+                        continue;
+                    }
+                }
+            } else {
+                self.uarte.fill_buf().await
+            };
+
+            let bytes = match read {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    defmt::warn!("serial frame read error: {}", e);
+                    self.parser.reset();
+                    continue;
+                }
+            };
+            let mut used = bytes.len();
+            let mut frame = None;
+            for (i, &byte) in bytes.iter().enumerate() {
+                frame = self.parser.push(byte);
+                if frame.is_some() {
+                    used = i + 1;
+                    break;
+                }
+            }
+            self.uarte.consume(used);
+            if let Some(frame) = frame {
+                return frame;
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! # Servo Module
+//!
+//! This module drives a standard hobby servo (such as an SG90) from an edge
+//! connector pin using one of the nRF52833's PWM peripherals.
+//!
+//! ## Signal Timing
+//! - **Frame Rate**: 50 Hz (20 ms period)
+//! - **Pulse Width**: 1.0 ms at 0° through 1.5 ms at 90° to 2.0 ms at 180°
+//! - **Resolution**: 8 μs per step (125 kHz PWM clock)
+//!
+//! ## Wiring
+//! Connect the servo signal wire to an edge pin (e.g. P0), and power it from
+//! an external 5 V supply sharing ground with the micro:bit; the board's 3 V
+//! rail cannot supply servo stall current.
+//!
+//! ## Usage Example
+//! ```ignore
+//! let board = Microbit::default();
+//! let mut servo = Servo::new(board.pwm1, board.p0);
+//! servo.set_angle(90); // neutral
+//! ```
+
+use embassy_nrf::gpio::Pin;
+use embassy_nrf::pwm::{Instance, Prescaler, SimplePwm};
+use embassy_nrf::Peripheral;
+
+/// **PWM Period**
+///
+/// 20 ms frame at the 125 kHz PWM clock (8 μs per tick).
+const PERIOD_TICKS: u16 = 2500;
+
+/// **PWM Tick Length**
+///
+/// Duration of one PWM clock tick in microseconds (16 MHz / 128).
+const TICK_US: u32 = 8;
+
+/// **Minimum Pulse Width**
+///
+/// Pulse width in microseconds for 0°.
+const MIN_PULSE_US: u32 = 1000;
+
+/// **Maximum Pulse Width**
+///
+/// Pulse width in microseconds for 180°.
+const MAX_PULSE_US: u32 = 2000;
+
+/// **Maximum Angle**
+///
+/// Largest supported servo angle in degrees.
+pub const MAX_ANGLE: u8 = 180;
+
+/// **Hobby Servo Driver**
+///
+/// Generates a 50 Hz pulse train whose width sets the servo position.
+///
+/// ## Type Parameters
+/// - `T`: PWM peripheral instance (PWM0-PWM3)
+pub struct Servo<T: Instance> {
+    pwm: SimplePwm<'static, T>,
+    angle: u8,
+}
+
+#[allow(dead_code)]
+impl<T: Instance> Servo<T> {
+    /// **Create Servo**
+    ///
+    /// Configures the PWM peripheral for 50 Hz output on `pin` and moves the
+    /// servo to its neutral (90°) position.
+    ///
+    /// # Arguments
+    /// * `pwm` - PWM peripheral to use (e.g. `board.pwm1`)
+    /// * `pin` - Edge connector pin wired to the servo signal line
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut servo = Servo::new(board.pwm1, board.p0);
+    /// ```
+    pub fn new(pwm: impl Peripheral<P = T> + 'static, pin: impl Peripheral<P = impl Pin> + 'static) -> Self {
+        let pwm = SimplePwm::new_1ch(pwm, pin);
+        pwm.set_prescaler(Prescaler::Div128);
+        pwm.set_max_duty(PERIOD_TICKS);
+
+        let mut servo = Self { pwm, angle: 0 };
+        servo.set_angle(MAX_ANGLE / 2);
+        servo
+    }
+
+    /// **Set Angle**
+    ///
+    /// Moves the servo to the given angle. 90° gives the 1.5 ms neutral pulse.
+    ///
+    /// # Arguments
+    /// * `degrees` - Target angle (values above 180 are clamped)
+    ///
+    /// # Example
+    /// ```ignore
+    /// servo.set_angle(0);   // full left
+    /// servo.set_angle(180); // full right
+    /// ```
+    pub fn set_angle(&mut self, degrees: u8) {
+        self.angle = degrees.min(MAX_ANGLE);
+        let pulse_us = MIN_PULSE_US + (MAX_PULSE_US - MIN_PULSE_US) * self.angle as u32 / MAX_ANGLE as u32;
+        let ticks = ((pulse_us + TICK_US / 2) / TICK_US) as u16;
+        // The output stays low until the counter reaches the compare value,
+        // so the high pulse is the remainder of the period
+        self.pwm.set_duty(0, PERIOD_TICKS - ticks);
+    }
+
+    /// **Current Angle**
+    ///
+    /// # Returns
+    /// The last angle set, in degrees
+    pub fn angle(&self) -> u8 {
+        self.angle
+    }
+}
@@ -0,0 +1,229 @@
+//! # Speaker Module
+//!
+//! This module drives the micro:bit v2's onboard speaker (P0_00) with a
+//! square wave generated by the PWM0 peripheral. Tones keep playing in
+//! hardware while the task sleeps, so sound never stalls the executor.
+//!
+//! ## Features
+//! - **Tones**: Play a frequency for a fixed duration
+//! - **Melodies**: Sequence `Note`s with rests and short separating gaps
+//! - **Sweeps**: Smooth linear frequency ramps for sirens and alarms
+//!
+//! ## Frequency Range
+//! The PWM counter runs at 1 MHz with a 15-bit period, so tones between
+//! 31 Hz and 20 kHz are supported. Requests outside this range are clamped.
+//!
+//! ## Usage Example
+//! ```ignore
+//! let board = Microbit::default();
+//! let mut speaker = Speaker::new(board.pwm0, board.speaker);
+//! speaker.tone(440, Duration::from_millis(500)).await;
+//! speaker.play_melody(&STARTUP_JINGLE).await;
+//! ```
+
+use embassy_nrf::peripherals::{P0_00, PWM0};
+use embassy_nrf::pwm::{Prescaler, SimplePwm};
+use embassy_time::{Duration, Instant, Timer};
+
+/// **PWM Counter Clock**
+///
+/// Frequency of the PWM counter in Hz (16 MHz divided by 16).
+const PWM_CLOCK_HZ: u32 = 1_000_000;
+
+/// **Lowest Supported Frequency**
+///
+/// Limited by the 15-bit PWM period at the 1 MHz counter clock.
+const MIN_FREQ_HZ: u32 = 31;
+
+/// **Highest Supported Frequency**
+///
+/// Upper end of human hearing; higher tones are clamped to this.
+const MAX_FREQ_HZ: u32 = 20_000;
+
+/// **Inter-Note Gap**
+///
+/// Silence inserted at the end of every melody note. Long enough to
+/// separate repeated identical notes, short enough not to sound staccato.
+const NOTE_GAP: Duration = Duration::from_millis(20);
+
+/// **Sweep Step Interval**
+///
+/// How often the frequency is updated during a sweep. At 200 updates per
+/// second the individual steps blend into a continuous glide.
+const SWEEP_STEP: Duration = Duration::from_millis(5);
+
+/// **Melody Note**
+///
+/// A single note of a melody: a frequency held for a duration. A frequency
+/// of 0 is a rest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Note {
+    /// Tone frequency in Hz, or 0 for a rest
+    pub freq_hz: u16,
+    /// Note length in milliseconds, including the inter-note gap
+    pub ms: u16,
+}
+
+impl Note {
+    /// **Create Note**
+    ///
+    /// # Arguments
+    /// * `freq_hz` - Tone frequency in Hz, or 0 for a rest
+    /// * `ms` - Note length in milliseconds
+    pub const fn new(freq_hz: u16, ms: u16) -> Self {
+        Self { freq_hz, ms }
+    }
+
+    /// **Create Rest**
+    ///
+    /// # Arguments
+    /// * `ms` - Rest length in milliseconds
+    #[allow(dead_code)]
+    pub const fn rest(ms: u16) -> Self {
+        Self { freq_hz: 0, ms }
+    }
+}
+
+/// **Startup Jingle**
+///
+/// A short rising C major arpeggio (C5, E5, G5, C6) for boot feedback.
+pub const STARTUP_JINGLE: [Note; 4] = [
+    Note::new(523, 120),
+    Note::new(659, 120),
+    Note::new(784, 120),
+    Note::new(1047, 240),
+];
+
+/// **Onboard Speaker Driver**
+///
+/// Generates square-wave tones on the speaker pin using PWM0 at 50% duty.
+pub struct Speaker {
+    pwm: SimplePwm<'static, PWM0>,
+}
+
+#[allow(dead_code)]
+impl Speaker {
+    /// **Create Speaker**
+    ///
+    /// Configures PWM0 on the speaker pin. The speaker starts silent.
+    ///
+    /// # Arguments
+    /// * `pwm0` - PWM0 peripheral
+    /// * `pin` - Speaker pin (P0_00)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut speaker = Speaker::new(board.pwm0, board.speaker);
+    /// ```
+    pub fn new(pwm0: PWM0, pin: P0_00) -> Self {
+        let pwm = SimplePwm::new_1ch(pwm0, pin);
+        pwm.set_prescaler(Prescaler::Div16);
+        pwm.disable();
+        Self { pwm }
+    }
+
+    /// **Start Tone**
+    ///
+    /// Starts a continuous tone that keeps playing until `stop` is called
+    /// or another tone is started.
+    ///
+    /// # Arguments
+    /// * `freq_hz` - Tone frequency in Hz (clamped to the supported range)
+    pub fn start_tone(&mut self, freq_hz: u32) {
+        let period = (PWM_CLOCK_HZ / freq_hz.clamp(MIN_FREQ_HZ, MAX_FREQ_HZ)) as u16;
+        self.pwm.set_max_duty(period);
+        self.pwm.set_duty(0, period / 2);
+        self.pwm.enable();
+    }
+
+    /// **Stop Tone**
+    ///
+    /// Silences the speaker.
+    pub fn stop(&mut self) {
+        self.pwm.disable();
+    }
+
+    /// **Play Tone**
+    ///
+    /// Plays a tone for the given duration, then silences the speaker.
+    ///
+    /// # Arguments
+    /// * `freq_hz` - Tone frequency in Hz (clamped to the supported range)
+    /// * `duration` - How long to play the tone
+    ///
+    /// # Example
+    /// ```ignore
+    /// speaker.tone(440, Duration::from_millis(500)).await; // A4
+    /// ```
+    pub async fn tone(&mut self, freq_hz: u32, duration: Duration) {
+        self.start_tone(freq_hz);
+        Timer::after(duration).await;
+        self.stop();
+    }
+
+    /// **Play Melody**
+    ///
+    /// Plays each note in order. Every note ends with a short silent gap
+    /// (taken out of the note's own length so the tempo is preserved), which
+    /// keeps repeated identical notes distinct. Notes with a frequency of 0
+    /// are rests.
+    ///
+    /// # Arguments
+    /// * `notes` - Notes to play
+    ///
+    /// # Example
+    /// ```ignore
+    /// speaker.play_melody(&STARTUP_JINGLE).await;
+    /// ```
+    pub async fn play_melody(&mut self, notes: &[Note]) {
+        for note in notes {
+            let length = Duration::from_millis(note.ms as u64);
+            if note.freq_hz == 0 {
+                Timer::after(length).await;
+                continue;
+            }
+            let gap = NOTE_GAP.min(length);
+            self.tone(note.freq_hz as u32, length - gap).await;
+            Timer::after(gap).await;
+        }
+    }
+
+    /// **Frequency Sweep**
+    ///
+    /// Linearly ramps the tone from `from_hz` to `to_hz` over `duration`,
+    /// updating the PWM period every few milliseconds so the glide sounds
+    /// continuous. Sweeps may rise or fall; equal frequencies simply hold a
+    /// steady tone. Alternate a rising and a falling sweep for a siren.
+    ///
+    /// # Arguments
+    /// * `from_hz` - Starting frequency in Hz
+    /// * `to_hz` - Final frequency in Hz
+    /// * `duration` - Total sweep time
+    ///
+    /// # Example
+    /// ```ignore
+    /// loop {
+    ///     speaker.sweep(600, 1200, Duration::from_millis(400)).await;
+    ///     speaker.sweep(1200, 600, Duration::from_millis(400)).await;
+    /// }
+    /// ```
+    pub async fn sweep(&mut self, from_hz: u32, to_hz: u32, duration: Duration) {
+        if from_hz == to_hz {
+            self.tone(from_hz, duration).await;
+            return;
+        }
+
+        let steps = (duration.as_ticks() / SWEEP_STEP.as_ticks()).max(1);
+        let span = to_hz as i64 - from_hz as i64;
+        let start = Instant::now();
+        for step in 0..steps {
+            let freq = from_hz as i64 + span * step as i64 / steps as i64;
+            self.start_tone(freq as u32);
+            // Schedule against the start time so per-step overhead doesn't
+            // stretch the sweep
+            Timer::at(start + duration * (step + 1) as u32 / steps as u32).await;
+        }
+        self.stop();
+    }
+}
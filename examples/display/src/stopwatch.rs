@@ -0,0 +1,118 @@
+//! # Stopwatch Module
+//!
+//! This module provides a start/stop stopwatch built on `embassy_time`'s
+//! monotonic `Instant`, and shows its reading on the LED matrix as scrolling
+//! `mm:ss` text.
+//!
+//! ## Features
+//! - **Start/Stop**: Stopping keeps the elapsed time; starting again resumes
+//! - **Reset**: Return to zero whether running or stopped
+//! - **Display**: Scroll the elapsed minutes and seconds, e.g. `"1:35"`
+//!
+//! ## Usage Example
+//! ```ignore
+//! let mut stopwatch = Stopwatch::new();
+//! btn_a.wait_for_low().await;
+//! stopwatch.start();
+//! btn_b.wait_for_low().await;
+//! stopwatch.stop();
+//! stopwatch.show_on(&mut display).await;
+//! ```
+
+use embassy_time::{Duration, Instant};
+
+use crate::board::LedMatrix;
+use crate::format::format_mm_ss;
+
+/// **Stopwatch**
+///
+/// Accumulates time across any number of start/stop intervals.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Stopwatch {
+    /// Time collected from intervals that have already been stopped
+    accumulated: Duration,
+    /// Start of the running interval, or `None` while stopped
+    started: Option<Instant>,
+}
+
+#[allow(dead_code)]
+impl Stopwatch {
+    /// **Create Stopwatch**
+    ///
+    /// # Returns
+    /// A stopped stopwatch reading zero
+    pub const fn new() -> Self {
+        Self {
+            accumulated: Duration::from_ticks(0),
+            started: None,
+        }
+    }
+
+    /// **Start Timing**
+    ///
+    /// Begins a new interval, resuming from the current reading. Does
+    /// nothing if the stopwatch is already running.
+    pub fn start(&mut self) {
+        if self.started.is_none() {
+            self.started = Some(Instant::now());
+        }
+    }
+
+    /// **Stop Timing**
+    ///
+    /// Ends the running interval and keeps its time in the reading. Does
+    /// nothing if the stopwatch is already stopped.
+    pub fn stop(&mut self) {
+        if let Some(started) = self.started.take() {
+            self.accumulated += started.elapsed();
+        }
+    }
+
+    /// **Reset**
+    ///
+    /// Sets the reading back to zero. A running stopwatch keeps running
+    /// from zero.
+    pub fn reset(&mut self) {
+        self.accumulated = Duration::from_ticks(0);
+        if self.started.is_some() {
+            self.started = Some(Instant::now());
+        }
+    }
+
+    /// **Check Running State**
+    ///
+    /// # Returns
+    /// `true` between `start` and the next `stop`
+    pub fn is_running(&self) -> bool {
+        self.started.is_some()
+    }
+
+    /// **Elapsed Time**
+    ///
+    /// # Returns
+    /// The total time of every interval, including the running one
+    pub fn elapsed(&self) -> Duration {
+        match self.started {
+            Some(started) => self.accumulated + started.elapsed(),
+            None => self.accumulated,
+        }
+    }
+
+    /// **Show Elapsed Time**
+    ///
+    /// Scrolls the reading as minutes and seconds (`"0:42"`, `"12:05"`);
+    /// fractions of a second are dropped.
+    ///
+    /// # Arguments
+    /// * `display` - Mutable reference to the LED matrix display driver
+    ///
+    /// # Example
+    /// ```ignore
+    /// stopwatch.stop();
+    /// stopwatch.show_on(&mut display).await;
+    /// ```
+    pub async fn show_on(&self, display: &mut LedMatrix) {
+        let secs = self.elapsed().as_secs().min(u32::MAX as u64) as u32;
+        display.scroll(&format_mm_ss(secs)).await;
+    }
+}
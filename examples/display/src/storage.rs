@@ -0,0 +1,158 @@
+//! # Settings Storage Module
+//!
+//! This module persists a small `Settings` record across resets using the
+//! nRF52833's internal flash through the NVMC (non-volatile memory
+//! controller).
+//!
+//! ## Flash Layout
+//! The record lives at the start of the last 4 KB flash page (`0x7F000`).
+//! `memory.x` reserves that page by limiting the program's `FLASH` region
+//! to 508 KB, so firmware updates never overwrite it and it never
+//! overwrites firmware.
+//!
+//! ## Record Format
+//! ```text
+//! +-------------+---------+------------+-------------+
+//! | magic (u32) | version | brightness | high score  |
+//! | 4 bytes LE  | 1 byte  | 1 byte     | 2 bytes LE  |
+//! +-------------+---------+------------+-------------+
+//! ```
+//! Erased flash reads as `0xFF`, which never matches the magic number, so
+//! a board that has never stored settings loads `None`.
+//!
+//! ## Wear
+//! Flash pages survive roughly 10,000 erase cycles. `store` skips the
+//! erase and write entirely when the stored record already matches.
+//!
+//! ## Usage Example
+//! ```ignore
+//! let board = Microbit::default();
+//! let mut storage = Storage::new(board.nvmc);
+//! let settings = storage.load().unwrap_or_default();
+//! storage.store(&Settings { high_score: 42, ..settings })?;
+//! ```
+
+use embassy_nrf::nvmc::{self, Nvmc, PAGE_SIZE};
+use embassy_nrf::peripherals::NVMC;
+use embedded_storage::nor_flash::{NorFlash, ReadNorFlash};
+
+use crate::types::Brightness;
+
+/// **Settings Page Address**
+///
+/// Flash offset of the page holding the settings record: the last page
+/// of the 512 KB flash.
+const SETTINGS_ADDR: u32 = 0x0008_0000 - PAGE_SIZE as u32;
+
+/// **Record Magic Number**
+///
+/// Marks an initialized settings record ("MBST" in little-endian ASCII).
+const MAGIC: u32 = 0x5453_424D;
+
+/// **Record Format Version**
+///
+/// Bumped whenever the record layout changes, so old records are ignored
+/// instead of being misread.
+const VERSION: u8 = 1;
+
+/// **Record Length**
+///
+/// Serialized size in bytes, a multiple of the 4-byte flash write size.
+const RECORD_LEN: usize = 8;
+
+/// **Persistent Settings**
+///
+/// Values remembered across resets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Settings {
+    /// Display brightness level (0-10)
+    pub brightness: u8,
+    /// Best game score
+    pub high_score: u16,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            brightness: Brightness::default().level(),
+            high_score: 0,
+        }
+    }
+}
+
+impl Settings {
+    /// Serialize into the on-flash record format
+    fn to_record(self) -> [u8; RECORD_LEN] {
+        let mut record = [0u8; RECORD_LEN];
+        record[0..4].copy_from_slice(&MAGIC.to_le_bytes());
+        record[4] = VERSION;
+        record[5] = self.brightness;
+        record[6..8].copy_from_slice(&self.high_score.to_le_bytes());
+        record
+    }
+
+    /// Parse an on-flash record, rejecting erased flash and other versions
+    fn from_record(record: &[u8; RECORD_LEN]) -> Option<Self> {
+        let magic = u32::from_le_bytes([record[0], record[1], record[2], record[3]]);
+        if magic != MAGIC || record[4] != VERSION {
+            return None;
+        }
+        Some(Self {
+            brightness: record[5],
+            high_score: u16::from_le_bytes([record[6], record[7]]),
+        })
+    }
+}
+
+/// **Flash Settings Storage**
+///
+/// Reads and writes the `Settings` record in the reserved flash page.
+pub struct Storage {
+    flash: Nvmc<'static>,
+}
+
+#[allow(dead_code)]
+impl Storage {
+    /// **Create Storage**
+    ///
+    /// # Arguments
+    /// * `nvmc` - NVMC peripheral (`board.nvmc`)
+    pub fn new(nvmc: NVMC) -> Self {
+        Self { flash: Nvmc::new(nvmc) }
+    }
+
+    /// **Load Settings**
+    ///
+    /// # Returns
+    /// The stored settings, or `None` if the page is erased, holds a
+    /// different record version, or could not be read
+    ///
+    /// # Example
+    /// ```ignore
+    /// let settings = storage.load().unwrap_or_default();
+    /// ```
+    pub fn load(&mut self) -> Option<Settings> {
+        let mut record = [0u8; RECORD_LEN];
+        self.flash.read(SETTINGS_ADDR, &mut record).ok()?;
+        Settings::from_record(&record)
+    }
+
+    /// **Store Settings**
+    ///
+    /// Erases the settings page and writes the new record. Nothing is
+    /// erased or written if the stored record already matches.
+    ///
+    /// # Arguments
+    /// * `settings` - Values to persist
+    ///
+    /// # Returns
+    /// `Ok(())` once the record is in flash, or the NVMC error
+    pub fn store(&mut self, settings: &Settings) -> Result<(), nvmc::Error> {
+        if self.load().as_ref() == Some(settings) {
+            return Ok(());
+        }
+        self.flash.erase(SETTINGS_ADDR, SETTINGS_ADDR + PAGE_SIZE as u32)?;
+        self.flash.write(SETTINGS_ADDR, &settings.to_record())
+    }
+}
@@ -0,0 +1,313 @@
+//! # System Information Module
+//!
+//! This module covers chip-level status information, such as why the
+//! nRF52833 last reset and the die temperature, plus the watchdog that
+//! resets the chip when the application hangs.
+//!
+//! ## Features
+//! - **Reset Reason**: Decodes the POWER RESETREAS register
+//! - **Temperature**: Reads the on-die TEMP sensor in tenths of a degree
+//! - **Watchdog**: Resets the chip unless it is fed within a timeout
+//!
+//! ## Usage Example
+//! ```ignore
+//! defmt::info!("reset reason: {}", Microbit::reset_reason());
+//! defmt::info!("device id: {:016x}", Microbit::device_id());
+//!
+//! let mut temp = TempSensor::new(board.temp);
+//! display.show_fixed(temp.read().await as i32, 1, Duration::from_secs(2)).await;
+//!
+//! let mut watchdog = Watchdog::new(board.wdt);
+//! watchdog.start(Duration::from_secs(2))?;
+//! loop {
+//!     display.scroll("OK").await;
+//!     watchdog.feed();
+//! }
+//! ```
+
+use embassy_nrf::peripherals::{TEMP, WDT};
+use embassy_nrf::temp::Temp;
+use embassy_nrf::wdt::{self, HaltConfig, SleepConfig, WatchdogHandle};
+use embassy_time::Duration;
+
+use crate::board::Irqs;
+
+/// **Reset Pin Bit**
+///
+/// Reset triggered by the external reset pin (the button on the back).
+const RESETPIN: u32 = 1 << 0;
+
+/// **Watchdog Bit**
+///
+/// Reset triggered by the watchdog timer.
+const DOG: u32 = 1 << 1;
+
+/// **Soft Reset Bit**
+///
+/// Reset requested by software (`SCB::sys_reset`).
+const SREQ: u32 = 1 << 2;
+
+/// **CPU Lockup Bit**
+///
+/// Reset caused by a CPU lockup (e.g. a fault inside a fault handler).
+const LOCKUP: u32 = 1 << 3;
+
+/// **System OFF Wake Bit**
+///
+/// Wake-up from System OFF mode by a GPIO DETECT signal.
+const OFF: u32 = 1 << 16;
+
+/// **LPCOMP Wake Bit**
+///
+/// Wake-up from System OFF mode by the low-power comparator.
+const LPCOMP: u32 = 1 << 17;
+
+/// **Debug Interface Bit**
+///
+/// Wake-up from System OFF mode triggered by the debug interface.
+const DIF: u32 = 1 << 18;
+
+/// **NFC Wake Bit**
+///
+/// Wake-up from System OFF mode by an NFC field.
+const NFC: u32 = 1 << 19;
+
+/// **VBUS Wake Bit**
+///
+/// Wake-up from System OFF mode by VBUS rising into its valid range.
+const VBUS: u32 = 1 << 20;
+
+/// **Reset Reason**
+///
+/// Why the chip last started running. RESETREAS bits accumulate until
+/// cleared, so when several are set the most specific cause is reported.
+/// A power-on or brown-out reset clears every bit, which reads as `PowerOn`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ResetReason {
+    /// Power-on or brown-out reset
+    PowerOn,
+    /// External reset pin
+    Pin,
+    /// Watchdog timeout
+    Watchdog,
+    /// Software reset request
+    SoftReset,
+    /// CPU lockup
+    Lockup,
+    /// Wake from System OFF by GPIO
+    SystemOffWake,
+    /// Wake from System OFF by the low-power comparator
+    LpComp,
+    /// Wake from System OFF by the debug interface
+    Debug,
+    /// Wake from System OFF by an NFC field
+    Nfc,
+    /// Wake from System OFF by VBUS
+    Vbus,
+}
+
+impl ResetReason {
+    /// **Decode RESETREAS**
+    ///
+    /// # Arguments
+    /// * `bits` - Raw value of the POWER RESETREAS register
+    ///
+    /// # Returns
+    /// The most specific reset cause present in `bits`
+    pub fn from_bits(bits: u32) -> Self {
+        // Checked from most to least specific cause
+        const REASONS: [(u32, ResetReason); 9] = [
+            (DOG, ResetReason::Watchdog),
+            (LOCKUP, ResetReason::Lockup),
+            (SREQ, ResetReason::SoftReset),
+            (RESETPIN, ResetReason::Pin),
+            (OFF, ResetReason::SystemOffWake),
+            (LPCOMP, ResetReason::LpComp),
+            (DIF, ResetReason::Debug),
+            (NFC, ResetReason::Nfc),
+            (VBUS, ResetReason::Vbus),
+        ];
+        REASONS
+            .iter()
+            .find(|(mask, _)| bits & mask != 0)
+            .map_or(ResetReason::PowerOn, |&(_, reason)| reason)
+    }
+}
+
+/// **On-Die Temperature Sensor**
+///
+/// Wraps the nRF52833 TEMP peripheral, which measures the chip's own
+/// temperature in 0.25 °C steps. The die runs slightly warmer than the
+/// surrounding air, so readings are typically a few degrees above room
+/// temperature.
+pub struct TempSensor {
+    temp: Temp<'static>,
+}
+
+#[allow(dead_code)]
+impl TempSensor {
+    /// **Create Temperature Sensor**
+    ///
+    /// # Arguments
+    /// * `temp` - TEMP peripheral (`board.temp`)
+    pub fn new(temp: TEMP) -> Self {
+        Self {
+            temp: Temp::new(temp, Irqs),
+        }
+    }
+
+    /// **Read Temperature**
+    ///
+    /// Starts a conversion and waits for it to complete (about 36 μs).
+    ///
+    /// # Returns
+    /// Temperature in tenths of a degree Celsius, e.g. `235` for 23.5 °C.
+    /// The sensor resolves quarter degrees, so the tenths digit is one of
+    /// 0, 2, 5 or 7.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let tenths = temp.read().await;
+    /// display.show_fixed(tenths as i32, 1, Duration::from_secs(2)).await;
+    /// ```
+    pub async fn read(&mut self) -> i16 {
+        // The sensor reports fixed-point quarter degrees (I30F2)
+        let quarters = self.temp.read().await.to_bits();
+        (quarters * 10 / 4) as i16
+    }
+}
+
+/// **Watchdog Clock Rate**
+///
+/// The watchdog counts the 32.768 kHz low-frequency clock.
+const WDT_TICK_HZ: u64 = 32_768;
+
+/// **Minimum Watchdog Ticks**
+///
+/// Smallest reload value the hardware accepts (about 0.5 ms).
+const WDT_MIN_TICKS: u32 = 0xF;
+
+/// **Watchdog Errors**
+///
+/// Reasons `Watchdog::start` could not honor the requested timeout.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WatchdogError {
+    /// `start` was already called; a running watchdog cannot be changed
+    AlreadyStarted,
+    /// The watchdog is still running from before a soft reset with a
+    /// different configuration, which only a full reset can change
+    ConfigMismatch,
+}
+
+/// **Watchdog Timer**
+///
+/// Resets the chip unless `feed` is called at least once per timeout,
+/// recovering from a display or sensor call that deadlocks. Once started
+/// the hardware cannot be stopped or reconfigured until the next reset,
+/// and it keeps counting while the CPU sleeps but pauses while a debugger
+/// halts the core.
+///
+/// After the reset `Microbit::reset_reason()` reads `ResetReason::Watchdog`.
+/// RESETREAS bits accumulate until a power cycle or
+/// `Microbit::clear_reset_reason()`, so clear them once the watchdog reset
+/// has been handled or later pin or soft resets keep reporting it too.
+pub struct Watchdog {
+    /// Peripheral before `start`, `None` once running
+    wdt: Option<WDT>,
+    /// Feed handle once running
+    handle: Option<WatchdogHandle>,
+}
+
+#[allow(dead_code)]
+impl Watchdog {
+    /// **Create Watchdog**
+    ///
+    /// The watchdog does nothing until `start` is called.
+    ///
+    /// # Arguments
+    /// * `wdt` - WDT peripheral (`board.wdt`)
+    pub fn new(wdt: WDT) -> Self {
+        Self {
+            wdt: Some(wdt),
+            handle: None,
+        }
+    }
+
+    /// **Start Watchdog**
+    ///
+    /// Arms the watchdog; the chip resets if `feed` is not called within
+    /// `timeout`. The timeout is rounded down to the 32.768 kHz watchdog
+    /// clock, with a floor of about 0.5 ms.
+    ///
+    /// # Arguments
+    /// * `timeout` - Longest allowed gap between feeds
+    ///
+    /// # Returns
+    /// An error if the watchdog is already running and so cannot take the
+    /// new timeout
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut watchdog = Watchdog::new(board.wdt);
+    /// watchdog.start(Duration::from_secs(2))?;
+    /// ```
+    pub fn start(&mut self, timeout: Duration) -> Result<(), WatchdogError> {
+        let wdt = self.wdt.take().ok_or(WatchdogError::AlreadyStarted)?;
+
+        let mut config = wdt::Config::default();
+        config.timeout_ticks = watchdog_ticks(timeout);
+        config.action_during_sleep = SleepConfig::RUN;
+        config.action_during_debug_halt = HaltConfig::PAUSE;
+
+        match wdt::Watchdog::try_new::<1>(wdt, config) {
+            Ok((_, [handle])) => {
+                self.handle = Some(handle);
+                Ok(())
+            }
+            Err(wdt) => {
+                self.wdt = Some(wdt);
+                Err(WatchdogError::ConfigMismatch)
+            }
+        }
+    }
+
+    /// **Feed Watchdog**
+    ///
+    /// Restarts the timeout. Does nothing before `start`.
+    pub fn feed(&mut self) {
+        if let Some(handle) = self.handle.as_mut() {
+            handle.pet();
+        }
+    }
+
+    /// **Check Running State**
+    ///
+    /// # Returns
+    /// `true` once `start` has succeeded
+    pub fn is_running(&self) -> bool {
+        self.handle.is_some()
+    }
+}
+
+/// Convert a timeout to watchdog clock ticks, clamped to the hardware range
+fn watchdog_ticks(timeout: Duration) -> u32 {
+    let ticks = timeout.as_micros().saturating_mul(WDT_TICK_HZ) / 1_000_000;
+    ticks.clamp(WDT_MIN_TICKS as u64, u32::MAX as u64) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reset_reason_picks_the_most_specific_cause() {
+        assert_eq!(ResetReason::from_bits(0), ResetReason::PowerOn);
+        assert_eq!(ResetReason::from_bits(RESETPIN), ResetReason::Pin);
+        // The watchdog outranks the reset pin when both bits are sticky
+        assert_eq!(ResetReason::from_bits(DOG | RESETPIN), ResetReason::Watchdog);
+        assert_eq!(ResetReason::from_bits(SREQ), ResetReason::SoftReset);
+        assert_eq!(ResetReason::from_bits(OFF), ResetReason::SystemOffWake);
+    }
+}
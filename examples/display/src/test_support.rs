@@ -0,0 +1,322 @@
+//! # Host Test Support Module
+//!
+//! Shared helpers for the unit tests, which run on the host with
+//! `cargo test --target <host triple>`. Nothing here is built into the
+//! firmware.
+//!
+//! ## Helpers
+//! - **Mock Clock**: Drives `embassy_time::MockDriver`, which stands in for
+//!   the RTC time driver, and serializes the tests that depend on it
+//! - **Null Pins**: Output pins that ignore every write, for a `LedMatrix`
+//!   whose state is checked directly
+//! - **Mock Pins**: Output pins that record their level so a test can see
+//!   which LEDs a `LedMatrix` has lit
+//! - **Mock Buttons**: Active-low input pins the test presses and releases
+//! - **Polling**: Polls a future once without an executor
+//! - **defmt Capture**: A defmt logger that records each test's output,
+//!   and `defmt_text` to decode it back to text (Linux hosts)
+//!
+//! ## Usage Example
+//! ```ignore
+//! let _clock = mock_clock();
+//! let animation = Animation::new(data, AnimationEffect::Slide, duration)?;
+//! ```
+
+use core::cell::Cell;
+use core::future::{poll_fn, Future};
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use embassy_time::{Duration, MockDriver};
+use embedded_hal::digital::{ErrorType, InputPin, OutputPin};
+use embedded_hal_async::digital::Wait;
+
+use crate::display::LedMatrix;
+use crate::types::{Brightness, Frame};
+
+/// **Mock Matrix Rows**
+///
+/// Row count of the matrices built by `mock_matrix`.
+pub const MOCK_ROWS: usize = 5;
+
+/// **Most Mock Columns**
+///
+/// Widest matrix `mock_matrix` can build, enough for two chained displays.
+pub const MAX_MOCK_COLS: usize = 10;
+
+/// Held by every test that reads or moves the global mock clock
+static CLOCK: Mutex<()> = Mutex::new(());
+
+/// Level of every `MockPin`, rows first and then columns
+static PIN_LEVELS: Mutex<[bool; MOCK_ROWS + MAX_MOCK_COLS]> = Mutex::new([false; MOCK_ROWS + MAX_MOCK_COLS]);
+
+/// **Take Mock Clock**
+///
+/// Waits until no other test is using the mock clock, then resets it to
+/// zero. Hold the guard for the whole test: the clock, and with it
+/// `LedMatrix::render` and the `MockPin` levels, are shared between tests.
+///
+/// # Returns
+/// A guard that releases the clock when dropped
+pub fn mock_clock() -> MutexGuard<'static, ()> {
+    // A failed test poisons the lock; the clock is reset below anyway
+    let guard = CLOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    MockDriver::get().reset();
+    guard
+}
+
+/// **Advance Mock Clock**
+///
+/// Moves the mock clock forward by `duration`, waking any timers that are
+/// now due.
+///
+/// # Arguments
+/// * `duration` - Time to skip ahead
+pub fn advance(duration: Duration) {
+    MockDriver::get().advance(duration);
+}
+
+/// **Poll Once**
+///
+/// Polls `future` a single time with a waker that does nothing, so a test
+/// can step an async function and inspect the state in between.
+///
+/// # Arguments
+/// * `future` - Pinned future to poll
+///
+/// # Returns
+/// The result of the poll
+pub fn poll_once<F: Future>(future: Pin<&mut F>) -> Poll<F::Output> {
+    future.poll(&mut Context::from_waker(Waker::noop()))
+}
+
+/// **Null Pin**
+///
+/// An output pin that ignores every write, for tests that only look at a
+/// display's state rather than its pins.
+pub struct NullPin;
+
+impl ErrorType for NullPin {
+    type Error = core::convert::Infallible;
+}
+
+impl OutputPin for NullPin {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// **Null Matrix**
+///
+/// Builds a 5x5 display on `NullPin`s at full brightness. Below full
+/// brightness `render` busy-waits for the row's on-time, which never ends
+/// while the mock clock stands still.
+///
+/// # Returns
+/// A display ready to render
+pub fn null_matrix() -> LedMatrix<NullPin, 5, 5> {
+    let mut display = LedMatrix::new(core::array::from_fn(|_| NullPin), core::array::from_fn(|_| NullPin));
+    display.set_brightness(Brightness::MAX);
+    display
+}
+
+/// **Recording Pin**
+///
+/// An output pin that records its level in a shared table, indexed by the
+/// number it was created with. Read the table back with `pin_levels` or
+/// `lit_pixels`.
+pub struct MockPin(pub usize);
+
+impl ErrorType for MockPin {
+    type Error = core::convert::Infallible;
+}
+
+impl OutputPin for MockPin {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        PIN_LEVELS.lock().unwrap()[self.0] = false;
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        PIN_LEVELS.lock().unwrap()[self.0] = true;
+        Ok(())
+    }
+}
+
+/// **Mock Matrix**
+///
+/// Builds a `MOCK_ROWS` by `COLS` display on `MockPin`s at full
+/// brightness, like `null_matrix`: rows are pins `0..MOCK_ROWS` and columns
+/// follow on from there. All pin levels start low.
+///
+/// # Returns
+/// A display whose pin writes land in the shared level table
+pub fn mock_matrix<const COLS: usize>() -> LedMatrix<MockPin, MOCK_ROWS, COLS> {
+    assert!(COLS <= MAX_MOCK_COLS, "mock matrix is too wide");
+    *PIN_LEVELS.lock().unwrap() = [false; MOCK_ROWS + MAX_MOCK_COLS];
+    let mut display = LedMatrix::new(
+        core::array::from_fn(MockPin),
+        core::array::from_fn(|col| MockPin(MOCK_ROWS + col)),
+    );
+    display.set_brightness(Brightness::MAX);
+    display
+}
+
+/// **Pin Levels**
+///
+/// # Returns
+/// The current level of every `MockPin`, `true` for high
+pub fn pin_levels() -> [bool; MOCK_ROWS + MAX_MOCK_COLS] {
+    *PIN_LEVELS.lock().unwrap()
+}
+
+/// **Lit Pixels**
+///
+/// Works out which LEDs of a `mock_matrix` are lit right now with the
+/// default polarity: those whose row is high and whose column is low.
+///
+/// # Returns
+/// A frame with the lit LEDs set
+pub fn lit_pixels<const COLS: usize>() -> Frame<COLS, MOCK_ROWS> {
+    let levels = pin_levels();
+    let mut lit = Frame::empty();
+    for row in 0..MOCK_ROWS {
+        for col in 0..COLS {
+            if levels[row] && !levels[MOCK_ROWS + col] {
+                lit.set(col, row);
+            }
+        }
+    }
+    lit
+}
+
+/// **Mock Button**
+///
+/// An active-low input pin that reads low while the shared flag is set,
+/// like a micro:bit button held down. Waits finish as soon as the flag
+/// allows, so poll them again after changing it.
+pub struct MockButton<'a>(pub &'a Cell<bool>);
+
+impl MockButton<'_> {
+    /// Wait until the pin reads `low`
+    async fn wait_for_level(&self, low: bool) {
+        poll_fn(|_| {
+            if self.0.get() == low {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        })
+        .await
+    }
+}
+
+impl ErrorType for MockButton<'_> {
+    type Error = core::convert::Infallible;
+}
+
+impl InputPin for MockButton<'_> {
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.0.get())
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.0.get())
+    }
+}
+
+impl Wait for MockButton<'_> {
+    async fn wait_for_high(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_level(false).await;
+        Ok(())
+    }
+
+    async fn wait_for_low(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_level(true).await;
+        Ok(())
+    }
+
+    async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_level(true).await;
+        self.wait_for_level(false).await;
+        Ok(())
+    }
+
+    async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_level(false).await;
+        self.wait_for_level(true).await;
+        Ok(())
+    }
+
+    async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
+        self.wait_for_level(!self.0.get()).await;
+        Ok(())
+    }
+}
+
+thread_local! {
+    /// Encoder and encoded bytes of the defmt frames logged by each test thread
+    #[cfg(all(feature = "defmt", target_os = "linux"))]
+    static DEFMT_OUTPUT: core::cell::RefCell<(defmt::Encoder, Vec<u8>)> = const { core::cell::RefCell::new((defmt::Encoder::new(), Vec::new())) };
+}
+
+/// **Capturing Logger**
+///
+/// The host tests' defmt logger. Each thread keeps its own output, so tests
+/// running side by side do not mix their log frames.
+#[cfg(all(feature = "defmt", target_os = "linux"))]
+#[defmt::global_logger]
+struct CapturingLogger;
+
+#[cfg(all(feature = "defmt", target_os = "linux"))]
+unsafe impl defmt::Logger for CapturingLogger {
+    fn acquire() {
+        DEFMT_OUTPUT.with_borrow_mut(|(encoder, bytes)| encoder.start_frame(|data| bytes.extend_from_slice(data)));
+    }
+
+    unsafe fn flush() {}
+
+    unsafe fn release() {
+        DEFMT_OUTPUT.with_borrow_mut(|(encoder, bytes)| encoder.end_frame(|data| bytes.extend_from_slice(data)));
+    }
+
+    unsafe fn write(data: &[u8]) {
+        DEFMT_OUTPUT.with_borrow_mut(|(encoder, bytes)| encoder.write(data, |out| bytes.extend_from_slice(out)));
+    }
+}
+
+/// **defmt Text**
+///
+/// Logs `value` with `defmt::println!` and decodes the captured frame
+/// against the defmt table of the running test binary, as a host tool
+/// would decode the micro:bit's output. Needs the `defmt_host.x` layout
+/// that `build.rs` links into Linux host builds.
+///
+/// # Arguments
+/// * `value` - Value to format
+///
+/// # Returns
+/// The text `value` formats to
+#[cfg(all(feature = "defmt", target_os = "linux"))]
+pub fn defmt_text<T: defmt::Format>(value: &T) -> String {
+    DEFMT_OUTPUT.with_borrow_mut(|(_, bytes)| bytes.clear());
+    defmt::println!("{}", value);
+    let bytes = DEFMT_OUTPUT.with_borrow_mut(|(_, bytes)| core::mem::take(bytes));
+
+    let elf = std::fs::read(std::env::current_exe().unwrap()).unwrap();
+    let table = defmt_decoder::Table::parse(&elf)
+        .unwrap()
+        .expect("test binary has no defmt table");
+    let mut decoder = table.new_stream_decoder();
+    decoder.received(&bytes);
+    decoder
+        .decode()
+        .expect("captured bytes are one defmt frame")
+        .display_message()
+        .to_string()
+}
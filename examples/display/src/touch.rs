@@ -0,0 +1,135 @@
+//! # Touch Logo Module
+//!
+//! This module reads the capacitive touch sensor behind the gold logo on the
+//! front of the micro:bit v2.
+//!
+//! ## How It Works
+//! The logo pad (P1_04) has a 10 MΩ pull-up resistor on the board. The pin
+//! is driven low to discharge the pad, then released as a floating input and
+//! the time taken to charge back up to a logic high is counted. A finger on
+//! the logo adds capacitance, so the pad charges noticeably slower.
+//!
+//! ## Features
+//! - **Calibration**: Measures the untouched charge time at startup
+//! - **Polling**: `is_touched` for instant checks
+//! - **Async Waiting**: `wait_for_touch` and `wait_for_release`
+//!
+//! ## Usage Example
+//! ```ignore
+//! let board = Microbit::default();
+//! let mut logo = TouchPad::new(board.logo);
+//! logo.wait_for_touch().await;
+//! ```
+
+use embassy_nrf::gpio::{Flex, OutputDrive, Pull};
+use embassy_nrf::peripherals::P1_04;
+use embassy_time::{Duration, Timer};
+
+/// **Calibration Samples**
+///
+/// Number of charge-time measurements averaged for the untouched baseline.
+const CALIBRATION_SAMPLES: u32 = 8;
+
+/// **Touch Margin**
+///
+/// Extra charge-time counts above the baseline that count as a touch.
+const TOUCH_MARGIN: u32 = 20;
+
+/// **Sample Limit**
+///
+/// Upper bound on the charge-time count, so a shorted pad cannot hang.
+const MAX_COUNT: u32 = 2_000;
+
+/// **Poll Interval**
+///
+/// Time between samples while waiting for a touch or release.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// **Capacitive Touch Pad**
+///
+/// Measures the charge time of the logo pad and compares it against a
+/// baseline taken when the pad was untouched.
+pub struct TouchPad {
+    pin: Flex<'static>,
+    threshold: u32,
+}
+
+#[allow(dead_code)]
+impl TouchPad {
+    /// **Create Touch Pad**
+    ///
+    /// Calibrates against the current reading, so the logo must not be
+    /// touched while this runs.
+    ///
+    /// # Arguments
+    /// * `logo` - Logo touch pin (`board.logo`)
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut logo = TouchPad::new(board.logo);
+    /// ```
+    pub fn new(logo: P1_04) -> Self {
+        let mut pad = Self {
+            pin: Flex::new(logo),
+            threshold: MAX_COUNT,
+        };
+        pad.calibrate();
+        pad
+    }
+
+    /// **Calibrate**
+    ///
+    /// Re-measures the untouched baseline. Call this if readings drift, for
+    /// example after the board is moved onto a different surface.
+    pub fn calibrate(&mut self) {
+        let total: u32 = (0..CALIBRATION_SAMPLES).map(|_| self.sample()).sum();
+        self.threshold = total / CALIBRATION_SAMPLES + TOUCH_MARGIN;
+    }
+
+    /// **Check Touch**
+    ///
+    /// # Returns
+    /// `true` if a finger is currently on the logo
+    pub fn is_touched(&mut self) -> bool {
+        self.sample() > self.threshold
+    }
+
+    /// **Wait For Touch**
+    ///
+    /// Polls the pad until the logo is touched.
+    ///
+    /// # Example
+    /// ```ignore
+    /// logo.wait_for_touch().await;
+    /// display.scroll("Hi!").await;
+    /// ```
+    pub async fn wait_for_touch(&mut self) {
+        while !self.is_touched() {
+            Timer::after(POLL_INTERVAL).await;
+        }
+    }
+
+    /// **Wait For Release**
+    ///
+    /// Polls the pad until the finger is lifted from the logo.
+    pub async fn wait_for_release(&mut self) {
+        while self.is_touched() {
+            Timer::after(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Discharge the pad and count loop iterations until it charges high
+    fn sample(&mut self) -> u32 {
+        self.pin.set_low();
+        self.pin.set_as_output(OutputDrive::Standard);
+        cortex_m::asm::delay(64);
+        self.pin.set_as_input(Pull::None);
+
+        let mut count = 0;
+        while self.pin.is_low() && count < MAX_COUNT {
+            count += 1;
+        }
+        self.pin.set_as_disconnected();
+        count
+    }
+}
@@ -8,13 +8,19 @@
 //! - **Bitmap**: Compact bit storage for LED patterns
 //! - **Frame**: NxM frame buffer for matrix display
 //! - **Brightness**: LED intensity control enumeration
+//! - **Direction**: Up/down/left/right movement on the matrix
+//! - **Sprite**: Small frame drawn at a signed position with edge clipping
+//! - **Layer**: Sprites of any size composed in z-order with `compose`
 //!
 //! ## Features
 //! - **Efficient Storage**: Optimized bitmap representation using u8 arrays
-//! - **Generic Frames**: Configurable frame sizes using const generics
+//! - **Generic Frames**: Configurable frame sizes using const generics, up to
+//!   16 columns wide for chained displays
 //! - **Brightness Levels**: 11 discrete brightness levels (0-10)
 //! - **Bit Manipulation**: Fast set/clear/test operations on individual pixels
 //! - **Debug Support**: Comprehensive debugging and formatting support
+//! - **Serde Support**: `Bitmap`, `Frame` and `Brightness` implement
+//!   `Serialize`/`Deserialize` with the optional `serde` feature
 //!
 //! ## Usage Examples
 //!
@@ -44,8 +50,9 @@ use core::ops::{AddAssign, SubAssign};
 /// **Bitmap Storage Width**
 ///
 /// Defines the width of the bitmap storage array in u8 words.
-/// Currently set to 1 for optimal performance with 5x5 LED matrices.
-const BITMAP_WIDTH: usize = 1;
+/// Two words hold rows of up to 16 pixels, enough for two chained 5-column
+/// banks, in the same space the padding of a single word already took.
+const BITMAP_WIDTH: usize = 2;
 
 /// **Bitmap Word Size**
 ///
@@ -53,16 +60,53 @@ const BITMAP_WIDTH: usize = 1;
 /// Used for bit manipulation calculations and storage optimization.
 const BITMAP_WORD_SIZE: usize = 8;
 
+/// **Bitmap Capacity**
+///
+/// Maximum number of bits a `Bitmap` holds, and so the maximum `Frame`
+/// width.
+pub const BITMAP_CAPACITY: usize = BITMAP_WIDTH * BITMAP_WORD_SIZE;
+
+// Shifts assemble all storage words into a single u32
+const _: () = assert!(BITMAP_CAPACITY <= u32::BITS as usize);
+
 /// **Compact Bitmap Storage**
 ///
-/// A bitmap with room for 8 bits used by Frame to create a compact frame buffer.
+/// A bitmap with room for `BITMAP_CAPACITY` bits used by Frame to create a compact frame buffer.
 /// Provides efficient storage and manipulation of LED patterns for matrix displays.
-#[derive(Clone, Copy, PartialEq)]
+///
+/// # Storage Layout
+/// Bits are stored from the most significant end of the word: bit 0 is
+/// `0x80`, bit 1 is `0x40` and so on, continuing into the next word after
+/// bit 7, which leaves the low bits past `nbits` unused. `as_raw` and
+/// `from_raw` expose the first word in this layout.
+///
+/// # Equality
+/// Shifting can leave stray bits in the unused part of the word.
+/// `PartialEq`, `Eq` and `Hash` only look at `nbits` and the used bits, so
+/// bitmaps that read the same compare equal and hash identically.
+#[derive(Clone, Copy)]
 pub struct Bitmap {
     data: [u8; BITMAP_WIDTH],
     nbits: usize,
 }
 
+impl PartialEq for Bitmap {
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_const(other)
+    }
+}
+
+impl Eq for Bitmap {}
+
+impl core::hash::Hash for Bitmap {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.nbits.hash(state);
+        for i in 0..BITMAP_WIDTH {
+            self.word(i).hash(state);
+        }
+    }
+}
+
 impl core::fmt::Debug for Bitmap {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         for i in 0..self.nbits {
@@ -91,6 +135,40 @@ impl defmt::Format for Bitmap {
     }
 }
 
+/// Serialized as the tuple `(words, nbits())`, where `words` holds every
+/// storage word in the `as_raw` layout, so bitmaps of any width round-trip
+#[cfg(feature = "serde")]
+impl serde::Serialize for Bitmap {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut words = [0u8; BITMAP_WIDTH];
+        for (i, word) in words.iter_mut().enumerate() {
+            *word = self.word(i);
+        }
+        (words, self.nbits as u8).serialize(serializer)
+    }
+}
+
+/// Deserialized from the words written by `serialize`; a bit count above
+/// `BITMAP_CAPACITY` is rejected and bits past it are cleared
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Bitmap {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (words, nbits) = <([u8; BITMAP_WIDTH], u8)>::deserialize(deserializer)?;
+        if nbits as usize > BITMAP_CAPACITY {
+            return Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(nbits as u64),
+                &"a bit count of at most 16",
+            ));
+        }
+        let mut bitmap = Self::empty(nbits as usize);
+        for (i, &word) in words.iter().enumerate() {
+            bitmap.data[i] = word;
+            bitmap.data[i] = bitmap.word(i);
+        }
+        Ok(bitmap)
+    }
+}
+
 impl Bitmap {
     /// **Create New Bitmap**
     ///
@@ -121,6 +199,121 @@ impl Bitmap {
         Self { data, nbits }
     }
 
+    /// **Create Bitmap From Raw Storage**
+    ///
+    /// Rebuilds a bitmap from a word produced by `as_raw`. Unlike `new`, the
+    /// data is taken exactly as stored: bit 0 is the most significant bit of
+    /// `data`, bit 1 the next one down, and so on, so the used bits sit in
+    /// the high part of the byte. Bits below the first `nbits` are cleared,
+    /// and bits past the first 8 of a wider bitmap start out clear.
+    ///
+    /// # Arguments
+    /// * `data` - Stored word, with the used bits in the high part
+    /// * `nbits` - Number of bits to use (clamped to `BITMAP_CAPACITY`)
+    ///
+    /// # Returns
+    /// A bitmap whose `as_raw` and `nbits` match the inputs
+    ///
+    /// # Example
+    /// ```ignore
+    /// let bitmap = Bitmap::from_raw(0b10100000, 5);
+    /// assert_eq!(bitmap, Bitmap::new(0b10100, 5));
+    /// ```
+    #[allow(dead_code)]
+    pub const fn from_raw(data: u8, nbits: usize) -> Self {
+        let nbits = if nbits < BITMAP_CAPACITY {
+            nbits
+        } else {
+            BITMAP_CAPACITY
+        };
+        let mut words = [0; BITMAP_WIDTH];
+        words[0] = data & used_mask(nbits);
+        Self { data: words, nbits }
+    }
+
+    /// **Raw Storage Word**
+    ///
+    /// Returns the first stored word, in the layout accepted by `from_raw`:
+    /// bit 0 in the most significant position and any bits past `nbits`
+    /// cleared. For bitmaps of up to 8 bits, this together with `nbits`
+    /// fully describes the bitmap.
+    ///
+    /// # Returns
+    /// The stored bits, aligned to the top of the byte
+    ///
+    /// # Example
+    /// ```ignore
+    /// let bitmap = Bitmap::new(0b10100, 5);
+    /// assert_eq!(bitmap.as_raw(), 0b10100000);
+    /// ```
+    #[allow(dead_code)]
+    pub const fn as_raw(&self) -> u8 {
+        self.word(0)
+    }
+
+    /// Storage word `i` with the bits past `nbits` masked off
+    const fn word(&self, i: usize) -> u8 {
+        self.data[i] & used_mask(self.nbits.saturating_sub(i * BITMAP_WORD_SIZE))
+    }
+
+    /// All storage words as one bit string, bit 0 in the most significant
+    /// position of the `u32`
+    fn bits(&self) -> u32 {
+        let mut bits = 0;
+        for i in 0..BITMAP_WIDTH {
+            bits |= (self.word(i) as u32) << (u32::BITS as usize - (i + 1) * BITMAP_WORD_SIZE);
+        }
+        bits
+    }
+
+    /// Store a bit string in the layout returned by `bits`, dropping bits
+    /// past `nbits`
+    fn set_bits(&mut self, bits: u32) {
+        for i in 0..BITMAP_WIDTH {
+            self.data[i] = (bits >> (u32::BITS as usize - (i + 1) * BITMAP_WORD_SIZE)) as u8;
+            self.data[i] = self.word(i);
+        }
+    }
+
+    /// **Bit Count**
+    ///
+    /// # Returns
+    /// Number of bits the bitmap holds
+    #[allow(dead_code)]
+    pub const fn nbits(&self) -> usize {
+        self.nbits
+    }
+
+    /// **Const Equality**
+    ///
+    /// Same comparison as `==`, usable in const contexts: the bit counts
+    /// and the used bits must match, while unused storage bits are ignored.
+    ///
+    /// # Arguments
+    /// * `other` - Bitmap to compare against
+    ///
+    /// # Returns
+    /// `true` if both bitmaps hold the same bits
+    ///
+    /// # Example
+    /// ```ignore
+    /// const SAME: bool = Bitmap::new(0b101, 3).eq_const(&Bitmap::from_raw(0b10100000, 3));
+    /// ```
+    #[allow(dead_code)]
+    pub const fn eq_const(&self, other: &Self) -> bool {
+        if self.nbits != other.nbits {
+            return false;
+        }
+        let mut i = 0;
+        while i < BITMAP_WIDTH {
+            if self.word(i) != other.word(i) {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
     /// **Create Empty Bitmap**
     ///
     /// Creates an empty bitmap with the specified number of bits.
@@ -137,7 +330,10 @@ impl Bitmap {
     /// let bitmap = Bitmap::empty(5); // 5-bit empty bitmap
     /// ```
     pub const fn empty(nbits: usize) -> Self {
-        Self { data: [0; 1], nbits }
+        Self {
+            data: [0; BITMAP_WIDTH],
+            nbits,
+        }
     }
 
     /// **Set Bit**
@@ -240,9 +436,7 @@ impl Bitmap {
     /// bitmap.shift_left(2); // Now 0b00000000 (bits shifted out)
     /// ```
     pub fn shift_left(&mut self, nbits: usize) {
-        for b in self.data.iter_mut() {
-            *b <<= nbits;
-        }
+        self.set_bits(self.bits().checked_shl(nbits as u32).unwrap_or(0));
     }
 
     /// **Shift Right**
@@ -259,9 +453,7 @@ impl Bitmap {
     /// bitmap.shift_right(2); // Now 0b00110000
     /// ```
     pub fn shift_right(&mut self, nbits: usize) {
-        for b in self.data.iter_mut() {
-            *b >>= nbits;
-        }
+        self.set_bits(self.bits().checked_shr(nbits as u32).unwrap_or(0));
     }
 
     /// **Logical OR Operation**
@@ -303,6 +495,32 @@ impl Bitmap {
             self.data[i] &= other.data[i];
         }
     }
+
+    /// **Invert Bits**
+    ///
+    /// Flips every bit within the bitmap's `nbits`; storage bits beyond
+    /// `nbits` stay clear.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut bitmap = Bitmap::new(0b10100, 5);
+    /// bitmap.invert(); // now 0b01011
+    /// ```
+    pub fn invert(&mut self) {
+        for bit in 0..self.nbits {
+            let idx = bit / BITMAP_WORD_SIZE;
+            self.data[idx] ^= 1 << ((BITMAP_WORD_SIZE - 1) - bit % BITMAP_WORD_SIZE);
+        }
+    }
+}
+
+/// Mask selecting the top `nbits` bits of a storage word
+const fn used_mask(nbits: usize) -> u8 {
+    if nbits < BITMAP_WORD_SIZE {
+        !(u8::MAX >> nbits)
+    } else {
+        u8::MAX
+    }
 }
 
 /// **Generic Frame Buffer for LED Matrix Display**
@@ -322,7 +540,7 @@ impl Bitmap {
 /// - `YSIZE`: Frame height in pixels (typically 5 for micro:bit)
 ///
 /// ## Current Limitations
-/// - **Width Restriction**: Currently limited to 8-bit width per row
+/// - **Width Restriction**: Currently limited to `BITMAP_CAPACITY` (16) pixels per row
 /// - **Future Enhancement**: Will support arbitrary widths with const generics
 ///
 /// ## Usage Examples
@@ -336,7 +554,12 @@ impl Bitmap {
 /// - **X-axis**: Horizontal (columns), 0 = leftmost
 /// - **Y-axis**: Vertical (rows), 0 = topmost
 /// - **Origin**: Top-left corner (0,0)
-#[derive(Clone, Copy, PartialEq)]
+///
+/// # Equality and Hashing
+/// Frames compare and hash row by row through `Bitmap`, so only pixels
+/// count and frames can key a lookup table. `eq_const` does the same
+/// comparison in const contexts.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Frame<const XSIZE: usize, const YSIZE: usize> {
     bitmap: [Bitmap; YSIZE],
 }
@@ -357,21 +580,62 @@ impl<const XSIZE: usize, const YSIZE: usize> core::fmt::Debug for Frame<XSIZE, Y
     }
 }
 
+/// Written one row at a time, so no buffer grows with the frame size
 #[cfg(feature = "defmt")]
 impl<const XSIZE: usize, const YSIZE: usize> defmt::Format for Frame<XSIZE, YSIZE> {
     fn format(&self, f: defmt::Formatter<'_>) {
-        let mut s: heapless::String<1056> = heapless::String::new();
-        for (i, b) in self.bitmap.iter().enumerate() {
-            for j in 0..b.nbits {
-                if self.bitmap[i].is_set(j) {
-                    s.push('1').unwrap();
-                } else {
-                    s.push('0').unwrap();
+        for row in self.bitmap.iter() {
+            defmt::write!(f, "{}\n", row);
+        }
+    }
+}
+
+/// Serialized as a tuple of `YSIZE` row bitmaps, top row first
+#[cfg(feature = "serde")]
+impl<const XSIZE: usize, const YSIZE: usize> serde::Serialize for Frame<XSIZE, YSIZE> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeTuple;
+
+        let mut rows = serializer.serialize_tuple(YSIZE)?;
+        for row in self.bitmap.iter() {
+            rows.serialize_element(row)?;
+        }
+        rows.end()
+    }
+}
+
+/// Deserialized from `YSIZE` row bitmaps; every row must be `XSIZE` bits wide
+#[cfg(feature = "serde")]
+impl<'de, const XSIZE: usize, const YSIZE: usize> serde::Deserialize<'de> for Frame<XSIZE, YSIZE> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct RowsVisitor<const XSIZE: usize, const YSIZE: usize>;
+
+        impl<'de, const XSIZE: usize, const YSIZE: usize> serde::de::Visitor<'de> for RowsVisitor<XSIZE, YSIZE> {
+            type Value = Frame<XSIZE, YSIZE>;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{} rows of {} bits", YSIZE, XSIZE)
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut frame = Frame::empty();
+                for (y, row) in frame.bitmap.iter_mut().enumerate() {
+                    let bitmap: Bitmap = seq
+                        .next_element()?
+                        .ok_or_else(|| serde::de::Error::invalid_length(y, &self))?;
+                    if bitmap.nbits != XSIZE {
+                        return Err(serde::de::Error::invalid_value(
+                            serde::de::Unexpected::Unsigned(bitmap.nbits as u64),
+                            &self,
+                        ));
+                    }
+                    *row = bitmap;
                 }
+                Ok(frame)
             }
-            s.push('\n').unwrap();
         }
-        defmt::write!(f, "{}", s.as_str());
+
+        deserializer.deserialize_tuple(YSIZE, RowsVisitor::<XSIZE, YSIZE>)
     }
 }
 
@@ -379,7 +643,8 @@ impl<const XSIZE: usize, const YSIZE: usize> Frame<XSIZE, YSIZE> {
     /// **Create Empty Frame**
     ///
     /// Creates a new frame with all pixels cleared (off).
-    /// All bitmap rows are initialized to empty state.
+    /// All bitmap rows are initialized to empty state. Frames wider than
+    /// `BITMAP_CAPACITY` fail to compile.
     ///
     /// # Returns
     /// A new Frame instance with all pixels set to 0
@@ -390,6 +655,7 @@ impl<const XSIZE: usize, const YSIZE: usize> Frame<XSIZE, YSIZE> {
     /// // All pixels are initially off
     /// ```
     pub const fn empty() -> Self {
+        const { assert!(XSIZE <= BITMAP_CAPACITY, "frame is wider than BITMAP_CAPACITY") };
         Self {
             bitmap: [Bitmap::empty(XSIZE); YSIZE],
         }
@@ -398,7 +664,8 @@ impl<const XSIZE: usize, const YSIZE: usize> Frame<XSIZE, YSIZE> {
     /// **Create Frame from Bitmap Array**
     ///
     /// Creates a new frame using a pre-configured array of bitmaps.
-    /// Each bitmap represents one row of the frame.
+    /// Each bitmap represents one row of the frame. Frames wider than
+    /// `BITMAP_CAPACITY` fail to compile.
     ///
     /// # Arguments
     /// * `bitmap` - Array of Bitmap instances, one per row
@@ -416,9 +683,103 @@ impl<const XSIZE: usize, const YSIZE: usize> Frame<XSIZE, YSIZE> {
     /// let frame = Frame::new(bitmaps);
     /// ```
     pub const fn new(bitmap: [Bitmap; YSIZE]) -> Self {
+        const { assert!(XSIZE <= BITMAP_CAPACITY, "frame is wider than BITMAP_CAPACITY") };
         Self { bitmap }
     }
 
+    /// **Create Frame From Bytes**
+    ///
+    /// Rebuilds a frame from the bytes produced by `to_bytes`, one byte per
+    /// row from the top. Each byte uses the `Bitmap::from_raw` layout: the
+    /// leftmost pixel is the most significant bit and the `XSIZE` used bits
+    /// sit in the high part, e.g. `0b10100000` lights columns 0 and 2 of a
+    /// 5-wide row. This is the stored encoding described for `frame_5x5`,
+    /// whose right-aligned pattern literals correspond to `byte >> 3`.
+    /// Only frames up to 8 pixels wide can be built this way; wider frames
+    /// fail to compile.
+    ///
+    /// # Arguments
+    /// * `bytes` - One byte per row, top row first
+    ///
+    /// # Returns
+    /// A frame with the encoded pixels lit
+    ///
+    /// # Example
+    /// ```ignore
+    /// let frame = Frame::<5, 5>::from_bytes(&[0b00100000; 5]);
+    /// assert_eq!(frame, frame_5x5(&[0b00100; 5])); // vertical center line
+    /// ```
+    #[allow(dead_code)]
+    pub const fn from_bytes(bytes: &[u8; YSIZE]) -> Self {
+        const { assert!(XSIZE <= BITMAP_WORD_SIZE, "a byte holds at most 8 pixels per row") };
+        let mut bitmap = [Bitmap::empty(XSIZE); YSIZE];
+        let mut y = 0;
+        while y < YSIZE {
+            bitmap[y] = Bitmap::from_raw(bytes[y], XSIZE);
+            y += 1;
+        }
+        Self { bitmap }
+    }
+
+    /// **Create Frame From Pattern Rows**
+    ///
+    /// Builds a frame of any height from one byte per row in the same
+    /// format as `frame_5x5`: the low `XSIZE` bits of each byte are the
+    /// pixels, leftmost pixel in bit `XSIZE - 1`, so a 5-wide row is written
+    /// `0b10100`. Higher bits are ignored. For `Frame<5, 5>` the result is
+    /// identical to `frame_5x5`.
+    ///
+    /// Rows are limited to 8 pixels, as each one is given as a single byte;
+    /// a wider frame fails to compile.
+    ///
+    /// # Arguments
+    /// * `rows` - One byte per row, top row first
+    ///
+    /// # Returns
+    /// A frame with the encoded pixels lit
+    ///
+    /// # Example
+    /// ```ignore
+    /// let bar: Frame<8, 2> = Frame::from_rows(&[0b11110000, 0b00001111]);
+    /// ```
+    #[allow(dead_code)]
+    pub const fn from_rows(rows: &[u8; YSIZE]) -> Self {
+        const { assert!(XSIZE <= BITMAP_WORD_SIZE, "a byte holds at most 8 pixels per row") };
+        let mut bitmap = [Bitmap::empty(XSIZE); YSIZE];
+        let mut y = 0;
+        while y < YSIZE {
+            bitmap[y] = Bitmap::new(rows[y], XSIZE);
+            y += 1;
+        }
+        Self { bitmap }
+    }
+
+    /// **Convert Frame To Bytes**
+    ///
+    /// Packs the frame into one byte per row, in the layout accepted by
+    /// `from_bytes`, ready to be stored in flash or sent over the radio or
+    /// UART. The conversion is lossless, so it is only available for frames
+    /// up to 8 pixels wide; wider frames fail to compile rather than
+    /// silently dropping columns.
+    ///
+    /// # Returns
+    /// The rows from top to bottom, each as `Bitmap::as_raw`
+    ///
+    /// # Example
+    /// ```ignore
+    /// let bytes = CHECK_MARK.to_bytes();
+    /// assert_eq!(Frame::<5, 5>::from_bytes(&bytes), CHECK_MARK);
+    /// ```
+    #[allow(dead_code)]
+    pub fn to_bytes(self) -> [u8; YSIZE] {
+        const { assert!(XSIZE <= BITMAP_WORD_SIZE, "a byte holds at most 8 pixels per row") };
+        let mut bytes = [0; YSIZE];
+        for (byte, row) in bytes.iter_mut().zip(self.bitmap.iter()) {
+            *byte = row.as_raw();
+        }
+        bytes
+    }
+
     /// **Clear Frame**
     ///
     /// Clears all pixels in the frame, setting them to the off state.
@@ -435,6 +796,30 @@ impl<const XSIZE: usize, const YSIZE: usize> Frame<XSIZE, YSIZE> {
         }
     }
 
+    /// **Fill Frame**
+    ///
+    /// Sets every pixel to `value`. Only the frame's `XSIZE` columns are
+    /// lit; unused storage bits stay clear, so a filled frame compares equal
+    /// to an inverted empty one.
+    ///
+    /// # Arguments
+    /// * `value` - `true` to light every pixel, `false` to turn all off
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut frame = Frame::<5, 5>::empty();
+    /// frame.fill(true); // all 25 pixels lit
+    /// ```
+    #[allow(dead_code)]
+    pub fn fill(&mut self, value: bool) {
+        for row in self.bitmap.iter_mut() {
+            row.clear_all();
+            if value {
+                row.invert();
+            }
+        }
+    }
+
     /// **Set Pixel**
     ///
     /// Sets the pixel at the specified coordinates to the on state.
@@ -503,6 +888,45 @@ impl<const XSIZE: usize, const YSIZE: usize> Frame<XSIZE, YSIZE> {
         self.bitmap[y].is_set(x)
     }
 
+    /// **Set or Clear Pixel If On Screen**
+    ///
+    /// Non-panicking variant of `set`/`unset` for positions computed from
+    /// sensor input or moving objects. Coordinates are signed, so positions
+    /// that have moved off the left or top edge are simply clipped, as are
+    /// those past the right or bottom edge. In-bounds coordinates behave
+    /// exactly like `set` (`value == true`) or `unset`.
+    ///
+    /// # Arguments
+    /// * `x` - Column position; anything outside `0..XSIZE` is ignored
+    /// * `y` - Row position; anything outside `0..YSIZE` is ignored
+    /// * `value` - `true` to switch the pixel on, `false` to switch it off
+    ///
+    /// # Returns
+    /// `true` if the position was on the frame and the pixel was updated
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut frame = Frame::<5, 5>::empty();
+    /// assert!(frame.try_set(4, 0, true));
+    /// assert!(!frame.try_set(-1, 2, true)); // off the left edge, ignored
+    /// assert!(!frame.try_set(5, 2, true)); // off the right edge, ignored
+    /// ```
+    #[allow(dead_code)]
+    pub fn try_set(&mut self, x: isize, y: isize, value: bool) -> bool {
+        let (Ok(x), Ok(y)) = (usize::try_from(x), usize::try_from(y)) else {
+            return false;
+        };
+        if x >= XSIZE || y >= YSIZE {
+            return false;
+        }
+        if value {
+            self.set(x, y);
+        } else {
+            self.unset(x, y);
+        }
+        true
+    }
+
     /// **Logical OR with Another Frame**
     ///
     /// Performs a bitwise OR operation with another frame of the same size.
@@ -561,6 +985,50 @@ impl<const XSIZE: usize, const YSIZE: usize> Frame<XSIZE, YSIZE> {
         }
     }
 
+    /// **Shift Frame Up**
+    ///
+    /// Moves every row up by the specified number of rows.
+    /// Rows shifted beyond the top edge are lost, and empty rows appear at the bottom.
+    ///
+    /// # Arguments
+    /// * `nrows` - Number of rows to shift up
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut frame = Frame::<5, 5>::new(some_pattern);
+    /// frame.shift_up(2); // Row 2 becomes row 0
+    /// ```
+    pub fn shift_up(&mut self, nrows: usize) {
+        for y in 0..YSIZE {
+            self.bitmap[y] = match y.checked_add(nrows) {
+                Some(src) if src < YSIZE => self.bitmap[src],
+                _ => Bitmap::empty(XSIZE),
+            };
+        }
+    }
+
+    /// **Shift Frame Down**
+    ///
+    /// Moves every row down by the specified number of rows.
+    /// Rows shifted beyond the bottom edge are lost, and empty rows appear at the top.
+    ///
+    /// # Arguments
+    /// * `nrows` - Number of rows to shift down
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut frame = Frame::<5, 5>::new(some_pattern);
+    /// frame.shift_down(1); // Row 0 becomes row 1
+    /// ```
+    pub fn shift_down(&mut self, nrows: usize) {
+        for y in (0..YSIZE).rev() {
+            self.bitmap[y] = match y.checked_sub(nrows) {
+                Some(src) => self.bitmap[src],
+                None => Bitmap::empty(XSIZE),
+            };
+        }
+    }
+
     /// **Logical AND with Another Frame**
     ///
     /// Performs a bitwise AND operation with another frame of the same size.
@@ -580,6 +1048,256 @@ impl<const XSIZE: usize, const YSIZE: usize> Frame<XSIZE, YSIZE> {
             self.bitmap[i].and(&other.bitmap[i]);
         }
     }
+
+    /// **Invert Frame**
+    ///
+    /// Turns every lit pixel off and every dark pixel on, for reverse-video
+    /// effects.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut frame = Frame::<5, 5>::empty();
+    /// frame.invert(); // all 25 pixels now lit
+    /// ```
+    pub fn invert(&mut self) {
+        for row in self.bitmap.iter_mut() {
+            row.invert();
+        }
+    }
+
+    /// **Count Differing Pixels**
+    ///
+    /// Counts the pixels lit in exactly one of the two frames, i.e. the
+    /// Hamming distance between them. The result is symmetric and zero only
+    /// for identical frames.
+    ///
+    /// # Arguments
+    /// * `other` - Frame to compare against
+    ///
+    /// # Returns
+    /// Number of pixels that differ, from 0 to `XSIZE * YSIZE`
+    ///
+    /// # Example
+    /// ```ignore
+    /// let steps = ARROW_LEFT.difference(&ARROW_RIGHT); // scale a transition
+    /// ```
+    #[allow(dead_code)]
+    pub fn difference(&self, other: &Frame<XSIZE, YSIZE>) -> u32 {
+        self.bitmap
+            .iter()
+            .zip(other.bitmap.iter())
+            .map(|(a, b)| (a.bits() ^ b.bits()).count_ones())
+            .sum()
+    }
+
+    /// **Const Equality**
+    ///
+    /// Same comparison as `==`, usable in const contexts such as checks on
+    /// lookup tables of icons.
+    ///
+    /// # Arguments
+    /// * `other` - Frame to compare against
+    ///
+    /// # Returns
+    /// `true` if every pixel of both frames matches
+    ///
+    /// # Example
+    /// ```ignore
+    /// const _: () = assert!(!ARROW_LEFT.eq_const(&ARROW_RIGHT));
+    /// ```
+    #[allow(dead_code)]
+    pub const fn eq_const(&self, other: &Self) -> bool {
+        let mut y = 0;
+        while y < YSIZE {
+            if !self.bitmap[y].eq_const(&other.bitmap[y]) {
+                return false;
+            }
+            y += 1;
+        }
+        true
+    }
+
+    /// **Game of Life Step**
+    ///
+    /// Computes the next generation of Conway's Game of Life using the
+    /// standard B3/S23 rules: an off pixel with exactly three lit neighbors
+    /// turns on, and a lit pixel with two or three lit neighbors stays on.
+    /// Pixels outside the frame count as dead, so patterns do not wrap.
+    ///
+    /// # Returns
+    /// A new frame holding the next generation
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut world: Frame<5, 5> = frame_5x5(&[0b00000, 0b00100, 0b00100, 0b00100, 0b00000]);
+    /// world = world.life_step(); // vertical blinker becomes horizontal
+    /// ```
+    pub fn life_step(&self) -> Frame<XSIZE, YSIZE> {
+        let mut next = Frame::empty();
+        for y in 0..YSIZE {
+            for x in 0..XSIZE {
+                let neighbors = self.live_neighbors(x, y);
+                if neighbors == 3 || (neighbors == 2 && self.is_set(x, y)) {
+                    next.set(x, y);
+                }
+            }
+        }
+        next
+    }
+
+    /// **Falling Sand Step**
+    ///
+    /// Moves every lit pixel down one row if the cell directly below it was
+    /// empty at the start of the step; otherwise the pixel stays put. Grains
+    /// on the bottom row rest there, so repeated steps pile them up. Each
+    /// empty cell can only be claimed by the grain directly above it, so
+    /// grains never overlap and the lit count never changes.
+    ///
+    /// # Returns
+    /// A new frame with every unsupported grain one row lower
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut sand: Frame<5, 5> = frame_5x5(&[0b00100, 0b00000, 0b00100, 0b00000, 0b00000]);
+    /// sand = sand.sand_step(); // both grains fall one row
+    /// ```
+    pub fn sand_step(&self) -> Frame<XSIZE, YSIZE> {
+        let mut next = Frame::empty();
+        for y in 0..YSIZE {
+            for x in 0..XSIZE {
+                if !self.is_set(x, y) {
+                    continue;
+                }
+                if y + 1 < YSIZE && !self.is_set(x, y + 1) {
+                    next.set(x, y + 1);
+                } else {
+                    next.set(x, y);
+                }
+            }
+        }
+        next
+    }
+
+    /// **Scale Frame Up**
+    ///
+    /// Magnifies the frame by an integer factor, replicating each pixel
+    /// into a `factor` x `factor` block. The copy goes pixel by pixel
+    /// through `is_set`/`set`, so it does not depend on how rows are stored.
+    ///
+    /// # Arguments
+    /// * `factor` - Magnification on both axes
+    ///
+    /// # Returns
+    /// A `Frame<NX, NY>` holding the magnified image
+    ///
+    /// # Panics
+    /// If `NX` is not `XSIZE * factor` or `NY` is not `YSIZE * factor`
+    ///
+    /// # Example
+    /// ```ignore
+    /// let tiny: Frame<2, 2> = Frame::new([Bitmap::new(0b10, 2), Bitmap::new(0b01, 2)]);
+    /// let big: Frame<4, 4> = tiny.scale(2); // each pixel becomes a 2x2 block
+    /// ```
+    #[allow(dead_code)]
+    pub fn scale<const NX: usize, const NY: usize>(&self, factor: usize) -> Frame<NX, NY> {
+        assert!(NX == XSIZE * factor && NY == YSIZE * factor);
+        let mut scaled = Frame::empty();
+        for y in 0..NY {
+            for x in 0..NX {
+                if self.is_set(x / factor, y / factor) {
+                    scaled.set(x, y);
+                }
+            }
+        }
+        scaled
+    }
+
+    /// Count lit pixels among the up to eight neighbors of (x, y)
+    fn live_neighbors(&self, x: usize, y: usize) -> usize {
+        let mut count = 0;
+        for ny in y.saturating_sub(1)..=(y + 1).min(YSIZE - 1) {
+            for nx in x.saturating_sub(1)..=(x + 1).min(XSIZE - 1) {
+                if (nx, ny) != (x, y) && self.is_set(nx, ny) {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// **Transpose Frame**
+    ///
+    /// Swaps the roles of x and y, so pixel `(x, y)` becomes `(y, x)` and
+    /// rows become columns. A non-square frame swaps its dimensions, e.g.
+    /// `Frame<8, 2>` becomes `Frame<2, 8>`. Transposing twice gives back the
+    /// original frame. Useful for importing column-major pattern data.
+    ///
+    /// # Returns
+    /// The transposed frame
+    ///
+    /// # Example
+    /// ```ignore
+    /// let columns: Frame<5, 5> = frame_5x5(&column_major_rows);
+    /// display.apply(columns.transpose());
+    /// ```
+    #[allow(dead_code)]
+    pub fn transpose(&self) -> Frame<YSIZE, XSIZE> {
+        let mut transposed = Frame::empty();
+        for y in 0..YSIZE {
+            for x in 0..XSIZE {
+                if self.is_set(x, y) {
+                    transposed.set(y, x);
+                }
+            }
+        }
+        transposed
+    }
+}
+
+impl<const N: usize> Frame<N, N> {
+    /// **Rotate Frame Left**
+    ///
+    /// Returns the frame turned a quarter turn counter-clockwise: the top
+    /// row becomes the left column. Only square frames can be rotated.
+    ///
+    /// # Returns
+    /// The rotated frame
+    ///
+    /// # Example
+    /// ```ignore
+    /// let up: Frame<5, 5> = ARROW_RIGHT.rotate_left();
+    /// ```
+    #[allow(dead_code)]
+    pub fn rotate_left(&self) -> Self {
+        let mut rotated = Frame::empty();
+        for y in 0..N {
+            for x in 0..N {
+                if self.is_set(x, y) {
+                    rotated.set(y, N - 1 - x);
+                }
+            }
+        }
+        rotated
+    }
+
+    /// **Rotate Frame Right**
+    ///
+    /// Returns the frame turned a quarter turn clockwise: the top row
+    /// becomes the right column. Only square frames can be rotated.
+    ///
+    /// # Returns
+    /// The rotated frame
+    pub fn rotate_right(&self) -> Self {
+        let mut rotated = Frame::empty();
+        for y in 0..N {
+            for x in 0..N {
+                if self.is_set(x, y) {
+                    rotated.set(N - 1 - y, x);
+                }
+            }
+        }
+        rotated
+    }
 }
 
 impl<const XSIZE: usize, const YSIZE: usize> Default for Frame<XSIZE, YSIZE> {
@@ -660,6 +1378,27 @@ impl Brightness {
         Self(level.clamp(Self::MIN.0, Self::MAX.0))
     }
 
+    /// **Brightness From Ratio**
+    ///
+    /// Converts a fraction of full brightness into the nearest level.
+    ///
+    /// # Arguments
+    /// * `ratio` - Fraction of full brightness (clamped to 0.0-1.0)
+    ///
+    /// # Returns
+    /// The level closest to `ratio * 10`
+    ///
+    /// # Example
+    /// ```ignore
+    /// assert_eq!(Brightness::from_ratio(0.5).level(), 5);
+    /// assert_eq!(Brightness::from_ratio(0.04).level(), 0);
+    /// ```
+    #[allow(dead_code)]
+    pub fn from_ratio(ratio: f32) -> Self {
+        let ratio = ratio.clamp(0.0, 1.0);
+        Self((ratio * Self::MAX.0 as f32 + 0.5) as u8)
+    }
+
     /// **Get Brightness Level**
     ///
     /// Returns the current brightness level as a u8 value.
@@ -694,3 +1433,538 @@ impl SubAssign<u8> for Brightness {
         self.0 -= core::cmp::min(self.0, rhs);
     }
 }
+
+/// Serialized as the plain level (0-10)
+#[cfg(feature = "serde")]
+impl serde::Serialize for Brightness {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Deserialized through `Brightness::new`, so levels above 10 are clamped
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Brightness {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u8::deserialize(deserializer).map(Self::new)
+    }
+}
+
+/// **Movement Direction**
+///
+/// One of the four directions on the LED matrix, using the same coordinate
+/// system as `Frame`: `Up` moves toward row 0 and `Left` toward column 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[allow(dead_code)]
+pub enum Direction {
+    /// Toward the top edge (decreasing y)
+    Up,
+    /// Toward the bottom edge (increasing y)
+    Down,
+    /// Toward the left edge (decreasing x)
+    Left,
+    /// Toward the right edge (increasing x)
+    Right,
+}
+
+#[allow(dead_code)]
+impl Direction {
+    /// **Opposite Direction**
+    ///
+    /// # Returns
+    /// The direction pointing the other way, e.g. `Down` for `Up`
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    /// **Turn Left**
+    ///
+    /// # Returns
+    /// The direction a quarter turn counterclockwise, e.g. `Left` for `Up`
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    /// **Turn Right**
+    ///
+    /// # Returns
+    /// The direction a quarter turn clockwise, e.g. `Right` for `Up`
+    pub fn turn_right(self) -> Self {
+        self.turn_left().opposite()
+    }
+}
+
+/// **Positioned Sprite**
+///
+/// A small `Frame` drawn onto a larger one at a signed (x, y) offset, for
+/// game objects that move around and partly off the display. Positions use
+/// the `Frame` coordinate system; negative values place the sprite's
+/// top-left corner beyond the top or left edge.
+///
+/// ## Type Parameters
+/// - `W`: Sprite width in pixels
+/// - `H`: Sprite height in pixels
+///
+/// # Example
+/// ```ignore
+/// let ball = Sprite::new(Frame::<2, 2>::new([Bitmap::new(0b11, 2); 2]), -1, 2);
+/// let mut screen = Frame::<5, 5>::empty();
+/// ball.blit(&mut screen); // only the right column of the ball is visible
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(dead_code)]
+pub struct Sprite<const W: usize, const H: usize> {
+    /// Sprite image
+    pub frame: Frame<W, H>,
+    /// Column of the sprite's left edge on the target frame
+    pub x: i32,
+    /// Row of the sprite's top edge on the target frame
+    pub y: i32,
+}
+
+#[allow(dead_code)]
+impl<const W: usize, const H: usize> Sprite<W, H> {
+    /// **Create Sprite**
+    ///
+    /// # Arguments
+    /// * `frame` - Sprite image
+    /// * `x` - Column of the sprite's left edge
+    /// * `y` - Row of the sprite's top edge
+    pub const fn new(frame: Frame<W, H>, x: i32, y: i32) -> Self {
+        Self { frame, x, y }
+    }
+
+    /// **Move Sprite**
+    ///
+    /// Moves the sprite by the given offsets.
+    ///
+    /// # Arguments
+    /// * `dx` - Columns to move (negative is left)
+    /// * `dy` - Rows to move (negative is up)
+    pub fn move_by(&mut self, dx: i32, dy: i32) {
+        self.x += dx;
+        self.y += dy;
+    }
+
+    /// **Draw Sprite**
+    ///
+    /// ORs the sprite's lit pixels into `target` at the sprite position.
+    /// Pixels falling outside `target` on any edge are clipped; pixels
+    /// already lit in `target` stay lit.
+    ///
+    /// # Arguments
+    /// * `target` - Frame to draw onto
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut screen = Frame::<5, 5>::empty();
+    /// player.blit(&mut screen);
+    /// display.display(screen, Duration::from_millis(100)).await;
+    /// ```
+    pub fn blit<const COLS: usize, const ROWS: usize>(&self, target: &mut Frame<COLS, ROWS>) {
+        self.for_each_visible::<COLS, ROWS>(|sx, sy, tx, ty| {
+            if self.frame.is_set(sx, sy) {
+                target.set(tx, ty);
+            }
+        });
+    }
+
+    /// **Draw Sprite Opaquely**
+    ///
+    /// Like `blit`, but the sprite's whole rectangle replaces what is
+    /// underneath: unlit sprite pixels switch the target pixels off.
+    /// Clipping at the edges is the same as for `blit`.
+    ///
+    /// # Arguments
+    /// * `target` - Frame to draw onto
+    pub fn cover<const COLS: usize, const ROWS: usize>(&self, target: &mut Frame<COLS, ROWS>) {
+        self.for_each_visible::<COLS, ROWS>(|sx, sy, tx, ty| {
+            if self.frame.is_set(sx, sy) {
+                target.set(tx, ty);
+            } else {
+                target.unset(tx, ty);
+            }
+        });
+    }
+
+    /// Call `f(sx, sy, tx, ty)` for every sprite pixel (sx, sy) that lands
+    /// at (tx, ty) inside a `COLS` x `ROWS` target
+    fn for_each_visible<const COLS: usize, const ROWS: usize>(&self, mut f: impl FnMut(usize, usize, usize, usize)) {
+        for sy in 0..H {
+            let Ok(ty) = usize::try_from(self.y + sy as i32) else {
+                continue;
+            };
+            if ty >= ROWS {
+                break;
+            }
+            for sx in 0..W {
+                let Ok(tx) = usize::try_from(self.x + sx as i32) else {
+                    continue;
+                };
+                if tx >= COLS {
+                    break;
+                }
+                f(sx, sy, tx, ty);
+            }
+        }
+    }
+}
+
+/// **Compositing Layer**
+///
+/// Anything that can be drawn into a `COLS` x `ROWS` frame as one layer of
+/// a scene. Lets `compose` take sprites of different sizes in one slice.
+pub trait Layer<const COLS: usize, const ROWS: usize> {
+    /// Draw this layer over `target`
+    fn draw(&self, target: &mut Frame<COLS, ROWS>);
+}
+
+impl<const W: usize, const H: usize, const COLS: usize, const ROWS: usize> Layer<COLS, ROWS> for Sprite<W, H> {
+    /// Sprites are opaque layers, so a sprite hides whatever is beneath its rectangle
+    fn draw(&self, target: &mut Frame<COLS, ROWS>) {
+        self.cover(target);
+    }
+}
+
+/// **Compose a Scene**
+///
+/// Starts from a copy of `background` and draws each layer in slice order,
+/// so later layers appear on top of earlier ones. Sprites are drawn with
+/// `Sprite::cover`, which makes each sprite's rectangle opaque and gives the
+/// draw order its meaning on a one-bit display. No allocation is needed:
+/// the layers are borrowed and the result is returned by value.
+///
+/// # Arguments
+/// * `background` - Frame the scene is drawn on
+/// * `layers` - Layers to draw, bottom first
+///
+/// # Returns
+/// The composed frame
+///
+/// # Example
+/// ```ignore
+/// let scene = compose(&Frame::empty(), &[&enemy, &projectile, &player]);
+/// display.display(scene, Duration::from_millis(100)).await;
+/// ```
+#[allow(dead_code)]
+pub fn compose<const COLS: usize, const ROWS: usize>(
+    background: &Frame<COLS, ROWS>,
+    layers: &[&dyn Layer<COLS, ROWS>],
+) -> Frame<COLS, ROWS> {
+    let mut frame = *background;
+    for layer in layers {
+        layer.draw(&mut frame);
+    }
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::{dice_face, frame_5x5, ARROW_LEFT, ARROW_RIGHT, CHECK_MARK, CROSS_MARK};
+    use std::hash::{BuildHasher, BuildHasherDefault, DefaultHasher};
+
+    #[test]
+    fn blinker_oscillates_with_period_two() {
+        let vertical: Frame<5, 5> = frame_5x5(&[0, 0b00100, 0b00100, 0b00100, 0]);
+        let horizontal: Frame<5, 5> = frame_5x5(&[0, 0, 0b01110, 0, 0]);
+        assert_eq!(vertical.life_step(), horizontal);
+        assert_eq!(horizontal.life_step(), vertical);
+    }
+
+    #[test]
+    fn life_still_lifes_and_edges() {
+        let block: Frame<5, 5> = frame_5x5(&[0b11000, 0b11000, 0, 0, 0]);
+        assert_eq!(block.life_step(), block);
+        // Cells beyond the edge count as dead
+        let corner: Frame<5, 5> = frame_5x5(&[0b00011, 0b00001, 0, 0, 0]);
+        assert_eq!(corner.life_step(), frame_5x5(&[0b00011, 0b00011, 0, 0, 0]));
+    }
+
+    #[test]
+    fn shift_up_and_down_reorder_rows() {
+        let diagonal: Frame<5, 5> = frame_5x5(&[0b10000, 0b01000, 0b00100, 0b00010, 0b00001]);
+        let mut up = diagonal;
+        up.shift_up(2);
+        assert_eq!(up, frame_5x5(&[0b00100, 0b00010, 0b00001, 0, 0]));
+        let mut down = diagonal;
+        down.shift_down(1);
+        assert_eq!(down, frame_5x5(&[0, 0b10000, 0b01000, 0b00100, 0b00010]));
+        // Shifting by the height or more clears the frame
+        let mut gone = diagonal;
+        gone.shift_down(5);
+        assert_eq!(gone, Frame::empty());
+        let mut gone = diagonal;
+        gone.shift_up(usize::MAX);
+        assert_eq!(gone, Frame::empty());
+    }
+
+    /// Blit a full 2x2 sprite at `(x, y)` onto a blank 5x5 frame
+    fn blit_2x2(x: i32, y: i32) -> Frame<5, 5> {
+        let sprite = Sprite::new(Frame::<2, 2>::new([Bitmap::new(0b11, 2); 2]), x, y);
+        let mut frame = Frame::empty();
+        sprite.blit(&mut frame);
+        frame
+    }
+
+    #[test]
+    fn sprites_clip_at_every_edge() {
+        assert_eq!(blit_2x2(-1, -1), frame_5x5(&[0b10000, 0, 0, 0, 0]));
+        assert_eq!(blit_2x2(4, 4), frame_5x5(&[0, 0, 0, 0, 0b00001]));
+        assert_eq!(blit_2x2(1, 2), frame_5x5(&[0, 0, 0b01100, 0b01100, 0]));
+        assert_eq!(blit_2x2(3, -1), frame_5x5(&[0b00011, 0, 0, 0, 0]));
+        // Fully off-screen sprites draw nothing
+        assert_eq!(blit_2x2(-2, 0), Frame::empty());
+        assert_eq!(blit_2x2(5, 0), Frame::empty());
+    }
+
+    #[test]
+    fn later_sprites_draw_on_top() {
+        let block = Sprite::new(Frame::<3, 3>::new([Bitmap::new(0b111, 3); 3]), 0, 0);
+        let diagonal = Sprite::new(Frame::<2, 2>::new([Bitmap::new(0b10, 2), Bitmap::new(0b01, 2)]), 1, 1);
+        // The diagonal's dark pixels punch through the block beneath it
+        let scene: Frame<5, 5> = compose(&Frame::empty(), &[&block, &diagonal]);
+        assert_eq!(scene, frame_5x5(&[0b11100, 0b11000, 0b10100, 0, 0]));
+        let scene: Frame<5, 5> = compose(&Frame::empty(), &[&diagonal, &block]);
+        assert_eq!(scene, frame_5x5(&[0b11100, 0b11100, 0b11100, 0, 0]));
+        // A clipped sprite still covers the background
+        let corner = Sprite::new(Frame::<2, 2>::empty(), 4, 4);
+        let full: Frame<5, 5> = frame_5x5(&[0b11111; 5]);
+        assert_eq!(
+            compose(&full, &[&corner]),
+            frame_5x5(&[0b11111, 0b11111, 0b11111, 0b11111, 0b11110])
+        );
+    }
+
+    #[test]
+    fn inverted_glyphs_light_the_unlit_pixels() {
+        for b in b' '..=b'~' {
+            let glyph: Frame<5, 5> = b.into();
+            let lit = glyph.difference(&Frame::empty());
+            let mut inverted = glyph;
+            inverted.invert();
+            assert_eq!(inverted.difference(&Frame::empty()), 25 - lit);
+            inverted.invert();
+            assert_eq!(inverted, glyph);
+        }
+    }
+
+    #[test]
+    fn raw_bits_round_trip() {
+        for nbits in 1..=8 {
+            for value in 0..=u8::MAX {
+                let bitmap = Bitmap::new(value, nbits);
+                let copy = Bitmap::from_raw(bitmap.as_raw(), bitmap.nbits());
+                assert_eq!(copy, bitmap);
+                assert_eq!(copy.as_raw(), bitmap.as_raw());
+            }
+        }
+        // Used bits sit in the high part and unused low bits are dropped
+        assert_eq!(Bitmap::new(0b10100, 5).as_raw(), 0b1010_0000);
+        assert_eq!(Bitmap::from_raw(0xFF, 5).as_raw(), 0b1111_1000);
+        assert_eq!(Bitmap::from_raw(0b1010_0000, 5), Bitmap::new(0b10100, 5));
+    }
+
+    #[test]
+    fn bytes_round_trip_through_frame_5x5() {
+        for frame in [
+            CHECK_MARK,
+            CROSS_MARK,
+            dice_face(5),
+            Frame::empty(),
+            frame_5x5(&[0b11111; 5]),
+        ] {
+            let bytes = frame.to_bytes();
+            assert_eq!(Frame::<5, 5>::from_bytes(&bytes), frame);
+            // The 5 used bits are the high bits of each byte
+            assert_eq!(frame_5x5::<5, 5>(&bytes.map(|b| b >> 3)), frame);
+        }
+        assert_eq!(Frame::<5, 5>::from_bytes(&[0b0010_0000; 5]), frame_5x5(&[0b00100; 5]));
+        assert_eq!(Frame::<5, 5>::from_bytes(&[0xFF; 5]).to_bytes(), [0xF8; 5]);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trips_through_postcard() {
+        let mut buf = [0u8; 64];
+        for frame in [CHECK_MARK, CROSS_MARK, dice_face(3), Frame::empty()] {
+            let used = postcard::to_slice(&frame, &mut buf).unwrap();
+            assert_eq!(postcard::from_bytes::<Frame<5, 5>>(used).unwrap(), frame);
+        }
+        // Frames wider than one storage word keep their high columns
+        let mut wide = Frame::<10, 2>::empty();
+        wide.set(9, 0);
+        wide.set(0, 1);
+        let used = postcard::to_slice(&wide, &mut buf).unwrap();
+        assert_eq!(postcard::from_bytes::<Frame<10, 2>>(used).unwrap(), wide);
+
+        let bitmap = Bitmap::new(0b101, 3);
+        let used = postcard::to_slice(&bitmap, &mut buf).unwrap();
+        assert_eq!(used, [0b1010_0000, 0, 3]);
+        assert_eq!(postcard::from_bytes::<Bitmap>(used).unwrap(), bitmap);
+        assert!(postcard::from_bytes::<Bitmap>(&[0, 0, 17]).is_err());
+        // A row of the wrong width, and too few rows, are rejected
+        assert!(postcard::from_bytes::<Frame<5, 5>>(&[0, 0, 5, 0, 0, 5, 0, 0, 5, 0, 0, 4, 0, 0, 5]).is_err());
+        assert!(postcard::from_bytes::<Frame<5, 5>>(&[0, 0, 5, 0, 0, 5]).is_err());
+
+        let used = postcard::to_slice(&Brightness::new(7), &mut buf).unwrap();
+        assert_eq!(postcard::from_bytes::<Brightness>(used).unwrap().level(), 7);
+        // Out-of-range levels clamp instead of failing
+        assert_eq!(postcard::from_bytes::<Brightness>(&[200]).unwrap().level(), 10);
+    }
+
+    #[test]
+    fn sand_settles_without_losing_grains() {
+        let mut frame: Frame<5, 5> = frame_5x5(&[0b00100, 0b00100, 0b00000, 0b00100, 0b00000]);
+        let grains = frame.difference(&Frame::empty());
+        let mut steps = 0;
+        loop {
+            let next = frame.sand_step();
+            assert_eq!(next.difference(&Frame::empty()), grains);
+            if next == frame {
+                break;
+            }
+            frame = next;
+            steps += 1;
+        }
+        assert_eq!(steps, 3);
+        assert_eq!(frame, frame_5x5(&[0, 0, 0b00100, 0b00100, 0b00100]));
+    }
+
+    #[test]
+    fn scaling_turns_pixels_into_blocks() {
+        let small: Frame<2, 2> = Frame::new([Bitmap::new(0b10, 2), Bitmap::new(0b01, 2)]);
+        let big: Frame<4, 4> = small.scale(2);
+        let expected = Frame::new([
+            Bitmap::new(0b1100, 4),
+            Bitmap::new(0b1100, 4),
+            Bitmap::new(0b0011, 4),
+            Bitmap::new(0b0011, 4),
+        ]);
+        assert_eq!(big, expected);
+        assert_eq!(small.scale::<2, 2>(1), small);
+    }
+
+    #[test]
+    #[should_panic]
+    fn scaling_to_the_wrong_size_panics() {
+        let _: Frame<4, 3> = Frame::<2, 2>::empty().scale(2);
+    }
+
+    #[test]
+    fn difference_counts_changed_pixels() {
+        let a: Frame<5, 5> = frame_5x5(&[0b10000, 0b00100, 0, 0, 0b00001]);
+        let b: Frame<5, 5> = frame_5x5(&[0b10001, 0b00000, 0, 0b01000, 0b00001]);
+        assert_eq!(a.difference(&b), 3);
+        assert_eq!(b.difference(&a), 3);
+        assert_eq!(a.difference(&a), 0);
+        assert_eq!(ARROW_LEFT.difference(&ARROW_RIGHT), ARROW_RIGHT.difference(&ARROW_LEFT));
+    }
+
+    #[test]
+    fn fill_sets_or_clears_every_pixel() {
+        let mut frame = Frame::<5, 5>::empty();
+        frame.fill(true);
+        assert_eq!(frame.difference(&Frame::empty()), 25);
+        // Padding bits stay clear
+        assert_eq!(frame.to_bytes(), [0b1111_1000; 5]);
+        frame.fill(false);
+        assert_eq!(frame, Frame::empty());
+    }
+
+    #[test]
+    fn from_rows_matches_frame_5x5() {
+        let heart = [0b01010, 0b11111, 0b11111, 0b01110, 0b00100];
+        assert_eq!(Frame::<5, 5>::from_rows(&heart), frame_5x5::<5, 5>(&heart));
+        assert_eq!(
+            Frame::<5, 5>::from_rows(&[0, 0b00001, 0b00010, 0b10100, 0b01000]),
+            CHECK_MARK
+        );
+        // The low XSIZE bits are used, leftmost column first
+        let narrow: Frame<3, 1> = Frame::from_rows(&[0b1111_1101]);
+        assert_eq!(narrow.to_bytes(), [0b1010_0000]);
+    }
+
+    #[test]
+    fn transpose_swaps_rows_and_columns() {
+        // An "L" becomes an upside-down "7"
+        let l: Frame<5, 5> = frame_5x5(&[0b10000, 0b10000, 0b10000, 0b10000, 0b11110]);
+        assert_eq!(l.transpose(), frame_5x5(&[0b11111, 0b00001, 0b00001, 0b00001, 0]));
+        assert_eq!(l.transpose().transpose(), l);
+        let wide: Frame<8, 2> = Frame::from_rows(&[0b1100_0001, 0b0000_0010]);
+        let tall: Frame<2, 8> = wide.transpose();
+        assert!(tall.is_set(0, 0) && tall.is_set(0, 1) && tall.is_set(0, 7) && tall.is_set(1, 6));
+        assert!(!tall.is_set(1, 0));
+        assert_eq!(tall.transpose(), wide);
+    }
+
+    #[test]
+    fn frames_format_one_line_per_row() {
+        let text = format!("{:?}", CHECK_MARK);
+        assert_eq!(text, "00000\n00001\n00010\n10100\n01000\n");
+        assert_eq!(text.len(), 30);
+        let wide = format!("{:?}", Frame::<10, 2>::empty());
+        assert_eq!(wide, "0000000000\n0000000000\n");
+    }
+
+    #[test]
+    #[cfg(all(feature = "defmt", target_os = "linux"))]
+    fn frames_log_one_defmt_line_per_row() {
+        use crate::test_support::defmt_text;
+
+        let text = defmt_text(&CHECK_MARK);
+        assert_eq!(text, "00000\n00001\n00010\n10100\n01000\n");
+        assert_eq!(text.len(), 30);
+        assert_eq!(defmt_text(&Frame::<10, 2>::empty()), "0000000000\n0000000000\n");
+    }
+
+    #[test]
+    fn equal_frames_hash_equally() {
+        let hash = |frame: &Frame<5, 5>| BuildHasherDefault::<DefaultHasher>::default().hash_one(frame);
+        // Shifting the only pixel off the edge leaves an empty frame
+        let mut shifted = Frame::<5, 5>::empty();
+        shifted.set(4, 0);
+        shifted.shift_right(1);
+        let empty = Frame::empty();
+        assert_eq!(shifted, empty);
+        assert_eq!(hash(&shifted), hash(&empty));
+        assert!(shifted.eq_const(&empty));
+        let copy = CHECK_MARK;
+        assert_eq!(hash(&copy), hash(&CHECK_MARK));
+        assert!(!copy.eq_const(&empty));
+        const _: () = assert!(!ARROW_LEFT.eq_const(&ARROW_RIGHT));
+    }
+
+    #[test]
+    fn try_set_ignores_positions_off_the_frame() {
+        let mut frame = Frame::<5, 3>::empty();
+        for (x, y) in [
+            (-1, 0),
+            (0, -1),
+            (5, 0),
+            (0, 3),
+            (isize::MIN, isize::MAX),
+            (isize::MAX, 1),
+        ] {
+            assert!(!frame.try_set(x, y, true), "({x}, {y}) is off the frame");
+        }
+        assert_eq!(frame, Frame::empty());
+        // The corners are on the frame, and clearing works too
+        assert!(frame.try_set(0, 0, true));
+        assert!(frame.try_set(4, 2, true));
+        assert!(frame.is_set(0, 0) && frame.is_set(4, 2));
+        assert!(frame.try_set(0, 0, false));
+        assert!(!frame.is_set(0, 0) && frame.is_set(4, 2));
+    }
+}
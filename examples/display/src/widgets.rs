@@ -0,0 +1,363 @@
+//! # Display Widgets Module
+//!
+//! This module builds ready-to-display frames that visualize values rather
+//! than text, such as progress indicators. Every widget is a pure function
+//! or small value type returning a `Frame<5, 5>`, so it can be passed
+//! straight to `LedMatrix::display` or `LedMatrix::apply` in a loop as the
+//! value changes.
+//!
+//! ## Widgets
+//! - **Progress Bar**: Fills the 25 LEDs in reading order from 0% to 100%
+//! - **Level Meter**: VU-meter style bars filling rows from the bottom or
+//!   columns from the left
+//! - **Compass Needle**: A line from the center pointing toward one of the
+//!   eight compass directions
+//! - **Cursor**: A single lit LED marking a position
+//! - **Scope**: A scrolling bar chart of the last five samples
+//!
+//! ## Usage Example
+//! ```ignore
+//! for percent in (0..=100).step_by(4) {
+//!     display.display(progress_frame(percent), Duration::from_millis(100)).await;
+//! }
+//! ```
+
+use crate::types::Frame;
+
+/// **Matrix Width**
+///
+/// Number of LED columns on the micro:bit display.
+const WIDTH: usize = 5;
+
+/// **Matrix Height**
+///
+/// Number of LED rows on the micro:bit display.
+const HEIGHT: usize = 5;
+
+/// **Progress Bar Frame**
+///
+/// Lights LEDs in reading order (left to right, top to bottom) in proportion
+/// to `percent`. Each LED represents 4%, so 0% is blank, 48% lights the first
+/// 12 LEDs and 100% lights the whole matrix. Partial steps round down, so the
+/// final LED only comes on once the operation is complete.
+///
+/// # Arguments
+/// * `percent` - Completion percentage (values above 100 are clamped)
+///
+/// # Returns
+/// A frame with `percent * 25 / 100` LEDs lit
+///
+/// # Example
+/// ```ignore
+/// display.apply(progress_frame(48)); // first 12 LEDs lit
+/// ```
+#[allow(dead_code)]
+pub fn progress_frame(percent: u8) -> Frame<5, 5> {
+    let percent = percent.min(100) as usize;
+    let lit = percent * WIDTH * HEIGHT / 100;
+
+    let mut frame = Frame::empty();
+    for i in 0..lit {
+        frame.set(i % WIDTH, i / WIDTH);
+    }
+    frame
+}
+
+/// **Level Meter Frame**
+///
+/// VU-meter style bar graph that fully lights the bottom rows in proportion
+/// to `level / max`. The row count is rounded up, so any nonzero level lights
+/// at least one row and a full-scale level lights all five.
+///
+/// # Arguments
+/// * `level` - Current reading (values above `max` are clamped)
+/// * `max` - Full-scale reading; a `max` of 0 always yields a blank frame
+///
+/// # Returns
+/// A frame with the bottom `ceil(level / max * 5)` rows lit
+///
+/// # Example
+/// ```ignore
+/// display.apply(level_frame(128, 255)); // bottom three rows lit
+/// ```
+#[allow(dead_code)]
+pub fn level_frame(level: u8, max: u8) -> Frame<5, 5> {
+    let rows = level_steps(level, max, HEIGHT);
+
+    let mut frame = Frame::empty();
+    for y in HEIGHT - rows..HEIGHT {
+        for x in 0..WIDTH {
+            frame.set(x, y);
+        }
+    }
+    frame
+}
+
+/// **Horizontal Level Meter Frame**
+///
+/// Column variant of [`level_frame`] that fills whole columns from left to
+/// right, using the same rounding rules.
+///
+/// # Arguments
+/// * `level` - Current reading (values above `max` are clamped)
+/// * `max` - Full-scale reading; a `max` of 0 always yields a blank frame
+///
+/// # Returns
+/// A frame with the leftmost `ceil(level / max * 5)` columns lit
+///
+/// # Example
+/// ```ignore
+/// display.apply(level_columns_frame(1, 255)); // leftmost column lit
+/// ```
+#[allow(dead_code)]
+pub fn level_columns_frame(level: u8, max: u8) -> Frame<5, 5> {
+    let cols = level_steps(level, max, WIDTH);
+
+    let mut frame = Frame::empty();
+    for y in 0..HEIGHT {
+        for x in 0..cols {
+            frame.set(x, y);
+        }
+    }
+    frame
+}
+
+/// **Needle Directions**
+///
+/// Step from the center toward each compass point, clockwise from north.
+/// North is the top edge of the display.
+const NEEDLE_STEPS: [(i32, i32); 8] = [
+    (0, -1),  // N
+    (1, -1),  // NE
+    (1, 0),   // E
+    (1, 1),   // SE
+    (0, 1),   // S
+    (-1, 1),  // SW
+    (-1, 0),  // W
+    (-1, -1), // NW
+];
+
+/// **Compass Needle Frame**
+///
+/// Draws a line from the center LED to the edge of the display, pointing
+/// toward the compass point nearest to `heading_deg`. Each point covers a
+/// 45° sector centered on it, so 0°-22° shows north, 23°-67° north-east and
+/// so on; 338°-359° wraps back round to north.
+///
+/// # Arguments
+/// * `heading_deg` - Heading in degrees clockwise from north (taken modulo 360)
+///
+/// # Returns
+/// A frame with three LEDs lit: the center and two toward the heading
+///
+/// # Example
+/// ```ignore
+/// display.apply(needle_frame(90)); // points right, toward east
+/// ```
+#[allow(dead_code)]
+pub fn needle_frame(heading_deg: u16) -> Frame<5, 5> {
+    let heading = (heading_deg % 360) as usize;
+    // Adding half a sector (22.5°) before dividing rounds to the nearest point
+    let sector = (heading * 2 + 45) / 90 % NEEDLE_STEPS.len();
+    let (dx, dy) = NEEDLE_STEPS[sector];
+
+    let mut frame = Frame::empty();
+    for distance in 0..=2 {
+        let x = 2 + dx * distance;
+        let y = 2 + dy * distance;
+        frame.set(x as usize, y as usize);
+    }
+    frame
+}
+
+/// Scale `level / max` to `0..=steps`, rounding up so small readings still show
+fn level_steps(level: u8, max: u8, steps: usize) -> usize {
+    if max == 0 {
+        return 0;
+    }
+    let level = level.min(max) as usize;
+    let max = max as usize;
+    (level * steps).div_ceil(max)
+}
+
+/// **Cursor Frame**
+///
+/// Lights the single LED at `pos`, e.g. the position of a `TiltCursor`.
+///
+/// # Arguments
+/// * `pos` - `(x, y)` position; coordinates past the edge are clamped to it
+///
+/// # Returns
+/// A frame with only the cursor LED lit
+///
+/// # Example
+/// ```ignore
+/// display.apply(cursor_frame(cursor.position()));
+/// ```
+#[allow(dead_code)]
+pub fn cursor_frame(pos: (usize, usize)) -> Frame<5, 5> {
+    let mut frame = Frame::empty();
+    frame.set(pos.0.min(WIDTH - 1), pos.1.min(HEIGHT - 1));
+    frame
+}
+
+/// **Oscilloscope Bar Chart**
+///
+/// Keeps the last five samples of a changing value and draws them as
+/// bottom-anchored bars, oldest on the left and newest on the right, so
+/// the history scrolls left as samples arrive. Bar heights use the same
+/// rounding as `level_frame`: any nonzero sample lights at least one row
+/// and 255 lights all five.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Scope {
+    /// Bar heights in rows, a ring buffer with the oldest at `oldest`
+    heights: [u8; WIDTH],
+    /// Index of the oldest sample in `heights`
+    oldest: usize,
+}
+
+#[allow(dead_code)]
+impl Scope {
+    /// **Create Scope**
+    ///
+    /// # Returns
+    /// A scope with an empty history, drawing as a blank frame
+    pub const fn new() -> Self {
+        Self {
+            heights: [0; WIDTH],
+            oldest: 0,
+        }
+    }
+
+    /// **Add Sample**
+    ///
+    /// Appends `value` as the newest bar, dropping the oldest one.
+    ///
+    /// # Arguments
+    /// * `value` - Sample on a 0-255 scale
+    ///
+    /// # Example
+    /// ```ignore
+    /// scope.push((mic.level().await / 16).min(255) as u8);
+    /// ```
+    pub fn push(&mut self, value: u8) {
+        self.heights[self.oldest] = level_steps(value, u8::MAX, HEIGHT) as u8;
+        self.oldest = (self.oldest + 1) % WIDTH;
+    }
+
+    /// **Render Scope**
+    ///
+    /// # Returns
+    /// A frame with one bar per column, newest in the rightmost column
+    ///
+    /// # Example
+    /// ```ignore
+    /// display.apply(scope.frame());
+    /// ```
+    pub fn frame(&self) -> Frame<5, 5> {
+        let mut frame = Frame::empty();
+        for i in 0..WIDTH {
+            let height = self.heights[(self.oldest + i) % WIDTH] as usize;
+            frame.shift_left(1);
+            for y in HEIGHT - height..HEIGHT {
+                frame.set(WIDTH - 1, y);
+            }
+        }
+        frame
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::{frame_5x5, SPINNER_FRAMES};
+
+    fn lit(frame: &Frame<5, 5>) -> usize {
+        (0..HEIGHT)
+            .flat_map(|y| (0..WIDTH).map(move |x| (x, y)))
+            .filter(|&(x, y)| frame.is_set(x, y))
+            .count()
+    }
+
+    #[test]
+    fn progress_lights_one_led_per_four_percent() {
+        assert_eq!(lit(&progress_frame(0)), 0);
+        assert_eq!(lit(&progress_frame(20)), 5);
+        assert_eq!(lit(&progress_frame(48)), 12);
+        assert_eq!(lit(&progress_frame(50)), 12);
+        assert_eq!(lit(&progress_frame(100)), 25);
+        assert_eq!(lit(&progress_frame(200)), 25);
+        // Reading order: the second LED is on the top row
+        assert!(progress_frame(8).is_set(1, 0));
+    }
+
+    #[test]
+    fn level_meter_fills_from_the_bottom() {
+        assert_eq!(lit(&level_frame(0, 100)), 0);
+        assert_eq!(lit(&level_frame(10, 100)), 5);
+        assert!(level_frame(10, 100).is_set(0, 4));
+        assert_eq!(lit(&level_frame(50, 100)), 15);
+        assert_eq!(lit(&level_frame(100, 100)), 25);
+        // Small readings round up so they still show
+        assert_eq!(lit(&level_frame(1, 255)), 5);
+        assert_eq!(lit(&level_frame(5, 0)), 0);
+    }
+
+    #[test]
+    fn column_meter_fills_from_the_left() {
+        assert_eq!(lit(&level_columns_frame(0, 100)), 0);
+        assert!(level_columns_frame(10, 100).is_set(0, 0));
+        assert!(!level_columns_frame(10, 100).is_set(1, 0));
+        assert_eq!(lit(&level_columns_frame(50, 100)), 15);
+        assert_eq!(lit(&level_columns_frame(100, 100)), 25);
+    }
+
+    #[test]
+    fn needle_snaps_to_eight_sectors() {
+        let sectors = [
+            frame_5x5(&[0b00100, 0b00100, 0b00100, 0, 0]),
+            frame_5x5(&[0b00001, 0b00010, 0b00100, 0, 0]),
+            frame_5x5(&[0, 0, 0b00111, 0, 0]),
+            frame_5x5(&[0, 0, 0b00100, 0b00010, 0b00001]),
+            frame_5x5(&[0, 0, 0b00100, 0b00100, 0b00100]),
+            frame_5x5(&[0, 0, 0b00100, 0b01000, 0b10000]),
+            frame_5x5(&[0, 0, 0b11100, 0, 0]),
+            frame_5x5(&[0b10000, 0b01000, 0b00100, 0, 0]),
+        ];
+        for (sector, expected) in sectors.iter().enumerate() {
+            let heading = sector as u16 * 45;
+            assert_eq!(needle_frame(heading), *expected, "{heading} degrees");
+            // The spinner turns the same needle
+            assert_eq!(SPINNER_FRAMES[sector], *expected);
+        }
+        // Sector boundaries sit halfway between the directions
+        assert_eq!(needle_frame(22), sectors[0]);
+        assert_eq!(needle_frame(23), sectors[1]);
+        assert_eq!(needle_frame(337), sectors[7]);
+        assert_eq!(needle_frame(338), sectors[0]);
+        assert_eq!(needle_frame(360 + 90), sectors[2]);
+    }
+
+    #[test]
+    fn scope_scrolls_a_ramp_of_columns() {
+        // Height of each column, checking it is lit from the bottom up
+        let heights = |frame: Frame<5, 5>| {
+            core::array::from_fn::<_, WIDTH, _>(|x| {
+                let height = (0..HEIGHT).filter(|&y| frame.is_set(x, y)).count();
+                assert!((0..HEIGHT).all(|y| frame.is_set(x, y) == (y >= HEIGHT - height)));
+                height
+            })
+        };
+        let mut scope = Scope::new();
+        assert_eq!(scope.frame(), Frame::empty());
+        // The newest value enters on the right
+        scope.push(255);
+        assert_eq!(heights(scope.frame()), [0, 0, 0, 0, 5]);
+        for value in [0, 51, 52, 153, 255] {
+            scope.push(value);
+        }
+        assert_eq!(heights(scope.frame()), [0, 1, 2, 3, 5]);
+        scope.push(1);
+        assert_eq!(heights(scope.frame()), [1, 2, 3, 5, 1]);
+    }
+}